@@ -5,19 +5,39 @@
     clippy::expect_used
 )]
 pub mod parsers {
+    pub mod backgrounds;
+    pub mod classes;
     pub mod creatures;
+    pub mod feats;
+    pub mod items;
+    pub mod objects;
+    pub mod optional_features;
+    pub mod races;
     pub mod spells;
+    pub mod tables;
+    pub mod traps;
+    pub mod vehicles;
 }
 
 pub mod models {
+    pub mod backgrounds;
+    pub mod classes;
     pub mod common;
     pub mod creatures;
+    pub mod feats;
     pub mod items;
+    pub mod objects;
+    pub mod optional_features;
+    pub mod races;
     pub mod spells;
+    pub mod tables;
+    pub mod traps;
+    pub mod vehicles;
 }
 
 pub mod utils {
     pub mod compare;
     pub mod error;
+    pub mod markdown;
     pub mod traits;
 }
@@ -8,9 +8,23 @@ use std::fs;
 use std::path::PathBuf;
 
 use clap::Parser;
+use dnd_document_parser::models::classes::{Class, Subclass};
 use dnd_document_parser::models::common::{merge_json, Source};
+use dnd_document_parser::models::items::{Item, WeaponProperty};
+use dnd_document_parser::models::races::Race;
 use dnd_document_parser::models::spells::Spell;
+use dnd_document_parser::parsers::backgrounds::parse_backgrounds;
+use dnd_document_parser::parsers::classes::{parse_classes, parse_subclasses};
+use dnd_document_parser::parsers::creatures::parse_creatures;
+use dnd_document_parser::parsers::feats::parse_feats;
+use dnd_document_parser::parsers::items::{parse_equipment_tables, parse_items};
+use dnd_document_parser::parsers::objects::parse_objects;
+use dnd_document_parser::parsers::optional_features::parse_optional_features;
+use dnd_document_parser::parsers::races::parse_races;
 use dnd_document_parser::parsers::spells::parse_gm_binder;
+use dnd_document_parser::parsers::tables::parse_tables;
+use dnd_document_parser::parsers::traps::parse_traps_hazards;
+use dnd_document_parser::parsers::vehicles::parse_vehicles;
 use dnd_document_parser::utils::error::Error;
 
 use dnd_document_parser::utils::traits::To5etools;
@@ -34,6 +48,9 @@ struct Cli {
     /// Path to metadata json file
     #[arg(short, long = "meta", default_value = "meta.json")]
     meta_path: PathBuf,
+    /// Check creature stat blocks for inconsistencies instead of writing output
+    #[arg(long)]
+    check: bool,
 }
 
 // TODO: Better error messages
@@ -84,9 +101,87 @@ fn parse_gm_binder_spells(sources: Vec<PathBuf>, source_book: Source) -> Vec<Res
         .collect_vec()
 }
 
+/// Parse all sources with the given parser, printing the entities that fail to parse.
+fn parse_all<'a, T>(
+    sources: &[PathBuf],
+    source_book: &Source<'a>,
+    parse: impl Fn(&str, &Source<'a>) -> Vec<Result<T, Error>>,
+) -> Result<Vec<T>, Error> {
+    let mut parsed = vec![];
+    for path in sources {
+        let document = fs::read_to_string(path)?;
+        for entity in parse(&document, source_book) {
+            match entity {
+                Ok(entity) => parsed.push(entity),
+                Err(err) => eprintln!("{err:?}"),
+            }
+        }
+    }
+    Ok(parsed)
+}
+
+/// Number of table rows the stated challenge rating may differ from the DMG estimate before it is
+/// reported, as the estimate is rough.
+const CHALLENGE_RATING_TOLERANCE: u16 = 1;
+
+/// Print the inconsistencies found in each creature stat block, and the challenge ratings that
+/// are further off the estimate than the tolerance.
+fn check_creatures(sources: Vec<PathBuf>) -> Result<(), Error> {
+    let source_book = Source {
+        source_book: "",
+        page: 0,
+    };
+    let mut num_inconsistent = 0;
+    let mut num_off_estimate = 0;
+    for path in sources {
+        let document = fs::read_to_string(&path)?;
+        for creature in parse_creatures(document, &source_book) {
+            match creature {
+                Ok(creature) => {
+                    let inconsistencies = creature.check();
+                    let estimate = creature.estimate_challenge_rating();
+                    let off_estimate =
+                        estimate.deviation.unsigned_abs() > CHALLENGE_RATING_TOLERANCE;
+                    if inconsistencies.is_empty() && !off_estimate {
+                        continue;
+                    }
+                    println!("{} ({}):", creature.name, path.display());
+                    if !inconsistencies.is_empty() {
+                        num_inconsistent += 1;
+                    }
+                    for inconsistency in inconsistencies {
+                        println!("  {inconsistency}");
+                    }
+                    if off_estimate {
+                        num_off_estimate += 1;
+                        println!(
+                            "  Challenge rating: stated {}, estimated {} (defensive {}, offensive {})",
+                            creature.challenge_rating.rating,
+                            estimate.estimated,
+                            estimate.defensive,
+                            estimate.offensive,
+                        );
+                    }
+                }
+                Err(err) => eprintln!("{err:?}"),
+            }
+        }
+    }
+    println!("Found {num_inconsistent} creatures with inconsistencies");
+    println!(
+        "Found {num_off_estimate} creatures whose challenge rating is more than \
+         {CHALLENGE_RATING_TOLERANCE} step off the estimate"
+    );
+    Ok(())
+}
+
+#[allow(clippy::too_many_lines)]
 fn main() -> Result<(), Error> {
     let args = Cli::parse();
     let sources = find_html_or_md_files(args.input_path)?;
+    if args.check {
+        return check_creatures(sources);
+    }
     let num_sources = sources.len();
     let meta_path = args.meta_path.clone();
     let meta = read_meta_file(args.meta_path)?;
@@ -95,6 +190,88 @@ fn main() -> Result<(), Error> {
         source_book: abbrev.as_str().unwrap(),
         page: 0,
     };
+    let (base_items, items): (Vec<_>, Vec<_>) =
+        parse_all(&sources, &source_book, |document, source| {
+            parse_items(document, source)
+                .into_iter()
+                .chain(parse_equipment_tables(document, source))
+                .collect()
+        })?
+        .into_iter()
+        .partition(Item::is_base_item);
+    let num_parsed_items = base_items.len() + items.len();
+    let item_properties = base_items
+        .iter()
+        .filter_map(|item| item.weapon.as_ref())
+        .flat_map(|weapon| &weapon.properties)
+        .filter_map(|property| match property {
+            WeaponProperty::Custom(property) => Some(property),
+            _ => None,
+        })
+        .unique_by(|property| &property.abbreviation)
+        .map(|property| property.to_5etools_property(&source_book))
+        .collect_vec();
+    let parsed_items = serde_json::json!({
+        "item": items.to_5etools_base(),
+        "baseitem": base_items.to_5etools_base(),
+        "itemProperty": item_properties,
+    });
+    let parsed_creatures = parse_all(&sources, &source_book, |document, source| {
+        parse_creatures(document.to_string(), source)
+    })?;
+    let num_parsed_creatures = parsed_creatures.len();
+    let parsed_feats = parse_all(&sources, &source_book, parse_feats)?;
+    let num_parsed_feats = parsed_feats.len();
+    let parsed_backgrounds = parse_all(&sources, &source_book, parse_backgrounds)?;
+    let num_parsed_backgrounds = parsed_backgrounds.len();
+    let parsed_races = parse_all(&sources, &source_book, parse_races)?;
+    let num_parsed_races = parsed_races.len();
+    let parsed_classes = parse_all(&sources, &source_book, parse_classes)?;
+    let parsed_subclasses = parse_all(&sources, &source_book, parse_subclasses)?;
+    let num_parsed_classes = parsed_classes.len() + parsed_subclasses.len();
+    let parsed_optional_features = parse_all(&sources, &source_book, parse_optional_features)?;
+    let num_parsed_optional_features = parsed_optional_features.len();
+    let parsed_tables = parse_all(&sources, &source_book, parse_tables)?;
+    let num_parsed_tables = parsed_tables.len();
+    let parsed_vehicles = parse_all(&sources, &source_book, parse_vehicles)?;
+    let parsed_objects = parse_all(&sources, &source_book, parse_objects)?;
+    let (parsed_hazards, parsed_traps): (Vec<_>, Vec<_>) =
+        parse_all(&sources, &source_book, parse_traps_hazards)?
+            .into_iter()
+            .partition(|trap_hazard| trap_hazard.trap_hazard_type.is_hazard());
+    let num_parsed_vehicles_objects_traps_hazards =
+        parsed_vehicles.len() + parsed_objects.len() + parsed_traps.len() + parsed_hazards.len();
+    let parsed_other = serde_json::json!({
+        "monster": parsed_creatures.to_5etools_creature(),
+        "legendaryGroup": parsed_creatures
+            .iter()
+            .filter_map(|creature| creature.legendary_group.as_ref())
+            .map(To5etools::to_5etools_creature)
+            .collect_vec(),
+        "feat": parsed_feats.to_5etools_base(),
+        "background": parsed_backgrounds.to_5etools_base(),
+        "race": parsed_races.to_5etools_base(),
+        "subrace": parsed_races
+            .iter()
+            .flat_map(Race::to_5etools_subraces)
+            .collect_vec(),
+        "class": parsed_classes.to_5etools_base(),
+        "classFeature": parsed_classes
+            .iter()
+            .flat_map(Class::to_5etools_features)
+            .collect_vec(),
+        "subclass": parsed_subclasses.to_5etools_base(),
+        "subclassFeature": parsed_subclasses
+            .iter()
+            .flat_map(Subclass::to_5etools_features)
+            .collect_vec(),
+        "optionalfeature": parsed_optional_features.to_5etools_base(),
+        "table": parsed_tables.to_5etools_base(),
+        "vehicle": parsed_vehicles.to_5etools_base(),
+        "object": parsed_objects.to_5etools_base(),
+        "trap": parsed_traps.to_5etools_base(),
+        "hazard": parsed_hazards.to_5etools_base(),
+    });
     let parsed_spells = parse_gm_binder_spells(sources, source_book);
     let parsed_spells = parsed_spells
         .iter()
@@ -110,6 +287,8 @@ fn main() -> Result<(), Error> {
     let meta_with_spells = merge_json(vec![
         meta,
         serde_json::json!({ "spell": Value::Array(parsed_spells) }),
+        parsed_items,
+        parsed_other,
     ]);
     let output_path = args.output_path.clone();
     if args.output_path.exists() {
@@ -122,9 +301,18 @@ fn main() -> Result<(), Error> {
     serde_json::to_writer_pretty(output_file, &meta_with_spells)?;
 
     println!(
-        "Successfully parsed {} files with a total of {} spells into {}, using metadata from {}",
+        "Successfully parsed {} files with a total of {} spells, {} creatures, {} items, {} feats, {} backgrounds, {} races, {} classes and subclasses, {} optional features, {} tables and {} vehicles, objects, traps and hazards into {}, using metadata from {}",
         num_sources,
         num_parsed_spells,
+        num_parsed_creatures,
+        num_parsed_items,
+        num_parsed_feats,
+        num_parsed_backgrounds,
+        num_parsed_races,
+        num_parsed_classes,
+        num_parsed_optional_features,
+        num_parsed_tables,
+        num_parsed_vehicles_objects_traps_hazards,
         output_path.to_str().unwrap(),
         meta_path.to_str().unwrap(),
     );
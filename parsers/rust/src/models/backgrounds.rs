@@ -0,0 +1,205 @@
+use crate::{
+    models::{
+        common::{merge_json, Description, Language, Skill, Source},
+        items::ItemValue,
+    },
+    utils::traits::To5etools,
+};
+use itertools::Itertools;
+use serde_json::{json, Map, Value};
+
+#[cfg(test)]
+mod tests;
+
+/// Skill proficiencies of a background, e.g. `Insight, Religion` or `Two of the following:
+/// Arcana, History, Nature`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SkillProficiencies {
+    pub skills: Vec<Skill>,
+    /// Skills to choose from and the number of skills to choose.
+    pub choose: Option<(Vec<Skill>, u8)>,
+}
+
+impl To5etools for SkillProficiencies {
+    fn to_5etools_base(&self) -> Value {
+        let mut proficiencies = self
+            .skills
+            .iter()
+            .map(|skill| (lowercase_key(skill), json!(true)))
+            .collect::<Map<String, Value>>();
+        if let Some((from, count)) = &self.choose {
+            proficiencies.insert(
+                "choose".to_string(),
+                json!({
+                    "from": from.to_5etools_base(),
+                    "count": count,
+                }),
+            );
+        }
+        json!([proficiencies])
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolProficiency {
+    /// A specific tool, e.g. `disguise kit` or `vehicles (land)`, in lowercase.
+    Tool(String),
+    AnyArtisansTool,
+    AnyGamingSet,
+    AnyMusicalInstrument,
+}
+
+/// Language proficiencies of a background, e.g. `Elvish and one of your choice`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LanguageProficiencies {
+    pub languages: Vec<Language>,
+    /// Number of standard languages of the character's choice.
+    pub any_standard: u8,
+}
+
+impl To5etools for LanguageProficiencies {
+    fn to_5etools_base(&self) -> Value {
+        let mut proficiencies = self
+            .languages
+            .iter()
+            .map(|language| (lowercase_key(language), json!(true)))
+            .collect::<Map<String, Value>>();
+        if self.any_standard > 0 {
+            proficiencies.insert("anyStandard".to_string(), json!(self.any_standard));
+        }
+        json!([proficiencies])
+    }
+}
+
+/// Key of a skill or language in 5etools proficiency objects, e.g. `animal handling`.
+fn lowercase_key(value: &impl To5etools) -> String {
+    value
+        .to_5etools_base()
+        .as_str()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// A piece of starting equipment, e.g. `5 sticks of incense` or `15 gp`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Equipment {
+    Item {
+        name: String,
+        quantity: u8,
+        /// Coins in the item, e.g. `a belt pouch containing 15 gp`.
+        contains: Option<ItemValue>,
+    },
+    Coins(ItemValue),
+}
+
+impl To5etools for Equipment {
+    fn to_5etools_base(&self) -> Value {
+        match self {
+            Self::Item {
+                name,
+                quantity,
+                contains,
+            } => merge_json(vec![
+                json!({ "special": name }),
+                if *quantity > 1 {
+                    json!({ "quantity": quantity })
+                } else {
+                    json!({})
+                },
+                contains.map_or_else(
+                    || json!({}),
+                    |contains| json!({ "containsValue": contains.to_5etools_base() }),
+                ),
+            ]),
+            Self::Coins(value) => json!({ "value": value.to_5etools_base() }),
+        }
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Background<'a> {
+    pub name: String,
+    pub source: Source<'a>,
+    pub skill_proficiencies: SkillProficiencies,
+    pub tool_proficiencies: Vec<ToolProficiency>,
+    pub language_proficiencies: LanguageProficiencies,
+    /// Alternative sets of starting equipment, e.g. `(A) … or (B) 50 gp`.
+    pub starting_equipment: Vec<Vec<Equipment>>,
+    /// Description, proficiency lines, feature and suggested characteristics with their tables.
+    pub entries: Vec<Description>,
+}
+
+impl Background<'_> {
+    fn tool_proficiencies_to_5etools(&self) -> Value {
+        if self.tool_proficiencies.is_empty() {
+            return json!({});
+        }
+        let mut proficiencies = Map::new();
+        for tool in &self.tool_proficiencies {
+            let (key, value) = match tool {
+                ToolProficiency::Tool(tool) => (tool.as_str(), json!(true)),
+                ToolProficiency::AnyArtisansTool => ("anyArtisansTool", json!(1)),
+                ToolProficiency::AnyGamingSet => ("anyGamingSet", json!(1)),
+                ToolProficiency::AnyMusicalInstrument => ("anyMusicalInstrument", json!(1)),
+            };
+            // The same kind of tool twice, e.g. `two types of musical instruments`.
+            let value = proficiencies
+                .get(key)
+                .and_then(Value::as_u64)
+                .map_or(value, |count| json!(count + 1));
+            proficiencies.insert(key.to_string(), value);
+        }
+        json!({ "toolProficiencies": [proficiencies] })
+    }
+
+    fn starting_equipment_to_5etools(&self) -> Value {
+        let equipment_to_5etools = |equipment: &Vec<Equipment>| {
+            equipment
+                .iter()
+                .map(To5etools::to_5etools_base)
+                .collect_vec()
+        };
+        match &self.starting_equipment[..] {
+            [] => json!({}),
+            [equipment] => {
+                json!({ "startingEquipment": [{ "_": equipment_to_5etools(equipment) }] })
+            }
+            choices => json!({
+                "startingEquipment": [choices
+                    .iter()
+                    .zip('a'..='z')
+                    .map(|(equipment, key)| (key.to_string(), json!(equipment_to_5etools(equipment))))
+                    .collect::<Map<String, Value>>()]
+            }),
+        }
+    }
+}
+
+impl To5etools for Background<'_> {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "entries": self.entries.to_5etools_base(),
+            }),
+            self.source.to_5etools_base(),
+            if self.skill_proficiencies == SkillProficiencies::default() {
+                json!({})
+            } else {
+                json!({ "skillProficiencies": self.skill_proficiencies.to_5etools_base() })
+            },
+            self.tool_proficiencies_to_5etools(),
+            if self.language_proficiencies == LanguageProficiencies::default() {
+                json!({})
+            } else {
+                json!({ "languageProficiencies": self.language_proficiencies.to_5etools_base() })
+            },
+            self.starting_equipment_to_5etools(),
+        ])
+    }
+}
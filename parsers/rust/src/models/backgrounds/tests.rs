@@ -0,0 +1,99 @@
+use serde_json::json;
+
+use crate::{
+    models::{
+        common::{Language, Skill, Source},
+        items::{Currency, ItemValue},
+    },
+    utils::traits::To5etools,
+};
+
+use super::{Background, Equipment, LanguageProficiencies, SkillProficiencies, ToolProficiency};
+
+#[test]
+fn proficiencies() {
+    assert_eq!(
+        SkillProficiencies {
+            skills: vec![Skill::SleightOfHand],
+            choose: Some((vec![Skill::Arcana, Skill::History], 1)),
+        }
+        .to_5etools_base(),
+        json!([{
+            "sleight of hand": true,
+            "choose": {"from": ["arcana", "history"], "count": 1},
+        }])
+    );
+    assert_eq!(
+        LanguageProficiencies {
+            languages: vec![Language::DeepSpeech],
+            any_standard: 2,
+        }
+        .to_5etools_base(),
+        json!([{"deep speech": true, "anyStandard": 2}])
+    );
+}
+
+#[test]
+fn equipment() {
+    assert_eq!(
+        Equipment::Item {
+            name: "belt pouch".to_string(),
+            quantity: 1,
+            contains: Some(ItemValue {
+                value: 15,
+                unit: Currency::Gold,
+            }),
+        }
+        .to_5etools_base(),
+        json!({"special": "belt pouch", "containsValue": 1500})
+    );
+    assert_eq!(
+        Equipment::Item {
+            name: "candles".to_string(),
+            quantity: 5,
+            contains: None,
+        }
+        .to_5etools_base(),
+        json!({"special": "candles", "quantity": 5})
+    );
+}
+
+#[test]
+fn background() {
+    let background = Background {
+        name: "Minstrel".to_string(),
+        source: Source {
+            source_book: "test-source",
+            page: 3,
+        },
+        skill_proficiencies: SkillProficiencies::default(),
+        tool_proficiencies: vec![
+            ToolProficiency::AnyMusicalInstrument,
+            ToolProficiency::AnyMusicalInstrument,
+            ToolProficiency::Tool("disguise kit".to_string()),
+        ],
+        language_proficiencies: LanguageProficiencies::default(),
+        starting_equipment: vec![
+            vec![Equipment::Coins(ItemValue {
+                value: 2,
+                unit: Currency::Gold,
+            })],
+            vec![Equipment::Coins(ItemValue {
+                value: 5,
+                unit: Currency::Silver,
+            })],
+        ],
+        entries: vec![],
+    };
+    assert_eq!(
+        background.to_5etools_base(),
+        json!({
+            "name": "Minstrel",
+            "source": "test-source",
+            "page": 3,
+            "entries": [],
+            "toolProficiencies": [{"anyMusicalInstrument": 2, "disguise kit": true}],
+            "startingEquipment": [{"a": [{"value": 200}], "b": [{"value": 50}]}],
+        })
+    );
+}
@@ -0,0 +1,245 @@
+use crate::{
+    models::{
+        backgrounds::SkillProficiencies,
+        common::{merge_json, AbilityScore, Classes, Description, Source},
+    },
+    utils::traits::To5etools,
+};
+use itertools::Itertools;
+use serde_json::{json, Map, Value};
+
+#[cfg(test)]
+mod tests;
+
+/// A feature of a class or subclass gained at a level.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassFeature {
+    pub name: String,
+    pub level: u8,
+    /// Whether the character gains a subclass feature at this level, e.g. `Primal Path` or
+    /// `Path feature`.
+    pub gain_subclass_feature: bool,
+    pub entries: Vec<Description>,
+}
+
+/// Columns of a class progression table other than level, proficiency bonus and features, e.g.
+/// `Rages` and `Rage Damage`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ClassTable {
+    pub col_labels: Vec<String>,
+    /// One row per level.
+    pub rows: Vec<Vec<String>>,
+}
+
+impl To5etools for ClassTable {
+    fn to_5etools_base(&self) -> Value {
+        json!({
+            "colLabels": self.col_labels,
+            "rows": self.rows,
+        })
+    }
+}
+
+/// Proficiencies a character gains when taking the class at 1st level.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StartingProficiencies {
+    /// Armor in lowercase, e.g. `light armor` or `shields`.
+    pub armor: Vec<String>,
+    /// Weapons in lowercase, e.g. `simple weapons`.
+    pub weapons: Vec<String>,
+    /// Tools in lowercase, e.g. `thieves' tools`.
+    pub tools: Vec<String>,
+    pub skills: Option<SkillProficiencies>,
+}
+
+impl To5etools for StartingProficiencies {
+    fn to_5etools_base(&self) -> Value {
+        let mut proficiencies = Map::new();
+        for (key, values) in [
+            ("armor", &self.armor),
+            ("weapons", &self.weapons),
+            ("tools", &self.tools),
+        ] {
+            if !values.is_empty() {
+                proficiencies.insert(key.to_string(), json!(values));
+            }
+        }
+        if let Some(skills) = &self.skills {
+            proficiencies.insert("skills".to_string(), skills.to_5etools_base());
+        }
+        Value::Object(proficiencies)
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Class<'a> {
+    pub name: String,
+    pub source: Source<'a>,
+    /// Number of faces of the hit die, e.g. 10 for `1d10 per fighter level`.
+    pub hit_die: Option<u8>,
+    pub saving_throws: Vec<AbilityScore>,
+    pub starting_proficiencies: StartingProficiencies,
+    pub table: Option<ClassTable>,
+    /// Name of the feature that grants the subclass, e.g. `Primal Path`.
+    pub subclass_title: Option<String>,
+    pub features: Vec<ClassFeature>,
+}
+
+impl Class<'_> {
+    /// Reference to a feature of the class, e.g. `Rage|Berserker|HB|1`.
+    #[must_use]
+    pub fn feature_reference(&self, feature: &ClassFeature) -> String {
+        format!(
+            "{}|{}|{}|{}",
+            feature.name, self.name, self.source.source_book, feature.level
+        )
+    }
+
+    /// The features as 5etools `classFeature` entries, which refer to the class by name and
+    /// source.
+    #[must_use]
+    pub fn to_5etools_features(&self) -> Vec<Value> {
+        self.features
+            .iter()
+            .map(|feature| {
+                merge_json(vec![
+                    json!({
+                        "name": feature.name,
+                        "className": self.name,
+                        "classSource": self.source.source_book,
+                        "level": feature.level,
+                        "entries": feature.entries.to_5etools_base(),
+                    }),
+                    self.source.to_5etools_base(),
+                ])
+            })
+            .collect()
+    }
+}
+
+impl To5etools for Class<'_> {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "classFeatures": self
+                    .features
+                    .iter()
+                    .map(|feature| {
+                        if feature.gain_subclass_feature {
+                            json!({
+                                "classFeature": self.feature_reference(feature),
+                                "gainSubclassFeature": true,
+                            })
+                        } else {
+                            json!(self.feature_reference(feature))
+                        }
+                    })
+                    .collect_vec(),
+            }),
+            self.source.to_5etools_base(),
+            self.hit_die.map_or_else(
+                || json!({}),
+                |faces| json!({ "hd": { "number": 1, "faces": faces } }),
+            ),
+            if self.saving_throws.is_empty() {
+                json!({})
+            } else {
+                json!({ "proficiency": self.saving_throws.to_5etools_base() })
+            },
+            if self.starting_proficiencies == StartingProficiencies::default() {
+                json!({})
+            } else {
+                json!({ "startingProficiencies": self.starting_proficiencies.to_5etools_base() })
+            },
+            self.table.as_ref().map_or_else(
+                || json!({}),
+                |table| json!({ "classTableGroups": [table.to_5etools_base()] }),
+            ),
+            self.subclass_title
+                .as_ref()
+                .map_or_else(|| json!({}), |title| json!({ "subclassTitle": title })),
+        ])
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Subclass<'a> {
+    pub name: String,
+    /// Name without the subclass type, e.g. `Glory` for `Oath of Glory`.
+    pub short_name: String,
+    pub source: Source<'a>,
+    pub class: Classes,
+    pub features: Vec<ClassFeature>,
+}
+
+impl Subclass<'_> {
+    /// Reference to a feature of the subclass, e.g. `Sacred Vow|Paladin|PHB|Glory|HB|3`.
+    #[must_use]
+    pub fn feature_reference(&self, feature: &ClassFeature) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}",
+            feature.name,
+            self.class_name(),
+            self.class.source_book(),
+            self.short_name,
+            self.source.source_book,
+            feature.level
+        )
+    }
+
+    fn class_name(&self) -> String {
+        self.class
+            .to_5etools_base()
+            .as_str()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    /// The features as 5etools `subclassFeature` entries, which refer to the class and subclass
+    /// by name and source.
+    #[must_use]
+    pub fn to_5etools_features(&self) -> Vec<Value> {
+        self.features
+            .iter()
+            .map(|feature| {
+                merge_json(vec![
+                    json!({
+                        "name": feature.name,
+                        "className": self.class_name(),
+                        "classSource": self.class.source_book(),
+                        "subclassShortName": self.short_name,
+                        "subclassSource": self.source.source_book,
+                        "level": feature.level,
+                        "entries": feature.entries.to_5etools_base(),
+                    }),
+                    self.source.to_5etools_base(),
+                ])
+            })
+            .collect()
+    }
+}
+
+impl To5etools for Subclass<'_> {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "shortName": self.short_name,
+                "className": self.class_name(),
+                "classSource": self.class.source_book(),
+                "subclassFeatures": self
+                    .features
+                    .iter()
+                    .map(|feature| self.feature_reference(feature))
+                    .collect_vec(),
+            }),
+            self.source.to_5etools_base(),
+        ])
+    }
+}
@@ -0,0 +1,111 @@
+use serde_json::json;
+
+use crate::{
+    models::common::{AbilityScore, Classes, Description, Source},
+    utils::traits::To5etools,
+};
+
+use super::{Class, ClassFeature, ClassTable, StartingProficiencies, Subclass};
+
+const SOURCE: Source = Source {
+    source_book: "test-source",
+    page: 4,
+};
+
+fn feature(name: &str, level: u8, gain_subclass_feature: bool) -> ClassFeature {
+    ClassFeature {
+        name: name.to_string(),
+        level,
+        gain_subclass_feature,
+        entries: vec![Description::Entry("Text.".to_string())],
+    }
+}
+
+#[test]
+fn class() {
+    let class = Class {
+        name: "Tidecaller".to_string(),
+        source: SOURCE,
+        hit_die: Some(8),
+        saving_throws: vec![AbilityScore::Wisdom, AbilityScore::Charisma],
+        starting_proficiencies: StartingProficiencies {
+            armor: vec!["light armor".to_string()],
+            ..StartingProficiencies::default()
+        },
+        table: Some(ClassTable {
+            col_labels: vec!["Tides".to_string()],
+            rows: vec![vec!["2".to_string()], vec!["3".to_string()]],
+        }),
+        subclass_title: Some("Tidal Current".to_string()),
+        features: vec![
+            feature("Tidal Surge", 1, false),
+            feature("Tidal Current", 2, true),
+        ],
+    };
+    assert_eq!(
+        class.to_5etools_base(),
+        json!({
+            "name": "Tidecaller",
+            "source": "test-source",
+            "page": 4,
+            "hd": {"number": 1, "faces": 8},
+            "proficiency": ["wis", "cha"],
+            "startingProficiencies": {"armor": ["light armor"]},
+            "classTableGroups": [{"colLabels": ["Tides"], "rows": [["2"], ["3"]]}],
+            "subclassTitle": "Tidal Current",
+            "classFeatures": [
+                "Tidal Surge|Tidecaller|test-source|1",
+                {"classFeature": "Tidal Current|Tidecaller|test-source|2", "gainSubclassFeature": true},
+            ],
+        })
+    );
+    assert_eq!(
+        class.to_5etools_features()[0],
+        json!({
+            "name": "Tidal Surge",
+            "source": "test-source",
+            "page": 4,
+            "className": "Tidecaller",
+            "classSource": "test-source",
+            "level": 1,
+            "entries": ["Text."],
+        })
+    );
+}
+
+#[test]
+fn subclass() {
+    let subclass = Subclass {
+        name: "Oath of the Lighthouse".to_string(),
+        short_name: "Lighthouse".to_string(),
+        source: SOURCE,
+        class: Classes::Paladin,
+        features: vec![feature("Beacon", 3, false)],
+    };
+    assert_eq!(
+        subclass.to_5etools_base(),
+        json!({
+            "name": "Oath of the Lighthouse",
+            "shortName": "Lighthouse",
+            "source": "test-source",
+            "page": 4,
+            "className": "Paladin",
+            "classSource": "PHB",
+            "subclassFeatures": ["Beacon|Paladin|PHB|Lighthouse|test-source|3"],
+        })
+    );
+    assert_eq!(
+        subclass.to_5etools_features(),
+        vec![json!({
+            "name": "Beacon",
+            "source": "test-source",
+            "page": 4,
+            "className": "Paladin",
+            "classSource": "PHB",
+            "subclassShortName": "Lighthouse",
+            "subclassSource": "test-source",
+            "level": 3,
+            "entries": ["Text."],
+        })]
+    );
+}
@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use regex::Regex;
+use regex::{Captures, Regex};
 use serde_json::{json, Value};
 
 use crate::utils::traits::To5etools;
@@ -12,11 +12,26 @@ mod tests;
 pub enum Description {
     Entry(String),
     List(Vec<Self>),
+    /// A list item with a name, e.g. `Skill Proficiencies: Insight, Religion`.
+    Item {
+        name: String,
+        entry: String,
+    },
+    /// A titled group of entries, e.g. `Feature: Shelter of the Faithful`.
+    Section {
+        name: String,
+        entries: Vec<Self>,
+    },
+    Table {
+        caption: Option<String>,
+        col_labels: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
 }
 
 impl To5etools for Description {
     fn to_5etools_base(&self) -> Value {
-        use Description::{Entry, List};
+        use Description::{Entry, Item, List, Section, Table};
         match self {
             Entry(entry) => {
                 // Capture e.g. "2d4" or "20d12" or "5d6 + 10"
@@ -31,6 +46,30 @@ impl To5etools for Description {
                 "type": "list",
                 "items": list_entries.iter().map(Self::to_5etools_base).collect_vec()
             }),
+            Item { name, entry } => json!({
+                "type": "item",
+                "name": name,
+                "entry": entry,
+            }),
+            Section { name, entries } => json!({
+                "type": "entries",
+                "name": name,
+                "entries": entries.iter().map(Self::to_5etools_base).collect_vec()
+            }),
+            Table {
+                caption,
+                col_labels,
+                rows,
+            } => merge_json(vec![
+                json!({
+                    "type": "table",
+                    "colLabels": col_labels,
+                    "rows": rows,
+                }),
+                caption
+                    .as_ref()
+                    .map_or_else(|| json!({}), |caption| json!({ "caption": caption })),
+            ]),
         }
     }
 }
@@ -138,6 +177,32 @@ pub enum DamageType {
     Thunder,
 }
 
+impl DamageType {
+    /// Abbreviation used by 5etools for damage types of items, e.g. `S` for slashing.
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        use DamageType::{
+            Acid, Bludgeoning, Cold, Fire, Force, Lightning, Necrotic, Piercing, Poison, Psychic,
+            Radiant, Slashing, Thunder,
+        };
+        match self {
+            Acid => "A",
+            Bludgeoning => "B",
+            Cold => "C",
+            Fire => "F",
+            Force => "O",
+            Lightning => "L",
+            Necrotic => "N",
+            Piercing => "P",
+            Poison => "I",
+            Psychic => "Y",
+            Radiant => "R",
+            Slashing => "S",
+            Thunder => "T",
+        }
+    }
+}
+
 pub const ALL_DAMAGE_TYPES: [DamageType; 13] = {
     use DamageType::*;
     [
@@ -230,7 +295,7 @@ impl To5etools for Classes {
 }
 
 impl Classes {
-    fn source_book(self) -> String {
+    pub(crate) fn source_book(self) -> String {
         use Classes::Artificer;
         match self {
             Artificer => "TCE",
@@ -285,7 +350,7 @@ impl To5etools for StatusCondition {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum AbilityScore {
     Strength,
     Dexterity,
@@ -313,7 +378,7 @@ impl To5etools for AbilityScore {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Skill {
     Acrobatics,
     AnimalHandling,
@@ -364,6 +429,22 @@ impl To5etools for Skill {
     }
 }
 
+impl Skill {
+    /// The ability score the skill is based on.
+    #[must_use]
+    pub const fn ability(&self) -> AbilityScore {
+        use AbilityScore::{Charisma, Dexterity, Intelligence, Strength, Wisdom};
+        use Skill::*;
+        match self {
+            Athletics => Strength,
+            Acrobatics | SleightOfHand | Stealth => Dexterity,
+            Arcana | History | Investigation | Nature | Religion => Intelligence,
+            AnimalHandling | Insight | Medicine | Perception | Survival => Wisdom,
+            Deception | Intimidation | Performance | Persuasion => Charisma,
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Language {
@@ -383,6 +464,15 @@ pub enum Language {
     Primordial,
     Sylvan,
     Undercommon,
+    Aquan,
+    Auran,
+    Ignan,
+    Terran,
+    Druidic,
+    ThievesCant,
+    Gith,
+    /// A language without a variant, e.g. a homebrew language or `the languages it knew in life`.
+    Other(String),
 }
 
 impl To5etools for Language {
@@ -406,6 +496,14 @@ impl To5etools for Language {
                 Primordial => "Primordial",
                 Sylvan => "Sylvan",
                 Undercommon => "Undercommon",
+                Aquan => "Aquan",
+                Auran => "Auran",
+                Ignan => "Ignan",
+                Terran => "Terran",
+                Druidic => "Druidic",
+                ThievesCant => "Thieves' cant",
+                Gith => "Gith",
+                Other(language) => language,
             }
             .to_owned(),
         )
@@ -476,28 +574,117 @@ impl To5etools for AlignmentAxis {
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Alignment {
+    /// Any alignment on one side of an axis, e.g. `any chaotic alignment`.
     OneAxis(AlignmentAxis),
+    /// Any alignment except one side of an axis, e.g. `any non-good alignment`.
+    ExcludedAxis(AlignmentAxis),
     TwoAxes {
         order: AlignmentAxisOrder,
         moral: AlignmentAxisMoral,
     },
     Any,
     Unaligned,
+    /// An alignment the creature usually has, e.g. `typically lawful evil`.
+    Typically(Box<Self>),
+    /// Alignments with their chance in percent, e.g. `neutral good (50%) or neutral evil (50%)`.
+    Chances(Vec<(Self, u8)>),
+    /// An alignment followed by a note, e.g. `any alignment (as its creator)`.
+    Note {
+        alignment: Box<Self>,
+        note: String,
+    },
+    /// An alignment that cannot be expressed with the axes, e.g. `as its creator`.
+    Special(String),
+}
+
+impl Alignment {
+    /// The alignment letters of every option on an axis, using `NX`/`NY` for the neutral ones.
+    fn axis_options(axis: &AlignmentAxis) -> Vec<&'static str> {
+        match axis {
+            AlignmentAxis::Order(_) => vec!["L", "NX", "C"],
+            AlignmentAxis::Moral(_) => vec!["G", "NY", "E"],
+        }
+    }
+
+    const fn axis_letter(axis: &AlignmentAxis) -> &'static str {
+        use AlignmentAxis::{Moral, Order};
+        match axis {
+            Order(AlignmentAxisOrder::Lawful) => "L",
+            Order(AlignmentAxisOrder::Neutral) => "NX",
+            Order(AlignmentAxisOrder::Chaotic) => "C",
+            Moral(AlignmentAxisMoral::Good) => "G",
+            Moral(AlignmentAxisMoral::Neutral) => "NY",
+            Moral(AlignmentAxisMoral::Evil) => "E",
+        }
+    }
+
+    /// The other axis, with any of its options allowed.
+    fn other_axis_options(axis: &AlignmentAxis) -> Vec<&'static str> {
+        match axis {
+            AlignmentAxis::Order(_) => {
+                Self::axis_options(&AlignmentAxis::Moral(AlignmentAxisMoral::Neutral))
+            }
+            AlignmentAxis::Moral(_) => {
+                Self::axis_options(&AlignmentAxis::Order(AlignmentAxisOrder::Neutral))
+            }
+        }
+    }
 }
 
 impl To5etools for Alignment {
     fn to_5etools_base(&self) -> Value {
         use Alignment::*;
-        json!(match self {
-            Any => vec![json!("A")],
-            Unaligned => vec![json!("U")],
-            OneAxis(axis) => vec![axis.to_5etools_base()],
+        match self {
+            Any => json!(["A"]),
+            Unaligned => json!(["U"]),
+            OneAxis(axis) => {
+                let letter = Self::axis_letter(axis);
+                match axis {
+                    AlignmentAxis::Order(_) => {
+                        json!([vec![letter], Self::other_axis_options(axis)].concat())
+                    }
+                    AlignmentAxis::Moral(_) => {
+                        json!([Self::other_axis_options(axis), vec![letter]].concat())
+                    }
+                }
+            }
+            ExcludedAxis(axis) => {
+                let letter = Self::axis_letter(axis);
+                let options = Self::axis_options(axis)
+                    .into_iter()
+                    .filter(|option| *option != letter)
+                    .collect_vec();
+                match axis {
+                    AlignmentAxis::Order(_) => {
+                        json!([options, Self::other_axis_options(axis)].concat())
+                    }
+                    AlignmentAxis::Moral(_) => {
+                        json!([Self::other_axis_options(axis), options].concat())
+                    }
+                }
+            }
             TwoAxes {
                 order: AlignmentAxisOrder::Neutral,
                 moral: AlignmentAxisMoral::Neutral,
-            } => vec![json!("N")],
-            TwoAxes { order, moral } => vec![order.to_5etools_base(), moral.to_5etools_base()],
-        })
+            } => json!(["N"]),
+            TwoAxes { order, moral } => json!([order.to_5etools_base(), moral.to_5etools_base()]),
+            // The "typically" prefix is a separate key of the creature.
+            Typically(alignment) => alignment.to_5etools_base(),
+            Chances(chances) => json!(chances
+                .iter()
+                .map(|(alignment, chance)| {
+                    json!({
+                        "alignment": alignment.to_5etools_base(),
+                        "chance": chance,
+                    })
+                })
+                .collect_vec()),
+            Note { alignment, note } => json!([{
+                "alignment": alignment.to_5etools_base(),
+                "note": note,
+            }]),
+            Special(special) => json!([{ "special": special }]),
+        }
     }
 }
 
@@ -509,6 +696,42 @@ pub struct NamedEntry {
     pub sub_entries: Option<Vec<Self>>,
 }
 
+impl NamedEntry {
+    /// Tag attack rolls and saving throw effects written in the 2024 format, e.g.
+    /// `*Melee Attack Roll:* +4, reach 5 ft. *Hit:* 5 (1d6 + 2) Slashing damage.`
+    fn tag_2024_entry(entry: &str) -> String {
+        let attack_roll_re = Regex::new(
+            r"^\*(?P<range>Melee or Ranged|Melee|Ranged) Attack Roll:\* \+?(?P<to_hit>-?\d+)",
+        )
+        .unwrap();
+        let saving_throw_re = Regex::new(
+            r"^\*(?P<ability>Strength|Dexterity|Constitution|Intelligence|Wisdom|Charisma) Saving Throw:\* DC (?P<dc>\d+)",
+        )
+        .unwrap();
+        let entry = attack_roll_re.replace(entry, |captures: &Captures| {
+            let range = match &captures["range"] {
+                "Melee" => "m",
+                "Ranged" => "r",
+                _ => "m,r",
+            };
+            format!("{{@atkr {range}}} {{@hit {}}}", &captures["to_hit"])
+        });
+        let entry = saving_throw_re.replace(&entry, |captures: &Captures| {
+            format!(
+                "{{@actSave {}}} {{@dc {}}}",
+                captures["ability"][..3].to_lowercase(),
+                &captures["dc"]
+            )
+        });
+        entry
+            .replace("*Hit:* ", "{@h}")
+            .replace("*Hit or Miss:* ", "{@hom}")
+            .replace("*Failure or Success:*", "{@actSaveSuccessOrFail}")
+            .replace("*Failure:*", "{@actSaveFail}")
+            .replace("*Success:*", "{@actSaveSuccess}")
+    }
+}
+
 impl To5etools for NamedEntry {
     fn to_5etools_base(&self) -> Value {
         let attack_type_re =
@@ -537,6 +760,7 @@ impl To5etools for NamedEntry {
         let entry = attack_type_re.replace(&self.entry, format!("{{@atk {attack_type}}}"));
         let entry = to_hit_re.replace(&entry, "{@hit $to_hit} to hit");
         let entry = hit_re.replace(&entry, ". {@h}");
+        let entry = Self::tag_2024_entry(&entry);
         let entry = Value::String(dice_re.replace_all(&entry, "{@damage $dice}").to_string());
         let sub_entries = self.sub_entries.as_ref().map(|sub_entries| {
             sub_entries
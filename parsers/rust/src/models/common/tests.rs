@@ -60,6 +60,57 @@ fn description_test() {
     );
 }
 
+#[test]
+fn description_section_and_table_test() {
+    use Description::{Entry, Item, Section, Table};
+
+    assert_eq!(
+        Item {
+            name: "Languages:".to_owned(),
+            entry: "Elvish".to_owned(),
+        }
+        .to_5etools_spell(),
+        json!({"type": "item", "name": "Languages:", "entry": "Elvish"})
+    );
+
+    assert_eq!(
+        Section {
+            name: "Feature: Shelter".to_owned(),
+            entries: vec![
+                Entry("Line 1".to_owned()),
+                Table {
+                    caption: None,
+                    col_labels: vec!["d6".to_owned(), "Ideal".to_owned()],
+                    rows: vec![vec!["1".to_owned(), "Charity".to_owned()]],
+                },
+            ],
+        }
+        .to_5etools_spell(),
+        json!({
+            "type": "entries",
+            "name": "Feature: Shelter",
+            "entries": [
+                "Line 1",
+                {
+                    "type": "table",
+                    "colLabels": ["d6", "Ideal"],
+                    "rows": [["1", "Charity"]],
+                }
+            ]
+        })
+    );
+
+    assert_eq!(
+        Table {
+            caption: Some("Loot".to_owned()),
+            col_labels: vec![],
+            rows: vec![],
+        }
+        .to_5etools_spell(),
+        json!({"type": "table", "caption": "Loot", "colLabels": [], "rows": []})
+    );
+}
+
 #[test]
 fn alignment_test() {
     use Alignment::*;
@@ -68,7 +119,60 @@ fn alignment_test() {
 
     assert_eq!(
         OneAxis(AlignmentAxis::Moral(AlignmentAxisMoral::Good)).to_5etools_creature(),
-        json!(["G"])
+        json!(["L", "NX", "C", "G"])
+    );
+
+    assert_eq!(
+        OneAxis(AlignmentAxis::Order(AlignmentAxisOrder::Chaotic)).to_5etools_creature(),
+        json!(["C", "G", "NY", "E"])
+    );
+
+    assert_eq!(
+        ExcludedAxis(AlignmentAxis::Order(AlignmentAxisOrder::Lawful)).to_5etools_creature(),
+        json!(["NX", "C", "G", "NY", "E"])
+    );
+
+    assert_eq!(
+        ExcludedAxis(AlignmentAxis::Moral(AlignmentAxisMoral::Good)).to_5etools_creature(),
+        json!(["L", "NX", "C", "NY", "E"])
+    );
+
+    assert_eq!(
+        Chances(vec![
+            (
+                TwoAxes {
+                    order: AlignmentAxisOrder::Neutral,
+                    moral: AlignmentAxisMoral::Good,
+                },
+                50
+            ),
+            (
+                TwoAxes {
+                    order: AlignmentAxisOrder::Neutral,
+                    moral: AlignmentAxisMoral::Evil,
+                },
+                50
+            ),
+        ])
+        .to_5etools_creature(),
+        json!([
+            { "alignment": ["N", "G"], "chance": 50 },
+            { "alignment": ["N", "E"], "chance": 50 }
+        ])
+    );
+
+    assert_eq!(
+        Note {
+            alignment: Box::new(Any),
+            note: "as its creator".to_string(),
+        }
+        .to_5etools_creature(),
+        json!([{ "alignment": ["A"], "note": "as its creator" }])
+    );
+
+    assert_eq!(
+        Special("as its creator".to_string()).to_5etools_creature(),
+        json!([{ "special": "as its creator" }])
     );
 
     assert_eq!(
@@ -182,3 +286,34 @@ fn named_entry() {
         })
     );
 }
+
+#[test]
+fn named_entry_2024() {
+    assert_eq!(
+        NamedEntry {
+            name: "Scimitar.".to_owned(),
+            entry: "*Melee Attack Roll:* +4, reach 5 ft. *Hit:* 5 (1d6 + 2) Slashing damage."
+                .to_owned(),
+            sub_entries: None,
+        }
+        .to_5etools_creature(),
+        json!({
+            "name": "Scimitar.",
+            "entries": ["{@atkr m} {@hit 4}, reach 5 ft. {@h}5 ({@damage 1d6 + 2}) Slashing damage."]
+        })
+    );
+
+    assert_eq!(
+        NamedEntry {
+            name: "Fire Breath.".to_owned(),
+            entry: "*Dexterity Saving Throw:* DC 13, each creature in a 15-foot Cone. *Failure:* 7 (2d6) Fire damage. *Success:* Half damage."
+                .to_owned(),
+            sub_entries: None,
+        }
+        .to_5etools_creature(),
+        json!({
+            "name": "Fire Breath.",
+            "entries": ["{@actSave dex} {@dc 13}, each creature in a 15-foot Cone. {@actSaveFail} 7 ({@damage 2d6}) Fire damage. {@actSaveSuccess} Half damage."]
+        })
+    );
+}
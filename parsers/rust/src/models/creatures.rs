@@ -1,12 +1,21 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
-use crate::utils::traits::{option_to_5etools_creature, To5etools};
+use crate::utils::traits::{option_to_5etools_base, option_to_5etools_creature, To5etools};
+use itertools::Itertools;
+use regex::Regex;
 use serde_json::{json, Value};
 
+pub use challenge::ChallengeRatingEstimate;
+pub use check::Inconsistency;
+
 use super::common::{
-    merge_json, AbilityScore, Alignment, DamageType, NamedEntry, Skill, Source, StatusCondition,
+    merge_json, AbilityScore, Alignment, DamageType, Description, Language, NamedEntry, Skill,
+    Source, StatusCondition,
 };
 
+mod challenge;
+mod check;
+mod tags;
 #[cfg(test)]
 mod tests;
 
@@ -85,7 +94,7 @@ impl To5etools for CreatureTypeEnum {
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HitPointsFormula {
-    pub number_of_dice: u8,
+    pub number_of_dice: u16,
     pub die_size: u8,
     pub modifier: i16,
 }
@@ -103,64 +112,262 @@ impl To5etools for HitPointsFormula {
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct HitPoints {
-    pub average: u16,
-    pub formula: HitPointsFormula,
+pub enum HitPoints {
+    /// Average with the dice formula, e.g. `10 (1d10 + 4)`.
+    Rolled {
+        average: u16,
+        formula: HitPointsFormula,
+    },
+    /// Average without a formula, e.g. `45`.
+    Fixed(u16),
+    /// Free text, e.g. `equal to half the conjurer's hit point maximum`.
+    Special(String),
+}
+
+impl HitPoints {
+    #[must_use]
+    pub const fn average(&self) -> Option<u16> {
+        match self {
+            Self::Rolled { average, .. } | Self::Fixed(average) => Some(*average),
+            Self::Special(_) => None,
+        }
+    }
 }
 
 impl To5etools for HitPoints {
     fn to_5etools_base(&self) -> Value {
-        json!({
-            "average": self.average,
-            "formula": self.formula.to_5etools_base(),
+        match self {
+            Self::Rolled { average, formula } => json!({
+                "average": average,
+                "formula": formula.to_5etools_base(),
+            }),
+            Self::Fixed(average) => json!({ "average": average }),
+            Self::Special(special) => json!({ "special": special }),
+        }
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpeedMode {
+    Walk,
+    Burrow,
+    Climb,
+    Crawl,
+    Fly,
+    Swim,
+}
+
+impl To5etools for SpeedMode {
+    fn to_5etools_base(&self) -> Value {
+        use SpeedMode::*;
+        json!(match self {
+            Walk => "walk",
+            Burrow => "burrow",
+            Climb => "climb",
+            Crawl => "crawl",
+            Fly => "fly",
+            Swim => "swim",
         })
     }
 }
 
+/// A speed in feet, e.g. `60` or `60 ft. (hover)`.
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct FlySpeed {
+pub struct SpeedValue {
     pub speed: u16,
-    pub hover: bool,
+    pub condition: Option<String>,
 }
 
-impl To5etools for FlySpeed {
-    fn to_5etools_base(&self) -> Value {
-        if self.hover {
-            json!({
-                "number": self.speed,
-                "condition": "(hover)",
-            })
-        } else {
-            json!(self.speed)
+impl From<u16> for SpeedValue {
+    fn from(speed: u16) -> Self {
+        Self {
+            speed,
+            condition: None,
         }
     }
 }
 
+impl To5etools for SpeedValue {
+    fn to_5etools_base(&self) -> Value {
+        self.condition.as_ref().map_or_else(
+            || json!(self.speed),
+            |condition| {
+                json!({
+                    "number": self.speed,
+                    "condition": condition,
+                })
+            },
+        )
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Speed {
-    pub walk: u16,
-    pub burrow: Option<u16>,
-    pub climb: Option<u16>,
-    pub crawl: Option<u16>,
-    pub fly: Option<FlySpeed>,
-    pub swim: Option<u16>,
+    pub walk: SpeedValue,
+    pub burrow: Option<SpeedValue>,
+    pub climb: Option<SpeedValue>,
+    pub crawl: Option<SpeedValue>,
+    pub fly: Option<SpeedValue>,
+    pub swim: Option<SpeedValue>,
+    pub can_hover: bool,
+    /// Speeds in other forms, e.g. `40 ft. in bear form`.
+    pub alternate: Option<HashMap<SpeedMode, Vec<SpeedValue>>>,
 }
 
 impl To5etools for Speed {
     fn to_5etools_base(&self) -> Value {
         merge_json(vec![
-            json!({"walk": self.walk}),
+            json!({"walk": self.walk.to_5etools_creature()}),
             option_to_5etools_creature(self.burrow.as_ref(), "burrow"),
             option_to_5etools_creature(self.climb.as_ref(), "climb"),
             option_to_5etools_creature(self.crawl.as_ref(), "crawl"),
             option_to_5etools_creature(self.fly.as_ref(), "fly"),
             option_to_5etools_creature(self.swim.as_ref(), "swim"),
+            if self.can_hover {
+                json!({"canHover": true})
+            } else {
+                json!({})
+            },
+            option_to_5etools_creature(self.alternate.as_ref(), "alternate"),
         ])
     }
 }
 
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SenseType {
+    Blindsight,
+    Darkvision,
+    Tremorsense,
+    Truesight,
+    /// A sense 5etools has no tag for, e.g. `sense magic`, kept as written.
+    Other(String),
+}
+
+impl To5etools for SenseType {
+    fn to_5etools_base(&self) -> Value {
+        use SenseType::*;
+        Value::String(
+            match self {
+                Blindsight => "blindsight",
+                Darkvision => "darkvision",
+                Tremorsense => "tremorsense",
+                Truesight => "truesight",
+                Other(sense) => sense,
+            }
+            .to_owned(),
+        )
+    }
+}
+
+/// A special sense with its range in feet, e.g. `blindsight 30 ft. (blind beyond this radius)`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sense {
+    pub sense_type: SenseType,
+    /// Range in feet, or `None` for a sense written without one.
+    pub range: Option<u16>,
+    pub blind_beyond_radius: bool,
+    /// Any other text in parentheses, e.g. `with devil's sight`.
+    pub note: Option<String>,
+}
+
+impl To5etools for Sense {
+    fn to_5etools_base(&self) -> Value {
+        Value::String(format!(
+            "{}{}{}",
+            self.sense_type
+                .to_5etools_base()
+                .as_str()
+                .unwrap_or_default(),
+            self.range
+                .map_or_else(String::new, |range| format!(" {range} ft.")),
+            if self.blind_beyond_radius {
+                " (blind beyond this radius)".to_string()
+            } else {
+                self.note
+                    .as_ref()
+                    .map_or_else(String::new, |note| format!(" ({note})"))
+            }
+        ))
+    }
+}
+
+/// A choice of languages, e.g. `any two languages` or `any one language (usually Common)`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnyLanguages {
+    pub count: u8,
+    pub note: Option<String>,
+}
+
+impl To5etools for AnyLanguages {
+    fn to_5etools_base(&self) -> Value {
+        let count = match self.count {
+            1 => "one".to_string(),
+            2 => "two".to_string(),
+            3 => "three".to_string(),
+            4 => "four".to_string(),
+            5 => "five".to_string(),
+            6 => "six".to_string(),
+            count => count.to_string(),
+        };
+        let plural = if self.count == 1 { "" } else { "s" };
+        Value::String(self.note.as_ref().map_or_else(
+            || format!("any {count} language{plural}"),
+            |note| format!("any {count} language{plural} {note}"),
+        ))
+    }
+}
+
+/// The languages line of a stat block. A creature without languages (`—`) has all fields empty.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Languages {
+    pub spoken: Vec<Language>,
+    /// Languages the creature understands but can't speak.
+    pub understood: Vec<Language>,
+    pub any: Option<AnyLanguages>,
+    /// Range of the creature's telepathy in feet.
+    pub telepathy: Option<u16>,
+}
+
+impl To5etools for Languages {
+    fn to_5etools_base(&self) -> Value {
+        let name = |language: &Language| {
+            language
+                .to_5etools_base()
+                .as_str()
+                .unwrap_or_default()
+                .to_string()
+        };
+        let mut languages = self.spoken.iter().map(name).collect::<Vec<_>>();
+        if let Some((last, rest)) = self.understood.split_last() {
+            let understood = if rest.is_empty() {
+                name(last)
+            } else {
+                format!("{} and {}", rest.iter().map(name).join(", "), name(last))
+            };
+            languages.push(format!("understands {understood} but can't speak"));
+        }
+        if let Some(any) = &self.any {
+            languages.push(
+                any.to_5etools_base()
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+        }
+        if let Some(telepathy) = self.telepathy {
+            languages.push(format!("telepathy {telepathy} ft."));
+        }
+        json!(languages)
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AbilityScores {
@@ -172,19 +379,139 @@ pub struct AbilityScores {
     pub charisma: u8,
 }
 
+impl AbilityScores {
+    #[must_use]
+    pub const fn score(&self, ability: &AbilityScore) -> u8 {
+        use AbilityScore::*;
+        match ability {
+            Strength => self.strength,
+            Dexterity => self.dexterity,
+            Constitution => self.constitution,
+            Intelligence => self.intelligence,
+            Wisdom => self.wisdom,
+            Charisma => self.charisma,
+        }
+    }
+
+    /// The modifier of an ability score, e.g. -1 for 8 or 9 and +2 for 14 or 15.
+    #[must_use]
+    pub fn modifier(&self, ability: &AbilityScore) -> i8 {
+        i8::try_from(self.score(ability) / 2).unwrap_or(i8::MAX) - 5
+    }
+}
+
 impl To5etools for AbilityScores {
     fn to_5etools_base(&self) -> Value {
         json!({
             "str": self.strength,
             "dex": self.dexterity,
             "con": self.constitution,
-            "int": self.constitution,
+            "int": self.intelligence,
             "wis": self.wisdom,
             "cha": self.charisma,
         })
     }
 }
 
+/// A saving throw or skill bonus, e.g. `+5` or `+5 (+10 with sight)`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bonus {
+    pub bonus: i8,
+    pub note: Option<String>,
+}
+
+impl From<i8> for Bonus {
+    fn from(bonus: i8) -> Self {
+        Self { bonus, note: None }
+    }
+}
+
+impl To5etools for Bonus {
+    fn to_5etools_base(&self) -> Value {
+        self.note.as_ref().map_or_else(
+            || json!(self.bonus),
+            |note| json!(format!("{:+} {note}", self.bonus)),
+        )
+    }
+}
+
+fn bonuses_to_5etools<T: To5etools>(bonuses: &[(T, Bonus)]) -> Value {
+    Value::Object(
+        bonuses
+            .iter()
+            .map(|(key, bonus)| {
+                (
+                    key.to_5etools_base()
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_owned(),
+                    bonus.to_5etools_base(),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Saving throws in the order of the stat block.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SavingThrows {
+    pub saves: Vec<(AbilityScore, Bonus)>,
+    /// Text that is not a saving throw bonus, e.g. `advantage against spells`.
+    pub special: Option<String>,
+}
+
+impl SavingThrows {
+    #[must_use]
+    pub fn get(&self, ability: &AbilityScore) -> Option<&Bonus> {
+        self.saves
+            .iter()
+            .find(|(save, _)| save == ability)
+            .map(|(_, bonus)| bonus)
+    }
+}
+
+impl To5etools for SavingThrows {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            bonuses_to_5etools(&self.saves),
+            option_to_5etools_base(self.special.as_ref(), "special"),
+        ])
+    }
+}
+
+/// Skills in the order of the stat block.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Skills {
+    pub skills: Vec<(Skill, Bonus)>,
+    /// Skills of which the creature has one, e.g. `plus one of the following: Arcana +5, ...`.
+    pub one_of: Option<Vec<(Skill, Bonus)>>,
+}
+
+impl Skills {
+    #[must_use]
+    pub fn get(&self, skill: &Skill) -> Option<&Bonus> {
+        self.skills
+            .iter()
+            .find(|(other, _)| other == skill)
+            .map(|(_, bonus)| bonus)
+    }
+}
+
+impl To5etools for Skills {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            bonuses_to_5etools(&self.skills),
+            self.one_of.as_ref().map_or_else(
+                || json!({}),
+                |one_of| json!({"other": [{"oneOf": bonuses_to_5etools(one_of)}]}),
+            ),
+        ])
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DamageModifierType {
@@ -244,18 +571,38 @@ impl To5etools for DamageModifier {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreatureType {
     pub main_type: CreatureTypeEnum,
-    pub subtypes: Option<Vec<String>>,
+    pub subtypes: Option<Vec<CreatureTypeTag>>,
+    /// Size of the individual creatures of a swarm, e.g. `swarm of Tiny beasts`.
+    pub swarm_size: Option<Size>,
 }
 
 impl To5etools for CreatureType {
     fn to_5etools_base(&self) -> Value {
-        match &self.subtypes {
-            None => self.main_type.to_5etools_base(),
-            Some(subtypes) => json!({
-                "type": self.main_type.to_5etools_base(),
-                "tags": subtypes,
-            }),
+        if self.subtypes.is_none() && self.swarm_size.is_none() {
+            return self.main_type.to_5etools_base();
         }
+        merge_json(vec![
+            json!({"type": self.main_type.to_5etools_base()}),
+            option_to_5etools_base(self.subtypes.as_ref(), "tags"),
+            option_to_5etools_base(self.swarm_size.as_ref(), "swarmSize"),
+        ])
+    }
+}
+
+/// A creature type tag, e.g. `demon`, optionally with a prefix, e.g. `Chondathan human`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatureTypeTag {
+    pub tag: String,
+    pub prefix: Option<String>,
+}
+
+impl To5etools for CreatureTypeTag {
+    fn to_5etools_base(&self) -> Value {
+        self.prefix.as_ref().map_or_else(
+            || json!(self.tag),
+            |prefix| json!({"tag": self.tag, "prefix": prefix}),
+        )
     }
 }
 
@@ -264,17 +611,26 @@ impl To5etools for CreatureType {
 pub struct ArmorClass {
     pub ac: u8,
     pub armor_type: Option<Vec<String>>,
+    pub condition: Option<String>,
+    /// Whether the entry is an alternative given in parentheses, e.g. `12 (15 with mage armor)`.
+    pub braces: bool,
 }
 
 impl To5etools for ArmorClass {
     fn to_5etools_base(&self) -> Value {
-        match &self.armor_type {
-            None => json!([self.ac]),
-            Some(armor) => json!([{
-                    "ac": self.ac,
-                    "from": armor,
-            }]),
+        if self.armor_type.is_none() && self.condition.is_none() && !self.braces {
+            return json!(self.ac);
         }
+        merge_json(vec![
+            json!({ "ac": self.ac }),
+            option_to_5etools_base(self.armor_type.as_ref(), "from"),
+            option_to_5etools_base(self.condition.as_ref(), "condition"),
+            if self.braces {
+                json!({ "braces": true })
+            } else {
+                json!({})
+            },
+        ])
     }
 }
 
@@ -287,15 +643,258 @@ pub enum ChallengeRating {
     Eighth,
 }
 
+impl ChallengeRating {
+    /// The proficiency bonus of a creature with this challenge rating.
+    #[must_use]
+    pub fn proficiency_bonus(&self) -> i8 {
+        match self {
+            Self::WholeNumber(cr) => {
+                2_i8.saturating_add(i8::try_from(cr.saturating_sub(1) / 4).unwrap_or(i8::MAX))
+            }
+            _ => 2,
+        }
+    }
+}
+
+impl fmt::Display for ChallengeRating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ChallengeRating::*;
+        match self {
+            WholeNumber(number) => write!(f, "{number}"),
+            Half => write!(f, "1/2"),
+            Quarter => write!(f, "1/4"),
+            Eighth => write!(f, "1/8"),
+        }
+    }
+}
+
 impl To5etools for ChallengeRating {
     fn to_5etools_base(&self) -> Value {
-        use ChallengeRating::*;
-        Value::String(match self {
-            WholeNumber(number) => number.to_string(),
-            Half => "1/2".to_string(),
-            Quarter => "1/4".to_string(),
-            Eighth => "1/8".to_string(),
+        Value::String(self.to_string())
+    }
+}
+
+/// Challenge rating as stated in a stat block, with the experience points and the
+/// alternative ratings when encountered in its lair or as part of a coven.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Challenge {
+    pub rating: ChallengeRating,
+    pub experience_points: Option<u32>,
+    pub lair: Option<ChallengeRating>,
+    pub coven: Option<ChallengeRating>,
+}
+
+impl To5etools for Challenge {
+    fn to_5etools_base(&self) -> Value {
+        let nonstandard_xp = self
+            .experience_points
+            .filter(|&xp| !self.rating.is_standard_experience_points(xp));
+        if self.lair.is_none() && self.coven.is_none() && nonstandard_xp.is_none() {
+            return self.rating.to_5etools_base();
+        }
+        merge_json(vec![
+            json!({ "cr": self.rating.to_5etools_base() }),
+            option_to_5etools_creature(self.lair.as_ref(), "lair"),
+            option_to_5etools_creature(self.coven.as_ref(), "coven"),
+            option_to_5etools_creature(nonstandard_xp.as_ref(), "xp"),
+        ])
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpellSlotLevel {
+    pub level: u8,
+    /// Lowest level of the spells in the group, for e.g. warlock style `1st-5th level`.
+    pub lower: Option<u8>,
+    pub slots: Option<u8>,
+    pub spells: Vec<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DailySpells {
+    pub uses: u8,
+    /// Whether the uses apply to each spell (`3/day each`) or are shared (`3/day`).
+    pub each: bool,
+    pub spells: Vec<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spellcasting {
+    pub name: String,
+    pub header_entries: Vec<String>,
+    pub ability: Option<AbilityScore>,
+    pub save_dc: Option<u8>,
+    pub attack_bonus: Option<i8>,
+    pub at_will: Option<Vec<String>>,
+    pub daily: Option<Vec<DailySpells>>,
+    pub spell_slots: Option<Vec<SpellSlotLevel>>,
+    pub footer_entries: Option<Vec<String>>,
+}
+
+/// Wrap the names of spells in `{@spell}` tags, leaving any parenthesised notes untagged.
+fn tag_spells(spells: &[String]) -> Value {
+    spells
+        .iter()
+        .map(|spell| {
+            spell.split_once(" (").map_or_else(
+                || format!("{{@spell {spell}}}"),
+                |(name, note)| format!("{{@spell {name}}} ({note}"),
+            )
         })
+        .collect()
+}
+
+/// Tag save DCs and attack bonuses in the header or footer of a spellcasting trait.
+fn tag_spellcasting_entries(entries: &[String]) -> Value {
+    let dc_re = Regex::new(r"DC (?P<dc>\d+)").unwrap();
+    let to_hit_re = Regex::new(r"\+?(?P<to_hit>-?\d+) to hit").unwrap();
+    entries
+        .iter()
+        .map(|entry| {
+            let entry = dc_re.replace_all(entry, "{@dc $dc}");
+            to_hit_re
+                .replace_all(&entry, "{@hit $to_hit} to hit")
+                .to_string()
+        })
+        .collect()
+}
+
+impl To5etools for Spellcasting {
+    fn to_5etools_base(&self) -> Value {
+        let will = self
+            .at_will
+            .as_ref()
+            .map_or_else(|| json!({}), |spells| json!({ "will": tag_spells(spells) }));
+        let daily = self.daily.as_ref().map_or_else(
+            || json!({}),
+            |daily| {
+                let daily_map = daily
+                    .iter()
+                    .map(|daily_spells| {
+                        let each = if daily_spells.each { "e" } else { "" };
+                        (
+                            format!("{uses}{each}", uses = daily_spells.uses),
+                            tag_spells(&daily_spells.spells),
+                        )
+                    })
+                    .collect::<serde_json::Map<String, Value>>();
+                json!({ "daily": daily_map })
+            },
+        );
+        let spells = self.spell_slots.as_ref().map_or_else(
+            || json!({}),
+            |spell_slots| {
+                let spells_map = spell_slots
+                    .iter()
+                    .map(|slot_level| {
+                        (
+                            slot_level.level.to_string(),
+                            merge_json(vec![
+                                json!({ "spells": tag_spells(&slot_level.spells) }),
+                                option_to_5etools_creature(slot_level.slots.as_ref(), "slots"),
+                                option_to_5etools_creature(slot_level.lower.as_ref(), "lower"),
+                            ]),
+                        )
+                    })
+                    .collect::<serde_json::Map<String, Value>>();
+                json!({ "spells": spells_map })
+            },
+        );
+        let footer = self.footer_entries.as_ref().map_or_else(
+            || json!({}),
+            |entries| json!({ "footerEntries": tag_spellcasting_entries(entries) }),
+        );
+
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "headerEntries": tag_spellcasting_entries(&self.header_entries),
+            }),
+            option_to_5etools_creature(self.ability.as_ref(), "ability"),
+            will,
+            daily,
+            spells,
+            footer,
+        ])
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearItem {
+    pub name: String,
+    pub quantity: Option<u16>,
+}
+
+impl To5etools for GearItem {
+    fn to_5etools_base(&self) -> Value {
+        let item = self.name.to_lowercase();
+        self.quantity.map_or_else(
+            || json!(item),
+            |quantity| json!({ "item": item, "quantity": quantity }),
+        )
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegendaryHeader {
+    pub actions_per_round: u8,
+    /// Number of legendary actions per round while in its lair (2024 format).
+    pub actions_in_lair: Option<u8>,
+    /// Introductory text of the section, only kept when it differs from the standard wording.
+    pub header: Option<String>,
+}
+
+impl To5etools for LegendaryHeader {
+    fn to_5etools_base(&self) -> Value {
+        // 5etools assumes 3 legendary actions when the count is omitted.
+        let actions_per_round = if self.actions_per_round == 3 {
+            json!({})
+        } else {
+            json!({ "legendaryActions": self.actions_per_round })
+        };
+        merge_json(vec![
+            actions_per_round,
+            option_to_5etools_creature(self.actions_in_lair.as_ref(), "legendaryActionsLair"),
+            self.header.as_ref().map_or_else(
+                || json!({}),
+                |header| json!({ "legendaryHeader": [header] }),
+            ),
+        ])
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegendaryGroup<'a> {
+    pub name: String,
+    pub source: Source<'a>,
+    pub lair_actions: Option<Vec<Description>>,
+    pub regional_effects: Option<Vec<Description>>,
+}
+
+impl<'a> LegendaryGroup<'a> {
+    /// Reference from a monster to its legendary group.
+    fn to_5etools_reference(&self) -> Value {
+        json!({
+            "name": self.name,
+            "source": self.source.source_book,
+        })
+    }
+}
+
+impl<'a> To5etools for LegendaryGroup<'a> {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            self.to_5etools_reference(),
+            option_to_5etools_creature(self.lair_actions.as_ref(), "lairActions"),
+            option_to_5etools_creature(self.regional_effects.as_ref(), "regionalEffects"),
+        ])
     }
 }
 
@@ -304,52 +903,67 @@ impl To5etools for ChallengeRating {
 pub struct Creature<'a> {
     pub name: String,
     pub source: Source<'a>,
-    pub size: Size,
-    pub creature_type: CreatureTypeEnum,
+    pub size: Vec<Size>,
+    pub creature_type: CreatureType,
     pub alignment: Alignment,
-    pub armor_class: ArmorClass,
+    pub armor_class: Vec<ArmorClass>,
+    pub initiative: Option<i8>,
     pub hit_points: HitPoints,
     pub speed: Speed,
     pub ability_scores: AbilityScores,
-    pub saving_throws: Option<HashMap<AbilityScore, u8>>,
-    pub skills: Option<HashMap<Skill, i8>>,
-    pub senses: Option<Vec<String>>,
+    pub saving_throws: Option<SavingThrows>,
+    pub skills: Option<Skills>,
+    pub senses: Option<Vec<Sense>>,
     pub passive_perception: u8,
     pub damage_resistance: Option<Vec<DamageModifier>>,
     pub damage_immunity: Option<Vec<DamageModifier>>,
     pub damage_vulnerability: Option<Vec<DamageModifier>>,
     pub condition_immunities: Option<Vec<StatusCondition>>,
-    pub languages: Vec<String>,
-    pub challenge_rating: ChallengeRating,
+    pub gear: Option<Vec<GearItem>>,
+    pub languages: Languages,
+    pub challenge_rating: Challenge,
     pub abilities: Option<Vec<NamedEntry>>,
+    pub spellcasting: Option<Vec<Spellcasting>>,
     pub actions: Option<Vec<NamedEntry>>,
     pub bonus_actions: Option<Vec<NamedEntry>>,
     pub reactions: Option<Vec<NamedEntry>>,
+    pub legendary_header: Option<LegendaryHeader>,
     pub legendary_actions: Option<Vec<NamedEntry>>,
     pub mythic_actions: Option<Vec<NamedEntry>>,
     pub mythic_header: Option<String>,
+    pub legendary_group: Option<LegendaryGroup<'a>>,
 }
 
 impl<'a> To5etools for Creature<'a> {
     fn to_5etools_base(&self) -> Value {
         let main_body = json!({
             "name": self.name,
-            "size": [self.size.to_5etools_creature()],
+            "size": self.size.to_5etools_creature(),
             "type": self.creature_type.to_5etools_creature(),
             "alignment": self.alignment.to_5etools_creature(),
             "ac": self.armor_class.to_5etools_creature(),
             "hp": self.hit_points.to_5etools_creature(),
             "speed": self.speed.to_5etools_creature(),
             "passive": self.passive_perception,
-            "languages": self.languages,
             "cr": self.challenge_rating.to_5etools_creature(),
         });
 
         let ability_scores = self.ability_scores.to_5etools_creature();
         let source = self.source.to_5etools_creature();
+        let initiative = option_to_5etools_creature(self.initiative.as_ref(), "initiative");
         let saving_throws = option_to_5etools_creature(self.saving_throws.as_ref(), "save");
         let skills = option_to_5etools_creature(self.skills.as_ref(), "skill");
         let senses = option_to_5etools_creature(self.senses.as_ref(), "senses");
+        let alignment_prefix = if matches!(self.alignment, Alignment::Typically(_)) {
+            json!({ "alignmentPrefix": "typically " })
+        } else {
+            json!({})
+        };
+        let languages = if self.languages == Languages::default() {
+            json!({})
+        } else {
+            json!({ "languages": self.languages.to_5etools_creature() })
+        };
         let damage_resistance =
             option_to_5etools_creature(self.damage_resistance.as_ref(), "resist");
         let damage_immunity = option_to_5etools_creature(self.damage_immunity.as_ref(), "immune");
@@ -357,18 +971,31 @@ impl<'a> To5etools for Creature<'a> {
             option_to_5etools_creature(self.damage_vulnerability.as_ref(), "vulnerable");
         let condition_immunities =
             option_to_5etools_creature(self.condition_immunities.as_ref(), "conditionImmune");
+        let gear = option_to_5etools_creature(self.gear.as_ref(), "gear");
         let abilities = option_to_5etools_creature(self.abilities.as_ref(), "trait");
+        let spellcasting = option_to_5etools_creature(self.spellcasting.as_ref(), "spellcasting");
         let actions = option_to_5etools_creature(self.actions.as_ref(), "action");
         let bonus_actions = option_to_5etools_creature(self.bonus_actions.as_ref(), "bonus");
         let reactions = option_to_5etools_creature(self.reactions.as_ref(), "reaction");
+        let legendary_header = self
+            .legendary_header
+            .as_ref()
+            .map_or_else(|| json!({}), To5etools::to_5etools_creature);
         let legendary_actions =
             option_to_5etools_creature(self.legendary_actions.as_ref(), "legendary");
         let mythic_header = option_to_5etools_creature(self.mythic_header.as_ref(), "mythicHeader");
         let mythic_actions = option_to_5etools_creature(self.mythic_actions.as_ref(), "mythic");
+        let legendary_group = self.legendary_group.as_ref().map_or_else(
+            || json!({}),
+            |group| json!({ "legendaryGroup": group.to_5etools_reference() }),
+        );
+        let tags = tags::creature_tags(self);
 
         merge_json(vec![
             source,
             main_body,
+            alignment_prefix,
+            initiative,
             ability_scores,
             saving_throws,
             skills,
@@ -377,13 +1004,19 @@ impl<'a> To5etools for Creature<'a> {
             damage_immunity,
             damage_vulnerability,
             condition_immunities,
+            gear,
+            languages,
             abilities,
+            spellcasting,
             actions,
             bonus_actions,
             reactions,
+            legendary_header,
             legendary_actions,
             mythic_header,
             mythic_actions,
+            legendary_group,
+            tags,
         ])
     }
 }
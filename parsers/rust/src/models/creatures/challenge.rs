@@ -0,0 +1,285 @@
+use regex::Regex;
+
+use crate::models::common::DamageType;
+
+use super::{ChallengeRating, Creature, DamageModifier};
+
+/// A row of the "Monster Statistics by Challenge Rating" table of the DMG.
+struct StatisticsRow {
+    armor_class: i16,
+    max_hit_points: u16,
+    attack_bonus: i16,
+    max_damage_per_round: u16,
+    save_dc: i16,
+}
+
+const fn row(
+    armor_class: i16,
+    max_hit_points: u16,
+    attack_bonus: i16,
+    max_damage_per_round: u16,
+    save_dc: i16,
+) -> StatisticsRow {
+    StatisticsRow {
+        armor_class,
+        max_hit_points,
+        attack_bonus,
+        max_damage_per_round,
+        save_dc,
+    }
+}
+
+/// Rows are ordered by challenge rating: 0, 1/8, 1/4, 1/2, 1, 2, ..., 30.
+const MONSTER_STATISTICS: [StatisticsRow; 34] = [
+    row(13, 6, 3, 1, 13),
+    row(13, 35, 3, 3, 13),
+    row(13, 49, 3, 5, 13),
+    row(13, 70, 3, 8, 13),
+    row(13, 85, 3, 14, 13),
+    row(13, 100, 3, 20, 13),
+    row(13, 115, 4, 26, 13),
+    row(14, 130, 5, 32, 14),
+    row(15, 145, 6, 38, 15),
+    row(15, 160, 6, 44, 15),
+    row(15, 175, 6, 50, 15),
+    row(16, 190, 7, 56, 16),
+    row(16, 205, 7, 62, 16),
+    row(17, 220, 7, 68, 16),
+    row(17, 235, 8, 74, 17),
+    row(17, 250, 8, 80, 17),
+    row(18, 265, 8, 86, 18),
+    row(18, 280, 8, 92, 18),
+    row(18, 295, 8, 98, 18),
+    row(18, 310, 9, 104, 18),
+    row(19, 325, 10, 110, 19),
+    row(19, 340, 10, 116, 19),
+    row(19, 355, 10, 122, 19),
+    row(19, 400, 10, 140, 19),
+    row(19, 445, 11, 158, 20),
+    row(19, 490, 11, 176, 20),
+    row(19, 535, 11, 194, 20),
+    row(19, 580, 12, 212, 21),
+    row(19, 625, 12, 230, 21),
+    row(19, 670, 12, 248, 21),
+    row(19, 715, 13, 266, 22),
+    row(19, 760, 13, 284, 22),
+    row(19, 805, 13, 302, 22),
+    row(19, 850, 14, 320, 23),
+];
+
+/// Experience points by challenge rating, ordered like `MONSTER_STATISTICS`.
+const EXPERIENCE_POINTS: [u32; 34] = [
+    10, 25, 50, 100, 200, 450, 700, 1_100, 1_800, 2_300, 2_900, 3_900, 5_000, 5_900, 7_200, 8_400,
+    10_000, 11_500, 13_000, 15_000, 18_000, 20_000, 22_000, 25_000, 33_000, 41_000, 50_000, 62_000,
+    75_000, 90_000, 105_000, 120_000, 135_000, 155_000,
+];
+
+impl ChallengeRating {
+    /// Position of the challenge rating in the DMG statistics table.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        match self {
+            Self::WholeNumber(0) => 0,
+            Self::Eighth => 1,
+            Self::Quarter => 2,
+            Self::Half => 3,
+            Self::WholeNumber(cr) => *cr as usize + 3,
+        }
+    }
+
+    #[must_use]
+    pub fn from_index(index: usize) -> Self {
+        match index {
+            0 => Self::WholeNumber(0),
+            1 => Self::Eighth,
+            2 => Self::Quarter,
+            3 => Self::Half,
+            _ => Self::WholeNumber(u8::try_from(index - 3).unwrap_or(u8::MAX)),
+        }
+    }
+
+    /// Experience points awarded for a creature of this challenge rating, or `None` above
+    /// challenge rating 30.
+    #[must_use]
+    pub fn experience_points(&self) -> Option<u32> {
+        EXPERIENCE_POINTS.get(self.index()).copied()
+    }
+
+    /// Whether the experience points match the rating, allowing 0 XP for challenge rating 0.
+    #[must_use]
+    pub fn is_standard_experience_points(&self, experience_points: u32) -> bool {
+        self.experience_points() == Some(experience_points)
+            || (experience_points == 0 && self.index() == 0)
+    }
+
+    /// The challenge rating awarding exactly the given experience points.
+    #[must_use]
+    pub fn from_experience_points(experience_points: u32) -> Option<Self> {
+        EXPERIENCE_POINTS
+            .iter()
+            .position(|&xp| xp == experience_points)
+            .map(Self::from_index)
+    }
+}
+
+/// Challenge rating computed from the statistics of a creature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChallengeRatingEstimate {
+    pub defensive: ChallengeRating,
+    pub offensive: ChallengeRating,
+    pub estimated: ChallengeRating,
+    /// Number of table rows the stated challenge rating is above (positive) or below (negative)
+    /// the estimate.
+    pub deviation: i16,
+}
+
+fn shift_index(index: usize, steps: i16) -> usize {
+    let steps_abs = usize::from(steps.unsigned_abs());
+    let shifted = if steps.is_negative() {
+        index.saturating_sub(steps_abs)
+    } else {
+        index.saturating_add(steps_abs)
+    };
+    shifted.min(MONSTER_STATISTICS.len() - 1)
+}
+
+fn row_index(value: u16, max_value: impl Fn(&StatisticsRow) -> u16) -> usize {
+    MONSTER_STATISTICS
+        .iter()
+        .position(|row| value <= max_value(row))
+        .unwrap_or(MONSTER_STATISTICS.len() - 1)
+}
+
+fn covers_weapon_damage(modifiers: Option<&Vec<DamageModifier>>) -> bool {
+    use DamageType::{Bludgeoning, Piercing, Slashing};
+    modifiers.into_iter().flatten().any(|modifier| {
+        let damage_types = match modifier {
+            DamageModifier::Unconditional(damage_type) => vec![damage_type],
+            DamageModifier::Conditional(conditional) => conditional.damage_types.iter().collect(),
+        };
+        damage_types
+            .into_iter()
+            .any(|damage_type| matches!(damage_type, Bludgeoning | Piercing | Slashing))
+    })
+}
+
+impl Creature<'_> {
+    /// Hit points multiplied according to the "Effective Hit Points Based on Resistances and
+    /// Immunities" table, using the stated challenge rating.
+    fn effective_hit_points(&self) -> u16 {
+        let (resistance_factor, immunity_factor) = match self.challenge_rating.rating.index() {
+            0..=7 => (8, 8),
+            8..=13 => (6, 8),
+            14..=19 => (5, 6),
+            _ => (4, 5),
+        };
+        let factor = if covers_weapon_damage(self.damage_immunity.as_ref()) {
+            immunity_factor
+        } else if covers_weapon_damage(self.damage_resistance.as_ref()) {
+            resistance_factor
+        } else {
+            4
+        };
+        self.hit_points
+            .average()
+            .unwrap_or_default()
+            .saturating_mul(factor)
+            / 4
+    }
+
+    fn defensive_challenge_rating(&self) -> usize {
+        let index = row_index(self.effective_hit_points(), |row| row.max_hit_points);
+        let save_bonus = if self
+            .saving_throws
+            .as_ref()
+            .map_or(0, |saving_throws| saving_throws.saves.len())
+            >= 3
+        {
+            2
+        } else {
+            0
+        };
+        let armor_class = self
+            .armor_class
+            .first()
+            .map_or(10, |armor_class| i16::from(armor_class.ac))
+            + save_bonus;
+        shift_index(
+            index,
+            (armor_class - MONSTER_STATISTICS[index].armor_class) / 2,
+        )
+    }
+
+    /// Damage per round, assuming a multiattack consists of its number of attacks with the most
+    /// damaging action.
+    fn damage_per_round(&self) -> u16 {
+        let damage_re = Regex::new(r"(?P<average>\d+) \(\d+d\d+(?: [+-] \d+)?\)").unwrap();
+        let multiattack_re = Regex::new(r"(?i)\b(?P<count>two|three|four|five|six)\b").unwrap();
+        let actions = self.actions.iter().flatten().collect::<Vec<_>>();
+        let max_damage = actions
+            .iter()
+            .map(|action| {
+                damage_re
+                    .captures_iter(&action.entry)
+                    .filter_map(|captures| captures["average"].parse::<u16>().ok())
+                    .sum::<u16>()
+            })
+            .max()
+            .unwrap_or_default();
+        let attacks = actions
+            .iter()
+            .find(|action| action.name.to_lowercase().starts_with("multiattack"))
+            .and_then(|multiattack| multiattack_re.captures(&multiattack.entry))
+            .map_or(1, |captures| match &captures["count"].to_lowercase()[..] {
+                "two" => 2,
+                "three" => 3,
+                "four" => 4,
+                "five" => 5,
+                _ => 6,
+            });
+        max_damage.saturating_mul(attacks)
+    }
+
+    fn offensive_challenge_rating(&self) -> usize {
+        let attack_re = Regex::new(r"Attack(?: Roll)?:\*? \+?(?P<to_hit>-?\d+)").unwrap();
+        let save_dc_re = Regex::new(r"DC (?P<dc>\d+)").unwrap();
+        let entries = self
+            .actions
+            .iter()
+            .flatten()
+            .map(|action| action.entry.as_str())
+            .collect::<Vec<_>>();
+        let best = |re: &Regex, group: &str| {
+            entries
+                .iter()
+                .flat_map(|entry| re.captures_iter(entry))
+                .filter_map(|captures| captures[group].parse::<i16>().ok())
+                .max()
+        };
+
+        let index = row_index(self.damage_per_round(), |row| row.max_damage_per_round);
+        let expected = &MONSTER_STATISTICS[index];
+        let steps = best(&attack_re, "to_hit").map_or_else(
+            || best(&save_dc_re, "dc").map_or(0, |save_dc| (save_dc - expected.save_dc) / 2),
+            |attack_bonus| (attack_bonus - expected.attack_bonus) / 2,
+        );
+        shift_index(index, steps)
+    }
+
+    /// Estimate the challenge rating following "Creating a Monster" in the DMG: the defensive
+    /// rating from hit points and armor class, the offensive rating from damage per round and
+    /// attack bonus or save DC, and their average as the final rating.
+    #[must_use]
+    pub fn estimate_challenge_rating(&self) -> ChallengeRatingEstimate {
+        let defensive = self.defensive_challenge_rating();
+        let offensive = self.offensive_challenge_rating();
+        let estimated = (defensive + offensive).div_ceil(2);
+        ChallengeRatingEstimate {
+            defensive: ChallengeRating::from_index(defensive),
+            offensive: ChallengeRating::from_index(offensive),
+            estimated: ChallengeRating::from_index(estimated),
+            deviation: i16::try_from(self.challenge_rating.rating.index()).unwrap_or(i16::MAX)
+                - i16::try_from(estimated).unwrap_or(i16::MAX),
+        }
+    }
+}
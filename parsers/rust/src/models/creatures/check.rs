@@ -0,0 +1,203 @@
+use std::fmt;
+
+use regex::Regex;
+
+use crate::{
+    models::common::{AbilityScore, Skill},
+    utils::traits::To5etools,
+};
+
+use super::{Creature, HitPoints};
+
+/// A value in a stat block that does not match the value derived from the rest of the stat block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inconsistency {
+    pub field: String,
+    pub found: i32,
+    pub expected: i32,
+}
+
+impl fmt::Display for Inconsistency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: found {}, expected {}",
+            self.field, self.found, self.expected
+        )
+    }
+}
+
+fn compare(
+    field: String,
+    found: impl Into<i32>,
+    expected: impl Into<i32>,
+) -> Option<Inconsistency> {
+    let (found, expected) = (found.into(), expected.into());
+    (found != expected).then_some(Inconsistency {
+        field,
+        found,
+        expected,
+    })
+}
+
+const fn ability_name(ability: &AbilityScore) -> &'static str {
+    use AbilityScore::*;
+    match ability {
+        Strength => "Strength",
+        Dexterity => "Dexterity",
+        Constitution => "Constitution",
+        Intelligence => "Intelligence",
+        Wisdom => "Wisdom",
+        Charisma => "Charisma",
+    }
+}
+
+impl Creature<'_> {
+    fn modifier(&self, ability: &AbilityScore) -> i16 {
+        i16::from(self.ability_scores.modifier(ability))
+    }
+
+    fn proficiency_bonus(&self) -> i16 {
+        i16::from(self.challenge_rating.rating.proficiency_bonus())
+    }
+
+    fn check_hit_points(&self) -> Vec<Inconsistency> {
+        let HitPoints::Rolled { average, formula } = &self.hit_points else {
+            return vec![];
+        };
+        let number_of_dice = i32::from(formula.number_of_dice);
+        let average_roll = number_of_dice * (i32::from(formula.die_size) + 1) / 2;
+        [
+            compare(
+                "Hit points average".to_string(),
+                i32::from(*average),
+                average_roll + i32::from(formula.modifier),
+            ),
+            compare(
+                "Hit points modifier".to_string(),
+                i32::from(formula.modifier),
+                number_of_dice * i32::from(self.modifier(&AbilityScore::Constitution)),
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    fn check_passive_perception(&self) -> Option<Inconsistency> {
+        let perception = self
+            .skills
+            .as_ref()
+            .and_then(|skills| skills.get(&Skill::Perception))
+            .map_or_else(
+                || self.modifier(&AbilityScore::Wisdom),
+                |bonus| i16::from(bonus.bonus),
+            );
+        compare(
+            "Passive Perception".to_string(),
+            i16::from(self.passive_perception),
+            10 + perception,
+        )
+    }
+
+    fn check_saving_throws(&self) -> Vec<Inconsistency> {
+        self.saving_throws
+            .iter()
+            .flat_map(|saving_throws| &saving_throws.saves)
+            .filter_map(|(ability, bonus)| {
+                compare(
+                    format!("{} saving throw", ability_name(ability)),
+                    i16::from(bonus.bonus),
+                    self.modifier(ability) + self.proficiency_bonus(),
+                )
+            })
+            .collect()
+    }
+
+    fn check_skills(&self) -> Vec<Inconsistency> {
+        self.skills
+            .iter()
+            .flat_map(|skills| &skills.skills)
+            .filter_map(|(skill, bonus)| {
+                let bonus = bonus.bonus;
+                let proficient = self.modifier(&skill.ability()) + self.proficiency_bonus();
+                let expertise = proficient + self.proficiency_bonus();
+                // Creatures with expertise add their proficiency bonus twice.
+                if i16::from(bonus) == expertise {
+                    None
+                } else {
+                    compare(
+                        format!(
+                            "{} skill",
+                            skill.to_5etools_base().as_str().unwrap_or_default()
+                        ),
+                        i16::from(bonus),
+                        proficient,
+                    )
+                }
+            })
+            .collect()
+    }
+
+    fn check_experience_points(&self) -> Option<Inconsistency> {
+        let challenge = &self.challenge_rating;
+        challenge
+            .experience_points
+            .filter(|&xp| !challenge.rating.is_standard_experience_points(xp))
+            .zip(challenge.rating.experience_points())
+            .map(|(xp, expected)| Inconsistency {
+                field: "Experience points".to_string(),
+                found: i32::try_from(xp).unwrap_or(i32::MAX),
+                expected: i32::try_from(expected).unwrap_or(i32::MAX),
+            })
+    }
+
+    /// Weapon attacks use either Strength or Dexterity (finesse and thrown weapons can use both).
+    fn check_attacks(&self) -> Vec<Inconsistency> {
+        let attack_re = Regex::new(
+            r"^\*?(?P<range>Melee or Ranged|Melee|Ranged) (?:Weapon Attack:\*?|Attack Roll:\*) \+?(?P<to_hit>-?\d+)",
+        )
+        .unwrap();
+        let strength = self.modifier(&AbilityScore::Strength) + self.proficiency_bonus();
+        let dexterity = self.modifier(&AbilityScore::Dexterity) + self.proficiency_bonus();
+        [&self.actions, &self.bonus_actions, &self.reactions]
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|action| {
+                let captures = attack_re.captures(&action.entry)?;
+                let to_hit = captures["to_hit"].parse::<i16>().ok()?;
+                let expected = if &captures["range"] == "Ranged" {
+                    dexterity
+                } else {
+                    strength.max(dexterity)
+                };
+                if to_hit == strength || to_hit == dexterity {
+                    None
+                } else {
+                    compare(
+                        format!("{} attack bonus", action.name.trim_end_matches('.')),
+                        to_hit,
+                        expected,
+                    )
+                }
+            })
+            .collect()
+    }
+
+    /// Compare the derived values of the stat block (average hit points, passive Perception,
+    /// saving throw, skill and attack bonuses) against the values derived from the ability
+    /// scores and proficiency bonus.
+    #[must_use]
+    pub fn check(&self) -> Vec<Inconsistency> {
+        [
+            self.check_hit_points(),
+            self.check_passive_perception().into_iter().collect(),
+            self.check_experience_points().into_iter().collect(),
+            self.check_saving_throws(),
+            self.check_skills(),
+            self.check_attacks(),
+        ]
+        .concat()
+    }
+}
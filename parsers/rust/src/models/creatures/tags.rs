@@ -0,0 +1,333 @@
+use std::collections::BTreeSet;
+
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::models::common::{merge_json, Language, NamedEntry};
+
+use super::{Creature, Languages, Sense, SenseType};
+
+const DAMAGE_TAGS: [(&str, &str); 13] = [
+    ("acid", "A"),
+    ("bludgeoning", "B"),
+    ("cold", "C"),
+    ("fire", "F"),
+    ("force", "O"),
+    ("lightning", "L"),
+    ("necrotic", "N"),
+    ("piercing", "P"),
+    ("poison", "I"),
+    ("psychic", "Y"),
+    ("radiant", "R"),
+    ("slashing", "S"),
+    ("thunder", "T"),
+];
+
+const CONDITIONS: [&str; 15] = [
+    "blinded",
+    "charmed",
+    "deafened",
+    "exhaustion",
+    "frightened",
+    "grappled",
+    "incapacitated",
+    "invisible",
+    "paralyzed",
+    "petrified",
+    "poisoned",
+    "prone",
+    "restrained",
+    "stunned",
+    "unconscious",
+];
+
+/// Trait name prefixes (lowercase) and the 5etools trait tag they map to.
+const TRAIT_TAGS: [(&str, &str); 45] = [
+    ("aggressive", "Aggressive"),
+    ("ambusher", "Ambusher"),
+    ("amorphous", "Amorphous"),
+    ("amphibious", "Amphibious"),
+    ("antimagic susceptibility", "Antimagic Susceptibility"),
+    ("brute", "Brute"),
+    ("charge", "Charge"),
+    ("damage absorption", "Damage Absorption"),
+    ("death burst", "Death Burst"),
+    ("devil's sight", "Devil's Sight"),
+    ("false appearance", "False Appearance"),
+    ("fey ancestry", "Fey Ancestry"),
+    ("flyby", "Flyby"),
+    ("hold breath", "Hold Breath"),
+    ("illumination", "Illumination"),
+    ("immutable form", "Immutable Form"),
+    ("incorporeal movement", "Incorporeal Movement"),
+    ("keen ", "Keen Senses"),
+    ("legendary resistance", "Legendary Resistances"),
+    ("light sensitivity", "Light Sensitivity"),
+    ("magic resistance", "Magic Resistance"),
+    ("magic weapons", "Magic Weapons"),
+    ("mimicry", "Mimicry"),
+    ("pack tactics", "Pack Tactics"),
+    ("pounce", "Pounce"),
+    ("rampage", "Rampage"),
+    ("reckless", "Reckless"),
+    ("regeneration", "Regeneration"),
+    ("rejuvenation", "Rejuvenation"),
+    ("shapechanger", "Shapechanger"),
+    ("siege monster", "Siege Monster"),
+    ("sneak attack", "Sneak Attack"),
+    ("spell immunity", "Spell Immunity"),
+    ("spider climb", "Spider Climb"),
+    ("sunlight sensitivity", "Sunlight Sensitivity"),
+    ("sunlight hypersensitivity", "Sunlight Sensitivity"),
+    ("tree stride", "Tree Stride"),
+    ("turn immunity", "Turn Immunity"),
+    ("turn resistance", "Turn Resistance"),
+    ("turn defiance", "Turn Resistance"),
+    ("undead fortitude", "Undead Fortitude"),
+    ("unusual nature", "Unusual Nature"),
+    ("water breathing", "Water Breathing"),
+    ("web sense", "Web Sense"),
+    ("web walker", "Web Walker"),
+];
+
+/// Action name fragments (lowercase) and the 5etools action tag they map to.
+const ACTION_TAGS: [(&str, &str); 9] = [
+    ("breath", "Breath Weapon"),
+    ("frightful presence", "Frightful Presence"),
+    ("multiattack", "Multiattack"),
+    ("parry", "Parry"),
+    ("change shape", "Shapechanger"),
+    ("shapechange", "Shapechanger"),
+    ("swallow", "Swallow"),
+    ("teleport", "Teleport"),
+    ("tentacle", "Tentacles"),
+];
+
+fn all_entries<'b>(creature: &'b Creature) -> impl Iterator<Item = &'b NamedEntry> {
+    fn flatten(entry: &NamedEntry) -> Vec<&NamedEntry> {
+        let mut entries = vec![entry];
+        if let Some(sub_entries) = &entry.sub_entries {
+            entries.extend(sub_entries.iter().flat_map(flatten));
+        }
+        entries
+    }
+    [
+        &creature.abilities,
+        &creature.actions,
+        &creature.bonus_actions,
+        &creature.reactions,
+        &creature.legendary_actions,
+        &creature.mythic_actions,
+    ]
+    .into_iter()
+    .flatten()
+    .flatten()
+    .flat_map(flatten)
+}
+
+fn damage_tags(texts: &[&str]) -> BTreeSet<&'static str> {
+    // Match the damage type following a roll, e.g. "10 (1d10 + 4) slashing damage"
+    let damage_re = Regex::new(r"(?i)\d+ \([^)]*\) (?P<type>[a-z]+) damage").unwrap();
+    texts
+        .iter()
+        .flat_map(|text| damage_re.captures_iter(text))
+        .filter_map(|captures| {
+            let damage_type = captures["type"].to_lowercase();
+            DAMAGE_TAGS
+                .iter()
+                .find(|(name, _)| *name == damage_type)
+                .map(|(_, tag)| *tag)
+        })
+        .collect()
+}
+
+fn condition_inflict(texts: &[&str]) -> BTreeSet<&'static str> {
+    CONDITIONS
+        .into_iter()
+        .filter(|condition| {
+            // Immunities, e.g. "can't be charmed", don't inflict the condition.
+            let inflict_re = Regex::new(&format!(
+                r"(?i)(?P<negation>\b(?:can't|can’t|cannot|not) )?\b(?:be|is|are|becomes?|falls?|knocked|has the|gains? the) (?:also )?(?:knocked )?{condition}\b"
+            ))
+            .unwrap();
+            texts.iter().any(|text| {
+                inflict_re
+                    .captures_iter(text)
+                    .any(|captures| captures.name("negation").is_none())
+            })
+        })
+        .collect()
+}
+
+fn misc_tags(texts: &[&str]) -> BTreeSet<&'static str> {
+    let reach_re = Regex::new(r"(?i)reach (?P<reach>\d+) ft").unwrap();
+    let area_re = Regex::new(
+        r"(?i)\d+-foot(?:-radius|-long|-wide)?[ -](?:cone|cube|cylinder|emanation|line|sphere|radius)",
+    )
+    .unwrap();
+    let patterns = [
+        ("MW", r"(?i)melee (?:or ranged )?weapon attack"),
+        ("RW", r"(?i)(?:melee or )?ranged weapon attack"),
+        (
+            "MA",
+            r"(?i)melee (?:or ranged )?(?:spell attack|attack roll)",
+        ),
+        (
+            "RA",
+            r"(?i)(?:melee or )?ranged (?:spell attack|attack roll)",
+        ),
+        ("HPR", r"(?i)hit point maximum (?:is )?reduced"),
+        ("CUR", r"(?i)\bcursed\b"),
+        ("DIS", r"(?i)\bdisease\b"),
+    ];
+
+    let mut tags: BTreeSet<&str> = patterns
+        .into_iter()
+        .filter(|(_, pattern)| {
+            let re = Regex::new(pattern).unwrap();
+            texts.iter().any(|text| re.is_match(text))
+        })
+        .map(|(tag, _)| tag)
+        .collect();
+    if texts.iter().any(|text| area_re.is_match(text)) {
+        tags.insert("AOE");
+    }
+    if texts.iter().any(|text| {
+        reach_re.captures_iter(text).any(|captures| {
+            captures["reach"]
+                .parse::<u16>()
+                .is_ok_and(|reach| reach > 5)
+        })
+    }) {
+        tags.insert("RCH");
+    }
+    tags
+}
+
+fn trait_tags(abilities: Option<&Vec<NamedEntry>>) -> BTreeSet<&'static str> {
+    abilities
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.name.to_lowercase();
+            TRAIT_TAGS
+                .iter()
+                .find(|(pattern, _)| name.starts_with(pattern))
+                .map(|(_, tag)| *tag)
+        })
+        .collect()
+}
+
+fn action_tags(creature: &Creature) -> BTreeSet<&'static str> {
+    [
+        &creature.actions,
+        &creature.bonus_actions,
+        &creature.reactions,
+        &creature.legendary_actions,
+    ]
+    .into_iter()
+    .flatten()
+    .flatten()
+    .filter_map(|entry| {
+        let name = entry.name.to_lowercase();
+        ACTION_TAGS
+            .iter()
+            .find(|(pattern, _)| name.contains(pattern))
+            .map(|(_, tag)| *tag)
+    })
+    .collect()
+}
+
+fn sense_tags(senses: &[Sense]) -> BTreeSet<&'static str> {
+    use SenseType::*;
+    senses
+        .iter()
+        .filter_map(|sense| match sense.sense_type {
+            Blindsight => Some("B"),
+            Darkvision if sense.range.is_some_and(|range| range >= 120) => Some("SD"),
+            Darkvision => Some("D"),
+            Tremorsense => Some("T"),
+            Truesight => Some("U"),
+            Other(_) => None,
+        })
+        .collect()
+}
+
+fn language_tag(language: &Language) -> Option<&'static str> {
+    use Language::*;
+    Some(match language {
+        Common => "C",
+        Dwarvish => "D",
+        Elvish => "E",
+        Giant => "GI",
+        Gnomish => "G",
+        Goblin => "GO",
+        Halfling => "H",
+        Orc => "O",
+        Abyssal => "AB",
+        Celestial => "CE",
+        Draconic => "DR",
+        DeepSpeech => "DS",
+        Infernal => "I",
+        Primordial => "P",
+        Sylvan => "S",
+        Undercommon => "U",
+        Aquan => "AQ",
+        Auran => "AU",
+        Ignan => "IG",
+        Terran => "T",
+        Druidic => "DU",
+        ThievesCant => "TC",
+        Gith => "GTH",
+        Other(language) if language.contains("knew in life") => "LF",
+        Other(_) => return None,
+    })
+}
+
+fn language_tags(languages: &Languages) -> BTreeSet<&'static str> {
+    let mut tags: BTreeSet<&str> = languages
+        .spoken
+        .iter()
+        .chain(&languages.understood)
+        .filter_map(language_tag)
+        .collect();
+    if !languages.understood.is_empty() {
+        tags.insert("CS");
+    }
+    if languages.any.is_some() {
+        tags.insert("X");
+    }
+    if languages.telepathy.is_some() {
+        tags.insert("TP");
+    }
+    tags
+}
+
+fn tag_entry(key: &str, tags: &BTreeSet<&str>) -> Value {
+    if tags.is_empty() {
+        json!({})
+    } else {
+        json!({ key: tags })
+    }
+}
+
+/// Derive the 5etools filter tags (damage types dealt, conditions inflicted, notable traits and
+/// actions, senses and languages) from the text of a creature's stat block.
+pub fn creature_tags(creature: &Creature) -> Value {
+    let texts = all_entries(creature)
+        .map(|entry| entry.entry.as_str())
+        .collect::<Vec<_>>();
+
+    merge_json(vec![
+        tag_entry("damageTags", &damage_tags(&texts)),
+        tag_entry("conditionInflict", &condition_inflict(&texts)),
+        tag_entry("miscTags", &misc_tags(&texts)),
+        tag_entry("traitTags", &trait_tags(creature.abilities.as_ref())),
+        tag_entry("actionTags", &action_tags(creature)),
+        tag_entry(
+            "senseTags",
+            &sense_tags(creature.senses.as_deref().unwrap_or_default()),
+        ),
+        tag_entry("languageTags", &language_tags(&creature.languages)),
+    ])
+}
@@ -1,13 +1,18 @@
-use std::{fs::File, io::BufReader};
+use std::{collections::HashMap, fs::File, io::BufReader};
 
 use serde_json::{json, Value};
 
 use crate::{
     models::{
-        common::{DamageType, NamedEntry, Source},
+        common::{
+            AbilityScore, Alignment, DamageType, Description, Language, NamedEntry, Skill, Source,
+        },
         creatures::{
-            ArmorClass, ConditionalDamageModifier, CreatureType, CreatureTypeEnum, DamageModifier,
-            DamageModifierType, FlySpeed, HitPoints, HitPointsFormula, Speed,
+            AnyLanguages, ArmorClass, Bonus, Challenge, ChallengeRating, ChallengeRatingEstimate,
+            ConditionalDamageModifier, CreatureType, CreatureTypeEnum, CreatureTypeTag,
+            DailySpells, DamageModifier, DamageModifierType, GearItem, HitPoints, HitPointsFormula,
+            Inconsistency, Languages, LegendaryGroup, LegendaryHeader, SavingThrows, Sense,
+            SenseType, Skills, Speed, SpeedMode, SpeedValue, SpellSlotLevel, Spellcasting,
         },
     },
     utils::{compare::json_compare, traits::To5etools},
@@ -18,7 +23,7 @@ use super::{Creature, Size};
 #[test]
 fn hit_points() {
     assert_eq!(
-        HitPoints {
+        HitPoints::Rolled {
             average: 91,
             formula: HitPointsFormula {
                 number_of_dice: 14,
@@ -28,19 +33,58 @@ fn hit_points() {
         }
         .to_5etools_base(),
         json!({"average": 91, "formula": "14d8 + 28"})
-    )
+    );
+
+    assert_eq!(
+        HitPoints::Fixed(45).to_5etools_base(),
+        json!({"average": 45})
+    );
+
+    assert_eq!(
+        HitPoints::Special("half the conjurer's hit point maximum".to_string()).to_5etools_base(),
+        json!({"special": "half the conjurer's hit point maximum"})
+    );
+}
+
+#[test]
+fn ability_scores() {
+    let scores = super::AbilityScores {
+        strength: 8,
+        dexterity: 14,
+        constitution: 1,
+        intelligence: 18,
+        wisdom: 9,
+        charisma: 255,
+    };
+    assert_eq!(
+        scores.to_5etools_base(),
+        json!({"str": 8, "dex": 14, "con": 1, "int": 18, "wis": 9, "cha": 255})
+    );
+    assert_eq!(
+        [
+            AbilityScore::Strength,
+            AbilityScore::Dexterity,
+            AbilityScore::Constitution,
+            AbilityScore::Wisdom,
+            AbilityScore::Charisma,
+        ]
+        .map(|ability| scores.modifier(&ability)),
+        [-1, 2, -5, -1, 122]
+    );
 }
 
 #[test]
 fn speed() {
     assert_eq!(
         Speed {
-            walk: 30,
+            walk: 30.into(),
             burrow: None,
             climb: None,
             crawl: None,
             fly: None,
             swim: None,
+            can_hover: false,
+            alternate: None,
         }
         .to_5etools_base(),
         json!({"walk": 30})
@@ -48,12 +92,14 @@ fn speed() {
 
     assert_eq!(
         Speed {
-            walk: 30,
-            burrow: Some(40),
+            walk: 30.into(),
+            burrow: Some(40.into()),
             climb: None,
             crawl: None,
             fly: None,
-            swim: Some(10),
+            swim: Some(10.into()),
+            can_hover: false,
+            alternate: None,
         }
         .to_5etools_base(),
         json!({"walk": 30, "burrow": 40, "swim": 10})
@@ -61,15 +107,14 @@ fn speed() {
 
     assert_eq!(
         Speed {
-            walk: 0,
+            walk: 0.into(),
             burrow: None,
             climb: None,
             crawl: None,
-            fly: Some(FlySpeed {
-                speed: 60,
-                hover: false
-            }),
-            swim: Some(10),
+            fly: Some(60.into()),
+            swim: Some(10.into()),
+            can_hover: false,
+            alternate: None,
         }
         .to_5etools_base(),
         json!({"walk": 0, "fly": 60, "swim": 10})
@@ -77,18 +122,48 @@ fn speed() {
 
     assert_eq!(
         Speed {
-            walk: 0,
+            walk: 0.into(),
             burrow: None,
             climb: None,
             crawl: None,
-            fly: Some(FlySpeed {
+            fly: Some(SpeedValue {
                 speed: 60,
-                hover: true
+                condition: Some("(hover)".to_string()),
+            }),
+            swim: Some(10.into()),
+            can_hover: true,
+            alternate: None,
+        }
+        .to_5etools_base(),
+        json!({"walk": 0, "fly": { "number": 60, "condition": "(hover)" }, "swim": 10, "canHover": true})
+    );
+
+    assert_eq!(
+        Speed {
+            walk: 30.into(),
+            burrow: None,
+            climb: Some(SpeedValue {
+                speed: 30,
+                condition: Some("(bear form only)".to_string()),
             }),
-            swim: Some(10),
+            crawl: None,
+            fly: None,
+            swim: None,
+            can_hover: false,
+            alternate: Some(HashMap::from([(
+                SpeedMode::Walk,
+                vec![SpeedValue {
+                    speed: 40,
+                    condition: Some("(in bear form)".to_string()),
+                }]
+            )])),
         }
         .to_5etools_base(),
-        json!({"walk": 0, "fly": { "number": 60, "condition": "(hover)" }, "swim": 10})
+        json!({
+            "walk": 30,
+            "climb": { "number": 30, "condition": "(bear form only)" },
+            "alternate": { "walk": [{ "number": 40, "condition": "(in bear form)" }] }
+        })
     );
 }
 
@@ -117,11 +192,12 @@ fn damage_resistance() {
 
 #[test]
 fn creature_type() {
-    use CreatureTypeEnum::Fiend;
+    use CreatureTypeEnum::{Beast, Fiend};
     assert_eq!(
         CreatureType {
             main_type: Fiend,
             subtypes: None,
+            swarm_size: None,
         }
         .to_5etools_base(),
         json!("fiend")
@@ -130,12 +206,35 @@ fn creature_type() {
     assert_eq!(
         CreatureType {
             main_type: Fiend,
-            subtypes: Some(vec!["demon".to_string()]),
+            subtypes: Some(vec![
+                CreatureTypeTag {
+                    tag: "demon".to_string(),
+                    prefix: None,
+                },
+                CreatureTypeTag {
+                    tag: "human".to_string(),
+                    prefix: Some("chondathan".to_string()),
+                },
+            ]),
+            swarm_size: None,
         }
         .to_5etools_base(),
         json!({
             "type": "fiend",
-            "tags": ["demon"]
+            "tags": ["demon", {"tag": "human", "prefix": "chondathan"}]
+        })
+    );
+
+    assert_eq!(
+        CreatureType {
+            main_type: Beast,
+            subtypes: None,
+            swarm_size: Some(Size::Tiny),
+        }
+        .to_5etools_base(),
+        json!({
+            "type": "beast",
+            "swarmSize": "T"
         })
     );
 }
@@ -143,19 +242,23 @@ fn creature_type() {
 #[test]
 fn armor_class() {
     assert_eq!(
-        ArmorClass {
+        vec![ArmorClass {
             ac: 10,
-            armor_type: None
-        }
+            armor_type: None,
+            condition: None,
+            braces: false,
+        }]
         .to_5etools_base(),
         json!([10])
     );
 
     assert_eq!(
-        ArmorClass {
+        vec![ArmorClass {
             ac: 10,
-            armor_type: Some(vec!["Natural Armor".to_string()])
-        }
+            armor_type: Some(vec!["Natural Armor".to_string()]),
+            condition: None,
+            braces: false,
+        }]
         .to_5etools_base(),
         json!([
             {
@@ -164,6 +267,260 @@ fn armor_class() {
             }
         ])
     );
+
+    assert_eq!(
+        vec![
+            ArmorClass {
+                ac: 12,
+                armor_type: None,
+                condition: None,
+                braces: false,
+            },
+            ArmorClass {
+                ac: 15,
+                armor_type: None,
+                condition: Some("with mage armor".to_string()),
+                braces: true,
+            }
+        ]
+        .to_5etools_base(),
+        json!([
+            12,
+            {
+                "ac": 15,
+                "condition": "with mage armor",
+                "braces": true
+            }
+        ])
+    );
+}
+
+#[test]
+fn spellcasting() {
+    assert_eq!(
+        Spellcasting {
+            name: "Spellcasting".to_string(),
+            header_entries: vec![
+                "Its spellcasting ability is Intelligence (spell save DC 14, +6 to hit with spell attacks).".to_string()
+            ],
+            ability: Some(AbilityScore::Intelligence),
+            save_dc: Some(14),
+            attack_bonus: Some(6),
+            at_will: Some(vec!["mage hand".to_string()]),
+            daily: Some(vec![DailySpells {
+                uses: 1,
+                each: true,
+                spells: vec!["fly".to_string(), "misty step (self only)".to_string()],
+            }]),
+            spell_slots: Some(vec![
+                SpellSlotLevel {
+                    level: 0,
+                    lower: None,
+                    slots: None,
+                    spells: vec!["light".to_string()],
+                },
+                SpellSlotLevel {
+                    level: 5,
+                    lower: Some(1),
+                    slots: Some(2),
+                    spells: vec!["hex".to_string()],
+                },
+            ]),
+            footer_entries: None,
+        }
+        .to_5etools_base(),
+        json!({
+            "name": "Spellcasting",
+            "headerEntries": [
+                "Its spellcasting ability is Intelligence (spell save {@dc 14}, {@hit 6} to hit with spell attacks)."
+            ],
+            "ability": "int",
+            "will": ["{@spell mage hand}"],
+            "daily": {
+                "1e": ["{@spell fly}", "{@spell misty step} (self only)"]
+            },
+            "spells": {
+                "0": {"spells": ["{@spell light}"]},
+                "5": {"lower": 1, "slots": 2, "spells": ["{@spell hex}"]}
+            }
+        })
+    );
+}
+
+#[test]
+fn legendary_header() {
+    assert_eq!(
+        LegendaryHeader {
+            actions_per_round: 3,
+            actions_in_lair: None,
+            header: None,
+        }
+        .to_5etools_base(),
+        json!({})
+    );
+
+    assert_eq!(
+        LegendaryHeader {
+            actions_per_round: 2,
+            actions_in_lair: Some(3),
+            header: Some("The beast can take 2 legendary actions.".to_string()),
+        }
+        .to_5etools_base(),
+        json!({
+            "legendaryActions": 2,
+            "legendaryActionsLair": 3,
+            "legendaryHeader": ["The beast can take 2 legendary actions."],
+        })
+    );
+}
+
+#[test]
+fn legendary_group() {
+    assert_eq!(
+        LegendaryGroup {
+            name: "Beast".to_string(),
+            source: Source {
+                source_book: "book",
+                page: 0,
+            },
+            lair_actions: Some(vec![
+                Description::Entry("The beast takes a lair action:".to_string()),
+                Description::List(vec![Description::Entry(
+                    "Each creature takes 7 (2d6) fire damage.".to_string()
+                )]),
+            ]),
+            regional_effects: None,
+        }
+        .to_5etools_base(),
+        json!({
+            "name": "Beast",
+            "source": "book",
+            "lairActions": [
+                "The beast takes a lair action:",
+                {
+                    "type": "list",
+                    "items": ["Each creature takes 7 ({@damage 2d6}) fire damage."]
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn gear_item() {
+    assert_eq!(
+        GearItem {
+            name: "Leather Armor".to_string(),
+            quantity: None,
+        }
+        .to_5etools_base(),
+        json!("leather armor")
+    );
+
+    assert_eq!(
+        GearItem {
+            name: "Arrows".to_string(),
+            quantity: Some(20),
+        }
+        .to_5etools_base(),
+        json!({"item": "arrows", "quantity": 20})
+    );
+}
+
+#[test]
+fn saving_throws_and_skills() {
+    assert_eq!(
+        SavingThrows {
+            saves: vec![(AbilityScore::Dexterity, 4.into())],
+            special: Some("advantage against spells".to_string()),
+        }
+        .to_5etools_base(),
+        json!({"dex": 4, "special": "advantage against spells"})
+    );
+
+    // Saving throws and skills keep the order of the stat block.
+    assert_eq!(
+        SavingThrows {
+            saves: vec![
+                (AbilityScore::Wisdom, 5.into()),
+                (AbilityScore::Dexterity, (-1).into()),
+            ],
+            special: None,
+        }
+        .to_5etools_base()
+        .to_string(),
+        r#"{"wis":5,"dex":-1}"#
+    );
+    assert_eq!(
+        Skills {
+            skills: vec![(Skill::Stealth, 6.into()), (Skill::Perception, 4.into())],
+            one_of: None,
+        }
+        .to_5etools_base()
+        .to_string(),
+        r#"{"stealth":6,"perception":4}"#
+    );
+
+    assert_eq!(
+        Skills {
+            skills: vec![(
+                Skill::Perception,
+                Bonus {
+                    bonus: 5,
+                    note: Some("(+10 with sight)".to_string()),
+                },
+            )],
+            one_of: Some(vec![(Skill::Arcana, 7.into()), (Skill::History, 7.into())]),
+        }
+        .to_5etools_base(),
+        json!({
+            "perception": "+5 (+10 with sight)",
+            "other": [{"oneOf": {"arcana": 7, "history": 7}}]
+        })
+    );
+}
+
+#[test]
+fn senses_and_languages() {
+    assert_eq!(
+        Sense {
+            sense_type: SenseType::Blindsight,
+            range: Some(30),
+            blind_beyond_radius: true,
+            note: None,
+        }
+        .to_5etools_base(),
+        json!("blindsight 30 ft. (blind beyond this radius)")
+    );
+    assert_eq!(
+        Sense {
+            sense_type: SenseType::Other("sense magic".to_string()),
+            range: Some(30),
+            blind_beyond_radius: false,
+            note: Some("see below".to_string()),
+        }
+        .to_5etools_base(),
+        json!("sense magic 30 ft. (see below)")
+    );
+
+    assert_eq!(
+        Languages {
+            spoken: vec![Language::DeepSpeech],
+            understood: vec![Language::Common, Language::Draconic],
+            any: Some(AnyLanguages {
+                count: 2,
+                note: None,
+            }),
+            telepathy: Some(60),
+        }
+        .to_5etools_base(),
+        json!([
+            "Deep Speech",
+            "understands Common and Draconic but can't speak",
+            "any two languages",
+            "telepathy 60 ft."
+        ])
+    );
 }
 
 fn read_json_file(filename: String) -> Value {
@@ -172,28 +529,28 @@ fn read_json_file(filename: String) -> Value {
     serde_json::from_reader(reader).unwrap()
 }
 
-#[test]
-fn creature() {
-    let expected_json = read_json_file("resources/test/creatures/unit_output.json".to_string())
-        .get("monster")
-        .and_then(|array| array.get(0))
-        .unwrap()
-        .to_owned();
-
-    let creature = Creature {
+fn test_creature() -> Creature<'static> {
+    Creature {
         name: "test".to_string(),
         source: Source {
             source_book: "book",
             page: 0,
         },
-        size: Size::Medium,
-        creature_type: CreatureTypeEnum::Beast,
+        size: vec![Size::Medium],
+        creature_type: CreatureType {
+            main_type: CreatureTypeEnum::Beast,
+            subtypes: None,
+            swarm_size: None,
+        },
         alignment: crate::models::common::Alignment::Unaligned,
-        armor_class: ArmorClass {
+        armor_class: vec![ArmorClass {
             ac: 10,
             armor_type: None,
-        },
-        hit_points: HitPoints {
+            condition: None,
+            braces: false,
+        }],
+        initiative: None,
+        hit_points: HitPoints::Rolled {
             average: 10,
             formula: HitPointsFormula {
                 number_of_dice: 1,
@@ -202,12 +559,14 @@ fn creature() {
             },
         },
         speed: Speed {
-            walk: 30,
+            walk: 30.into(),
             burrow: None,
             climb: None,
             crawl: None,
             fly: None,
             swim: None,
+            can_hover: false,
+            alternate: None,
         },
         ability_scores: super::AbilityScores {
             strength: 10,
@@ -225,9 +584,19 @@ fn creature() {
         damage_immunity: None,
         damage_vulnerability: None,
         condition_immunities: None,
-        languages: vec!["Common".to_string()],
-        challenge_rating: super::ChallengeRating::WholeNumber(2),
+        gear: None,
+        languages: Languages {
+            spoken: vec![Language::Common],
+            ..Default::default()
+        },
+        challenge_rating: Challenge {
+            rating: ChallengeRating::WholeNumber(2),
+            experience_points: None,
+            lair: None,
+            coven: None,
+        },
         abilities: None,
+        spellcasting: None,
         actions: Some(vec![NamedEntry {
             name: "attack".to_string(),
             entry:
@@ -237,9 +606,337 @@ fn creature() {
         }]),
         bonus_actions: None,
         reactions: None,
+        legendary_header: None,
         legendary_actions: None,
         mythic_actions: None,
         mythic_header: None,
+        legendary_group: None,
+    }
+}
+
+#[test]
+fn creature() {
+    let expected_json = read_json_file("resources/test/creatures/unit_output.json".to_string())
+        .get("monster")
+        .and_then(|array| array.get(0))
+        .unwrap()
+        .to_owned();
+
+    json_compare(test_creature().to_5etools_creature(), expected_json).unwrap();
+
+    let typically = Creature {
+        alignment: Alignment::Typically(Box::new(Alignment::Any)),
+        ..test_creature()
+    }
+    .to_5etools_creature();
+    assert_eq!(typically["alignment"], json!(["A"]));
+    assert_eq!(typically["alignmentPrefix"], json!("typically "));
+}
+
+#[test]
+fn creature_tags() {
+    let named_entry = |name: &str, entry: &str| NamedEntry {
+        name: name.to_string(),
+        entry: entry.to_string(),
+        sub_entries: None,
+    };
+    let creature = Creature {
+        senses: Some(vec![
+            Sense {
+                sense_type: SenseType::Darkvision,
+                range: Some(120),
+                blind_beyond_radius: false,
+                note: None,
+            },
+            Sense {
+                sense_type: SenseType::Tremorsense,
+                range: Some(30),
+                blind_beyond_radius: false,
+                note: None,
+            },
+        ]),
+        languages: Languages {
+            spoken: vec![Language::Undercommon],
+            telepathy: Some(60),
+            ..Default::default()
+        },
+        abilities: Some(vec![
+            named_entry("Legendary Resistance (3/Day).", "The beast succeeds instead."),
+            named_entry("Keen Smell.", "The beast has advantage on smell checks."),
+            named_entry(
+                "Fey Ancestry.",
+                "The beast has advantage on saving throws against being charmed, can't be frightened and magic can't put it to sleep.",
+            ),
+            named_entry("Web Walker.", "The beast ignores movement restrictions caused by webbing."),
+        ]),
+        actions: Some(vec![
+            named_entry("Multiattack.", "The beast makes two Bite attacks."),
+            named_entry(
+                "Bite.",
+                "Melee Weapon Attack: +7 to hit, reach 10 ft. Hit: 12 (2d8 + 3) piercing damage plus 7 (2d6) poison damage, and the target is grappled (escape DC 15).",
+            ),
+            named_entry(
+                "Fire Breath (Recharge 5-6).",
+                "The beast exhales fire in a 30-foot cone. Each creature in that area must make a DC 15 Dexterity saving throw, taking 21 (6d6) fire damage on a failed save, or be knocked prone.",
+            ),
+        ]),
+        ..test_creature()
+    };
+
+    let tags = creature.to_5etools_creature();
+    assert_eq!(tags["damageTags"], json!(["F", "I", "P"]));
+    assert_eq!(tags["conditionInflict"], json!(["grappled", "prone"]));
+    assert_eq!(tags["miscTags"], json!(["AOE", "MW", "RCH"]));
+    assert_eq!(
+        tags["traitTags"],
+        json!([
+            "Fey Ancestry",
+            "Keen Senses",
+            "Legendary Resistances",
+            "Web Walker"
+        ])
+    );
+    assert_eq!(tags["actionTags"], json!(["Breath Weapon", "Multiattack"]));
+    assert_eq!(tags["senseTags"], json!(["SD", "T"]));
+    assert_eq!(tags["languageTags"], json!(["TP", "U"]));
+}
+
+#[test]
+fn proficiency_bonus() {
+    use ChallengeRating::{Eighth, WholeNumber};
+    assert_eq!(Eighth.proficiency_bonus(), 2);
+    assert_eq!(WholeNumber(0).proficiency_bonus(), 2);
+    assert_eq!(WholeNumber(4).proficiency_bonus(), 2);
+    assert_eq!(WholeNumber(5).proficiency_bonus(), 3);
+    assert_eq!(WholeNumber(17).proficiency_bonus(), 6);
+    assert_eq!(WholeNumber(30).proficiency_bonus(), 9);
+}
+
+#[test]
+fn creature_check() {
+    let creature = Creature {
+        ability_scores: super::AbilityScores {
+            strength: 18,
+            dexterity: 15,
+            constitution: 16,
+            intelligence: 10,
+            wisdom: 9,
+            charisma: 10,
+        },
+        saving_throws: Some(SavingThrows {
+            saves: vec![
+                (AbilityScore::Strength, 6.into()),
+                (AbilityScore::Constitution, 4.into()),
+            ],
+            special: None,
+        }),
+        skills: Some(Skills {
+            skills: vec![(Skill::Stealth, 6.into()), (Skill::Athletics, 5.into())],
+            one_of: None,
+        }),
+        passive_perception: 9,
+        ..test_creature()
+    };
+    assert_eq!(
+        creature.check(),
+        vec![
+            Inconsistency {
+                field: "Hit points average".to_string(),
+                found: 10,
+                expected: 9,
+            },
+            Inconsistency {
+                field: "Hit points modifier".to_string(),
+                found: 4,
+                expected: 3,
+            },
+            Inconsistency {
+                field: "Constitution saving throw".to_string(),
+                found: 4,
+                expected: 5,
+            },
+            Inconsistency {
+                field: "athletics skill".to_string(),
+                found: 5,
+                expected: 6,
+            },
+            Inconsistency {
+                field: "attack attack bonus".to_string(),
+                found: 5,
+                expected: 6,
+            },
+        ]
+    );
+}
+
+#[test]
+fn creature_check_emphasized_attack() {
+    let creature = Creature {
+        actions: Some(vec![NamedEntry {
+            name: "Greataxe.".to_string(),
+            entry: "*Melee Weapon Attack:* +9 to hit, reach 5 ft., one target. *Hit:* 9 (1d12 + 3) slashing damage."
+                .to_string(),
+            sub_entries: None,
+        }]),
+        ..test_creature()
+    };
+    assert!(creature.check().contains(&Inconsistency {
+        field: "Greataxe attack bonus".to_string(),
+        found: 9,
+        expected: 2,
+    }));
+}
+
+#[test]
+fn challenge_rating_index() {
+    use ChallengeRating::{Eighth, Half, WholeNumber};
+    assert_eq!(WholeNumber(0).index(), 0);
+    assert_eq!(Eighth.index(), 1);
+    assert_eq!(Half.index(), 3);
+    assert_eq!(WholeNumber(1).index(), 4);
+    assert_eq!(WholeNumber(30).index(), 33);
+    assert_eq!(ChallengeRating::from_index(3), Half);
+    assert_eq!(ChallengeRating::from_index(10), WholeNumber(7));
+    assert_eq!(WholeNumber(12).to_string(), "12");
+    assert_eq!(Eighth.to_string(), "1/8");
+}
+
+#[test]
+fn estimate_challenge_rating() {
+    use ChallengeRating::{Half, WholeNumber};
+    assert_eq!(
+        test_creature().estimate_challenge_rating(),
+        ChallengeRatingEstimate {
+            defensive: WholeNumber(0),
+            offensive: WholeNumber(2),
+            estimated: Half,
+            deviation: 2,
+        }
+    );
+
+    // Resistance to weapon damage doubles the effective hit points at low challenge ratings,
+    // and a multiattack with two attacks doubles the damage per round.
+    let creature = Creature {
+        armor_class: vec![ArmorClass {
+            ac: 13,
+            armor_type: None,
+            condition: None,
+            braces: false,
+        }],
+        hit_points: HitPoints::Rolled {
+            average: 45,
+            formula: HitPointsFormula {
+                number_of_dice: 6,
+                die_size: 10,
+                modifier: 12,
+            },
+        },
+        damage_resistance: Some(vec![DamageModifier::Unconditional(DamageType::Slashing)]),
+        actions: Some(vec![
+            NamedEntry {
+                name: "Multiattack.".to_string(),
+                entry: "The beast makes two attacks.".to_string(),
+                sub_entries: None,
+            },
+            NamedEntry {
+                name: "Claw.".to_string(),
+                entry:
+                    "Melee Weapon Attack: +3 to hit, reach 5 ft. Hit: 7 (1d10 + 2) slashing damage."
+                        .to_string(),
+                sub_entries: None,
+            },
+        ]),
+        ..test_creature()
+    };
+    assert_eq!(
+        creature.estimate_challenge_rating(),
+        ChallengeRatingEstimate {
+            defensive: WholeNumber(2),
+            offensive: WholeNumber(1),
+            estimated: WholeNumber(2),
+            deviation: 0,
+        }
+    );
+
+    // Italicized 2014 attacks, e.g. `*Melee Weapon Attack:* +8`, count the same.
+    let with_attack = |entry: &str| Creature {
+        actions: Some(vec![NamedEntry {
+            name: "Claw.".to_string(),
+            entry: entry.to_string(),
+            sub_entries: None,
+        }]),
+        ..test_creature()
+    };
+    let plain = with_attack(
+        "Melee Weapon Attack: +8 to hit, reach 5 ft. Hit: 7 (1d10 + 2) slashing damage.",
+    );
+    let italic = with_attack(
+        "*Melee Weapon Attack:* +8 to hit, reach 5 ft. *Hit:* 7 (1d10 + 2) slashing damage.",
+    );
+    assert_ne!(
+        plain.estimate_challenge_rating(),
+        with_attack("7 (1d10 + 2) slashing damage.").estimate_challenge_rating()
+    );
+    assert_eq!(
+        italic.estimate_challenge_rating(),
+        plain.estimate_challenge_rating()
+    );
+}
+
+#[test]
+fn challenge() {
+    use ChallengeRating::{Half, WholeNumber};
+    let challenge = |rating, experience_points, lair, coven| Challenge {
+        rating,
+        experience_points,
+        lair,
+        coven,
+    };
+    assert_eq!(
+        challenge(Half, Some(100), None, None).to_5etools_base(),
+        json!("1/2")
+    );
+    assert_eq!(
+        challenge(WholeNumber(0), Some(0), None, None).to_5etools_base(),
+        json!("0")
+    );
+    assert_eq!(
+        challenge(WholeNumber(10), Some(5900), Some(WholeNumber(11)), None).to_5etools_base(),
+        json!({"cr": "10", "lair": "11"})
+    );
+    assert_eq!(
+        challenge(WholeNumber(3), None, None, Some(WholeNumber(5))).to_5etools_base(),
+        json!({"cr": "3", "coven": "5"})
+    );
+    assert_eq!(
+        challenge(Half, Some(150), None, None).to_5etools_base(),
+        json!({"cr": "1/2", "xp": 150})
+    );
+
+    let creature = Creature {
+        challenge_rating: challenge(WholeNumber(2), Some(400), None, None),
+        ..test_creature()
+    };
+    println!("{:?}", creature.check());
+    assert!(creature.check().contains(&Inconsistency {
+        field: "Experience points".to_string(),
+        found: 400,
+        expected: 450,
+    }));
+
+    // Challenge ratings above 30 have no standard experience points.
+    assert_eq!(WholeNumber(31).experience_points(), None);
+    assert_eq!(
+        challenge(WholeNumber(31), Some(175_000), None, None).to_5etools_base(),
+        json!({"cr": "31", "xp": 175_000})
+    );
+    let creature = Creature {
+        challenge_rating: challenge(WholeNumber(31), Some(175_000), None, None),
+        ..test_creature()
     };
-    json_compare(creature.to_5etools_creature(), expected_json).unwrap()
+    assert!(creature
+        .check()
+        .iter()
+        .all(|inconsistency| inconsistency.field != "Experience points"));
+    assert_eq!(creature.estimate_challenge_rating().deviation, 31);
 }
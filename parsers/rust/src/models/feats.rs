@@ -0,0 +1,164 @@
+use crate::{
+    models::common::{merge_json, AbilityScore, Description, Source},
+    utils::traits::{option_to_5etools_base, To5etools},
+};
+use itertools::Itertools;
+use serde_json::{json, Value};
+
+#[cfg(test)]
+mod tests;
+
+/// Category of a 2024 feat, e.g. `Origin Feat`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatCategory {
+    Origin,
+    General,
+    FightingStyle,
+    EpicBoon,
+}
+
+impl To5etools for FeatCategory {
+    fn to_5etools_base(&self) -> Value {
+        json!(match self {
+            Self::Origin => "O",
+            Self::General => "G",
+            Self::FightingStyle => "FS",
+            Self::EpicBoon => "EB",
+        })
+    }
+}
+
+/// All prerequisites of a feat, which must all be met.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Prerequisites {
+    pub level: Option<u8>,
+    /// Minimum ability scores, of which one must be met, e.g. `Strength or Dexterity 13 or
+    /// higher`.
+    pub abilities: Vec<(AbilityScore, u8)>,
+    /// Races of which the character must be one, in lowercase.
+    pub races: Vec<String>,
+    /// The ability to cast at least one spell.
+    pub spellcasting: bool,
+    /// Names of feats the character must have, in lowercase.
+    pub feats: Vec<String>,
+    /// Prerequisites without a 5etools structure, e.g. `Proficiency with medium armor`.
+    pub other: Vec<String>,
+}
+
+impl To5etools for Prerequisites {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            option_to_5etools_base(self.level.as_ref(), "level"),
+            if self.abilities.is_empty() {
+                json!({})
+            } else {
+                json!({
+                    "ability": self
+                        .abilities
+                        .iter()
+                        .map(|(ability, score)| json!({ ability_key(ability): score }))
+                        .collect_vec(),
+                })
+            },
+            if self.races.is_empty() {
+                json!({})
+            } else {
+                json!({
+                    "race": self
+                        .races
+                        .iter()
+                        .map(|race| json!({ "name": race }))
+                        .collect_vec(),
+                })
+            },
+            if self.spellcasting {
+                json!({"spellcasting": true})
+            } else {
+                json!({})
+            },
+            if self.feats.is_empty() {
+                json!({})
+            } else {
+                json!({ "feat": self.feats })
+            },
+            if self.other.is_empty() {
+                json!({})
+            } else {
+                json!({ "other": self.other.join(", ") })
+            },
+        ])
+    }
+}
+
+/// Ability score increase granted by a feat.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbilityIncrease {
+    /// Increases of fixed ability scores, e.g. `Increase your Constitution score by 1`.
+    Fixed(Vec<(AbilityScore, u8)>),
+    /// An increase of one ability score of a choice, e.g. `Increase your Strength or Dexterity
+    /// by 1`.
+    Choose { from: Vec<AbilityScore>, amount: u8 },
+}
+
+impl To5etools for AbilityIncrease {
+    fn to_5etools_base(&self) -> Value {
+        match self {
+            Self::Fixed(increases) => json!([merge_json(
+                increases
+                    .iter()
+                    .map(|(ability, amount)| json!({ ability_key(ability): amount }))
+                    .collect()
+            )]),
+            Self::Choose { from, amount } => json!([{
+                "choose": {
+                    "from": from.to_5etools_base(),
+                    "amount": amount,
+                }
+            }]),
+        }
+    }
+}
+
+fn ability_key(ability: &AbilityScore) -> String {
+    ability
+        .to_5etools_base()
+        .as_str()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Feat<'a> {
+    pub name: String,
+    pub source: Source<'a>,
+    pub category: Option<FeatCategory>,
+    pub prerequisites: Prerequisites,
+    pub ability: Option<AbilityIncrease>,
+    pub entries: Vec<Description>,
+}
+
+impl To5etools for Feat<'_> {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "entries": self.entries.to_5etools_base(),
+            }),
+            self.source.to_5etools_base(),
+            option_to_5etools_base(self.category.as_ref(), "category"),
+            if self.prerequisites == Prerequisites::default() {
+                json!({})
+            } else {
+                json!({ "prerequisite": [self.prerequisites.to_5etools_base()] })
+            },
+            self.ability.as_ref().map_or_else(
+                || json!({}),
+                |ability| json!({ "ability": ability.to_5etools_base() }),
+            ),
+        ])
+    }
+}
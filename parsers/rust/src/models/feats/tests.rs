@@ -0,0 +1,54 @@
+use serde_json::json;
+
+use crate::{models::common::AbilityScore, utils::traits::To5etools};
+
+use super::{AbilityIncrease, FeatCategory, Prerequisites};
+
+#[test]
+fn feat_category() {
+    assert_eq!(FeatCategory::FightingStyle.to_5etools_base(), json!("FS"));
+}
+
+#[test]
+fn prerequisites() {
+    assert_eq!(
+        Prerequisites {
+            level: Some(8),
+            abilities: vec![(AbilityScore::Wisdom, 13)],
+            races: vec!["gnome".to_string()],
+            spellcasting: true,
+            feats: vec!["alert".to_string()],
+            other: vec!["a deity".to_string(), "a holy symbol".to_string()],
+        }
+        .to_5etools_base(),
+        json!({
+            "level": 8,
+            "ability": [{"wis": 13}],
+            "race": [{"name": "gnome"}],
+            "spellcasting": true,
+            "feat": ["alert"],
+            "other": "a deity, a holy symbol",
+        })
+    );
+    assert_eq!(Prerequisites::default().to_5etools_base(), json!({}));
+}
+
+#[test]
+fn ability_increase() {
+    assert_eq!(
+        AbilityIncrease::Fixed(vec![
+            (AbilityScore::Strength, 1),
+            (AbilityScore::Constitution, 1)
+        ])
+        .to_5etools_base(),
+        json!([{"str": 1, "con": 1}])
+    );
+    assert_eq!(
+        AbilityIncrease::Choose {
+            from: vec![AbilityScore::Dexterity, AbilityScore::Wisdom],
+            amount: 2,
+        }
+        .to_5etools_base(),
+        json!([{"choose": {"from": ["dex", "wis"], "amount": 2}}])
+    );
+}
@@ -1,8 +1,23 @@
-use crate::utils::traits::To5etools;
+use std::{
+    cmp::Ordering,
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
+
+use crate::{
+    models::common::{merge_json, Classes, DamageType, Description, Source},
+    utils::traits::{option_to_5etools_base, To5etools},
+};
+use itertools::Itertools;
 use serde_json::{json, Value};
 
+#[cfg(test)]
+mod tests;
+
+/// Coins from the least to the most valuable, so that comparing currencies compares their worth.
 #[allow(dead_code)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Currency {
     Copper,
     Silver,
@@ -11,25 +26,577 @@ pub enum Currency {
     Platinum,
 }
 
+impl Currency {
+    /// Worth of a single coin in copper pieces.
+    #[must_use]
+    pub const fn in_copper(self) -> u64 {
+        match self {
+            Self::Copper => 1,
+            Self::Silver => 10,
+            Self::Electrum => 50,
+            Self::Gold => 100,
+            Self::Platinum => 1000,
+        }
+    }
+
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Copper => "cp",
+            Self::Silver => "sp",
+            Self::Electrum => "ep",
+            Self::Gold => "gp",
+            Self::Platinum => "pp",
+        }
+    }
+}
+
+/// An amount of coins of a single currency, e.g. `1,500 gp`.
+///
+/// Values are compared by their worth, so `1 gp` equals `10 sp`. Adding values of different
+/// currencies results in a value of the less valuable currency, which keeps the sum exact.
 #[allow(dead_code)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy)]
 pub struct ItemValue {
-    pub value: u32,
+    pub value: u64,
     pub unit: Currency,
 }
 
+impl ItemValue {
+    #[must_use]
+    pub const fn in_copper(self) -> u64 {
+        self.value * self.unit.in_copper()
+    }
+
+    /// Split the value into gold, silver and copper pieces, leaving out empty denominations.
+    ///
+    /// Platinum and electrum are not used, as prices are conventionally given in gold pieces.
+    #[must_use]
+    pub fn denominations(self) -> Vec<Self> {
+        let mut copper = self.in_copper();
+        [Currency::Gold, Currency::Silver, Currency::Copper]
+            .into_iter()
+            .filter_map(|unit| {
+                let value = copper / unit.in_copper();
+                copper %= unit.in_copper();
+                (value > 0).then_some(Self { value, unit })
+            })
+            .collect()
+    }
+}
+
+impl PartialEq for ItemValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.in_copper() == other.in_copper()
+    }
+}
+
+impl Eq for ItemValue {}
+
+impl PartialOrd for ItemValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ItemValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.in_copper().cmp(&other.in_copper())
+    }
+}
+
+impl Add for ItemValue {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let unit = self.unit.min(rhs.unit);
+        Self {
+            value: (self.in_copper() + rhs.in_copper()) / unit.in_copper(),
+            unit,
+        }
+    }
+}
+
+impl AddAssign for ItemValue {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sum for ItemValue {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(
+            Self {
+                value: 0,
+                unit: Currency::Platinum,
+            },
+            Add::add,
+        )
+    }
+}
+
+/// Display the value in the largest sensible denominations, e.g. `1,500 gp` or `2 gp 5 sp`.
+impl fmt::Display for ItemValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let denominations = self.denominations();
+        if denominations.is_empty() {
+            return write!(f, "0 gp");
+        }
+        let text = denominations
+            .into_iter()
+            .map(|value| {
+                // Thousands separators, e.g. `1,500`.
+                let digits = value.value.to_string();
+                let grouped = digits
+                    .as_bytes()
+                    .rchunks(3)
+                    .rev()
+                    .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+                    .join(",");
+                format!("{grouped} {}", value.unit.abbreviation())
+            })
+            .join(" ");
+        write!(f, "{text}")
+    }
+}
+
 impl To5etools for ItemValue {
     fn to_5etools_base(&self) -> Value {
-        use Currency::{Copper, Electrum, Gold, Platinum, Silver};
-        json!(
-            self.value
-                * match self.unit {
-                    Copper => 1,
-                    Silver => 10,
-                    Electrum => 50,
-                    Gold => 100,
-                    Platinum => 1000,
-                }
+        json!(self.in_copper())
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rarity {
+    /// Mundane items, which 5etools lists as base items.
+    None,
+    Common,
+    Uncommon,
+    Rare,
+    VeryRare,
+    Legendary,
+    Artifact,
+    Varies,
+}
+
+impl To5etools for Rarity {
+    fn to_5etools_base(&self) -> Value {
+        json!(match self {
+            Self::None => "none",
+            Self::Common => "common",
+            Self::Uncommon => "uncommon",
+            Self::Rare => "rare",
+            Self::VeryRare => "very rare",
+            Self::Legendary => "legendary",
+            Self::Artifact => "artifact",
+            Self::Varies => "varies",
+        })
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemType {
+    LightArmor,
+    MediumArmor,
+    HeavyArmor,
+    Shield,
+    MeleeWeapon,
+    RangedWeapon,
+    Ammunition,
+    Potion,
+    Ring,
+    Rod,
+    Scroll,
+    Staff,
+    Wand,
+    WondrousItem,
+}
+
+impl To5etools for ItemType {
+    fn to_5etools_base(&self) -> Value {
+        use ItemType::*;
+        match self {
+            LightArmor => json!({"type": "LA"}),
+            MediumArmor => json!({"type": "MA"}),
+            HeavyArmor => json!({"type": "HA"}),
+            Shield => json!({"type": "S"}),
+            MeleeWeapon => json!({"type": "M"}),
+            RangedWeapon => json!({"type": "R"}),
+            Ammunition => json!({"type": "A"}),
+            Potion => json!({"type": "P"}),
+            Ring => json!({"type": "RG"}),
+            Rod => json!({"type": "RD"}),
+            Scroll => json!({"type": "SC"}),
+            Staff => json!({"type": "ST"}),
+            Wand => json!({"type": "WD"}),
+            // Wondrous items have no type in 5etools.
+            WondrousItem => json!({"wondrous": true}),
+        }
+    }
+}
+
+/// Attunement requirement of an item, e.g. `requires attunement by a sorcerer or wizard`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Attunement {
+    Required,
+    RequiredBy {
+        /// Text following "requires attunement", e.g. `by a sorcerer or wizard`.
+        prerequisite: String,
+        classes: Vec<Classes>,
+    },
+}
+
+impl To5etools for Attunement {
+    fn to_5etools_base(&self) -> Value {
+        match self {
+            Self::Required => json!({"reqAttune": true}),
+            Self::RequiredBy {
+                prerequisite,
+                classes,
+            } if classes.is_empty() => json!({ "reqAttune": prerequisite }),
+            Self::RequiredBy {
+                prerequisite,
+                classes,
+            } => json!({
+                "reqAttune": prerequisite,
+                "reqAttuneTags": classes
+                    .iter()
+                    .map(|class| json!({
+                        "class": class.to_5etools_base().as_str().unwrap_or_default().to_lowercase()
+                    }))
+                    .collect_vec(),
+            }),
+        }
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RechargePeriod {
+    Dawn,
+    Dusk,
+    Midnight,
+    ShortRest,
+    LongRest,
+}
+
+impl To5etools for RechargePeriod {
+    fn to_5etools_base(&self) -> Value {
+        use RechargePeriod::*;
+        json!(match self {
+            Dawn => "dawn",
+            Dusk => "dusk",
+            Midnight => "midnight",
+            ShortRest => "restShort",
+            LongRest => "restLong",
+        })
+    }
+}
+
+/// Regained charges, e.g. `regains 1d6 + 4 expended charges daily at dawn`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recharge {
+    pub period: RechargePeriod,
+    /// Number or dice of charges regained, or `None` if all charges are regained.
+    pub amount: Option<String>,
+}
+
+impl To5etools for Recharge {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({"recharge": self.period.to_5etools_base()}),
+            self.amount.as_ref().map_or_else(
+                || json!({}),
+                |amount| {
+                    amount.parse::<u8>().map_or_else(
+                        |_| json!({ "rechargeAmount": format!("{{@dice {amount}}}") }),
+                        |amount| json!({ "rechargeAmount": amount }),
+                    )
+                },
+            ),
+        ])
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemBonus {
+    /// Bonus to attack and damage rolls.
+    Weapon(i8),
+    ArmorClass(i8),
+    SavingThrow(i8),
+    SpellAttack(i8),
+    SpellSaveDc(i8),
+}
+
+impl To5etools for ItemBonus {
+    fn to_5etools_base(&self) -> Value {
+        use ItemBonus::*;
+        let (key, bonus) = match self {
+            Weapon(bonus) => ("bonusWeapon", bonus),
+            ArmorClass(bonus) => ("bonusAc", bonus),
+            SavingThrow(bonus) => ("bonusSavingThrow", bonus),
+            SpellAttack(bonus) => ("bonusSpellAttack", bonus),
+            SpellSaveDc(bonus) => ("bonusSpellSaveDc", bonus),
+        };
+        json!({ key: format!("{bonus:+}") })
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeaponCategory {
+    Simple,
+    Martial,
+}
+
+impl To5etools for WeaponCategory {
+    fn to_5etools_base(&self) -> Value {
+        json!(match self {
+            Self::Simple => "simple",
+            Self::Martial => "martial",
+        })
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WeaponProperty {
+    Ammunition,
+    Finesse,
+    Heavy,
+    Light,
+    Loading,
+    Reach,
+    Special,
+    Thrown,
+    TwoHanded,
+    Versatile,
+    /// A homebrew property, which needs an `itemProperty` definition.
+    Custom(ItemProperty),
+}
+
+impl WeaponProperty {
+    /// Reference to the property, e.g. `V` or `CON|source` for custom properties.
+    #[must_use]
+    pub fn reference(&self, source: &Source) -> String {
+        match self {
+            Self::Ammunition => "A",
+            Self::Finesse => "F",
+            Self::Heavy => "H",
+            Self::Light => "L",
+            Self::Loading => "LD",
+            Self::Reach => "R",
+            Self::Special => "S",
+            Self::Thrown => "T",
+            Self::TwoHanded => "2H",
+            Self::Versatile => "V",
+            Self::Custom(property) => {
+                return format!("{}|{}", property.abbreviation, source.source_book)
+            }
+        }
+        .to_string()
+    }
+}
+
+/// Definition of a homebrew weapon property, e.g. `Concealable`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemProperty {
+    pub name: String,
+    pub abbreviation: String,
+}
+
+impl ItemProperty {
+    #[must_use]
+    pub fn to_5etools_property(&self, source: &Source) -> Value {
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "abbreviation": self.abbreviation,
+            }),
+            source.to_5etools_base(),
+        ])
+    }
+}
+
+/// Damage dealt by a weapon, e.g. `1d8 slashing`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeaponDamage {
+    /// Dice or a fixed number, e.g. `1d8` or `1`.
+    pub dice: String,
+    pub damage_type: DamageType,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeaponStats {
+    pub category: Option<WeaponCategory>,
+    pub damage: Option<WeaponDamage>,
+    /// Damage when wielded with two hands, e.g. `1d10` for `versatile (1d10)`.
+    pub versatile_damage: Option<String>,
+    /// Normal and long range in feet, e.g. `80/320`.
+    pub range: Option<(u16, u16)>,
+    pub properties: Vec<WeaponProperty>,
+}
+
+impl WeaponStats {
+    fn to_5etools_item(&self, source: &Source) -> Value {
+        merge_json(vec![
+            json!({"weapon": true}),
+            option_to_5etools_base(self.category.as_ref(), "weaponCategory"),
+            self.damage.as_ref().map_or_else(
+                || json!({}),
+                |damage| {
+                    json!({
+                        "dmg1": damage.dice,
+                        "dmgType": damage.damage_type.abbreviation(),
+                    })
+                },
+            ),
+            option_to_5etools_base(self.versatile_damage.as_ref(), "dmg2"),
+            self.range.map_or_else(
+                || json!({}),
+                |(normal, long)| json!({ "range": format!("{normal}/{long}") }),
+            ),
+            if self.properties.is_empty() {
+                json!({})
+            } else {
+                json!({
+                    "property": self
+                        .properties
+                        .iter()
+                        .map(|property| property.reference(source))
+                        .collect_vec(),
+                })
+            },
+        ])
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArmorStats {
+    /// Base armor class, or the bonus of a shield.
+    pub armor_class: u8,
+    /// Minimum Strength score to wear the armor without a speed penalty.
+    pub strength: Option<u8>,
+    pub stealth_disadvantage: bool,
+}
+
+impl To5etools for ArmorStats {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({
+                "armor": true,
+                "ac": self.armor_class,
+            }),
+            self.strength.map_or_else(
+                || json!({}),
+                |strength| json!({ "strength": strength.to_string() }),
+            ),
+            if self.stealth_disadvantage {
+                json!({"stealth": true})
+            } else {
+                json!({})
+            },
+        ])
+    }
+}
+
+/// The mundane item a magic item is based on, e.g. `longsword`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaseItemReference<'a> {
+    pub name: String,
+    /// Source of a base item from the same document, or `None` for one from the PHB.
+    pub source: Option<Source<'a>>,
+}
+
+impl To5etools for BaseItemReference<'_> {
+    fn to_5etools_base(&self) -> Value {
+        json!(format!(
+            "{}|{}",
+            self.name,
+            self.source
+                .as_ref()
+                .map_or("phb", |source| source.source_book)
+        ))
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item<'a> {
+    pub name: String,
+    pub source: Source<'a>,
+    pub item_type: ItemType,
+    pub base_item: Option<BaseItemReference<'a>>,
+    pub rarity: Rarity,
+    pub attunement: Option<Attunement>,
+    /// Weight in pounds.
+    pub weight: Option<f32>,
+    pub value: Option<ItemValue>,
+    pub charges: Option<u8>,
+    pub recharge: Option<Recharge>,
+    pub bonuses: Vec<ItemBonus>,
+    pub weapon: Option<WeaponStats>,
+    pub armor: Option<ArmorStats>,
+    pub entries: Vec<Description>,
+}
+
+impl Item<'_> {
+    /// Mundane items are listed as `baseitem` rather than `item` in 5etools.
+    #[must_use]
+    pub fn is_base_item(&self) -> bool {
+        self.rarity == Rarity::None
+    }
+}
+
+impl To5etools for Item<'_> {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(
+            [
+                vec![
+                    json!({
+                        "name": self.name,
+                        "rarity": self.rarity.to_5etools_base(),
+                    }),
+                    if self.entries.is_empty() {
+                        json!({})
+                    } else {
+                        json!({ "entries": self.entries.to_5etools_base() })
+                    },
+                    self.source.to_5etools_base(),
+                    self.item_type.to_5etools_base(),
+                    option_to_5etools_base(self.base_item.as_ref(), "baseItem"),
+                    self.attunement
+                        .as_ref()
+                        .map_or_else(|| json!({}), To5etools::to_5etools_base),
+                    option_to_5etools_base(self.value.as_ref(), "value"),
+                    option_to_5etools_base(self.charges.as_ref(), "charges"),
+                    self.recharge
+                        .as_ref()
+                        .map_or_else(|| json!({}), To5etools::to_5etools_base),
+                    self.weight
+                        .map_or_else(|| json!({}), |weight| json!({ "weight": weight })),
+                    self.weapon
+                        .as_ref()
+                        .map_or_else(|| json!({}), |weapon| weapon.to_5etools_item(&self.source)),
+                    self.armor
+                        .as_ref()
+                        .map_or_else(|| json!({}), To5etools::to_5etools_base),
+                ],
+                self.bonuses
+                    .iter()
+                    .map(To5etools::to_5etools_base)
+                    .collect(),
+            ]
+            .concat(),
         )
     }
 }
@@ -0,0 +1,194 @@
+use serde_json::json;
+
+use crate::{
+    models::common::{Classes, DamageType, Source},
+    utils::traits::To5etools,
+};
+
+use super::{
+    ArmorStats, Attunement, Currency, ItemBonus, ItemProperty, ItemType, ItemValue, Recharge,
+    RechargePeriod, WeaponCategory, WeaponDamage, WeaponProperty, WeaponStats,
+};
+
+#[test]
+fn item_value() {
+    assert_eq!(
+        ItemValue {
+            value: 15,
+            unit: Currency::Gold,
+        }
+        .to_5etools_base(),
+        json!(1500)
+    );
+    assert_eq!(
+        ItemValue {
+            value: 5_000_000,
+            unit: Currency::Platinum,
+        }
+        .to_5etools_base(),
+        json!(5_000_000_000_u64)
+    );
+}
+
+#[test]
+fn item_value_arithmetic() {
+    let gold = |value| ItemValue {
+        value,
+        unit: Currency::Gold,
+    };
+    let silver = |value| ItemValue {
+        value,
+        unit: Currency::Silver,
+    };
+    let sum = gold(2) + silver(5);
+    assert_eq!((sum.value, sum.unit), (25, Currency::Silver));
+    assert_eq!(gold(1), silver(10));
+    assert!(gold(1) < silver(11));
+    assert!(Currency::Platinum > Currency::Electrum);
+    let mut total = gold(1);
+    total += gold(2);
+    assert_eq!((total.value, total.unit), (3, Currency::Gold));
+    assert_eq!(
+        [gold(1), silver(3), gold(4)]
+            .into_iter()
+            .sum::<ItemValue>()
+            .in_copper(),
+        530
+    );
+    assert_eq!(
+        Vec::<ItemValue>::new().into_iter().sum::<ItemValue>(),
+        gold(0)
+    );
+}
+
+#[test]
+fn item_value_display() {
+    let value = |value, unit| ItemValue { value, unit };
+    assert_eq!(value(1500, Currency::Gold).to_string(), "1,500 gp");
+    assert_eq!(value(25, Currency::Silver).to_string(), "2 gp 5 sp");
+    assert_eq!(value(3, Currency::Platinum).to_string(), "30 gp");
+    assert_eq!(value(3, Currency::Electrum).to_string(), "1 gp 5 sp");
+    assert_eq!(
+        value(1_000_001, Currency::Copper).to_string(),
+        "10,000 gp 1 cp"
+    );
+    assert_eq!(value(0, Currency::Copper).to_string(), "0 gp");
+    assert_eq!(
+        value(205, Currency::Copper).denominations(),
+        vec![value(2, Currency::Gold), value(5, Currency::Copper)]
+    );
+}
+
+#[test]
+fn item_type() {
+    assert_eq!(
+        ItemType::HeavyArmor.to_5etools_base(),
+        json!({"type": "HA"})
+    );
+    assert_eq!(
+        ItemType::WondrousItem.to_5etools_base(),
+        json!({"wondrous": true})
+    );
+}
+
+#[test]
+fn attunement() {
+    assert_eq!(
+        Attunement::Required.to_5etools_base(),
+        json!({"reqAttune": true})
+    );
+    assert_eq!(
+        Attunement::RequiredBy {
+            prerequisite: "by a wizard".to_string(),
+            classes: vec![Classes::Wizard],
+        }
+        .to_5etools_base(),
+        json!({"reqAttune": "by a wizard", "reqAttuneTags": [{"class": "wizard"}]})
+    );
+}
+
+#[test]
+fn recharge() {
+    assert_eq!(
+        Recharge {
+            period: RechargePeriod::Dawn,
+            amount: Some("1d6 + 4".to_string()),
+        }
+        .to_5etools_base(),
+        json!({"recharge": "dawn", "rechargeAmount": "{@dice 1d6 + 4}"})
+    );
+    assert_eq!(
+        Recharge {
+            period: RechargePeriod::LongRest,
+            amount: Some("3".to_string()),
+        }
+        .to_5etools_base(),
+        json!({"recharge": "restLong", "rechargeAmount": 3})
+    );
+}
+
+#[test]
+fn item_bonus() {
+    assert_eq!(
+        ItemBonus::Weapon(2).to_5etools_base(),
+        json!({"bonusWeapon": "+2"})
+    );
+    assert_eq!(
+        ItemBonus::ArmorClass(1).to_5etools_base(),
+        json!({"bonusAc": "+1"})
+    );
+}
+
+#[test]
+fn weapon_and_armor() {
+    let source = Source {
+        source_book: "test-source",
+        page: 0,
+    };
+    let concealable = ItemProperty {
+        name: "Concealable".to_string(),
+        abbreviation: "CON".to_string(),
+    };
+    assert_eq!(
+        WeaponStats {
+            category: Some(WeaponCategory::Martial),
+            damage: Some(WeaponDamage {
+                dice: "1d8".to_string(),
+                damage_type: DamageType::Slashing,
+            }),
+            versatile_damage: Some("1d10".to_string()),
+            range: None,
+            properties: vec![
+                WeaponProperty::Versatile,
+                WeaponProperty::Custom(concealable.clone())
+            ],
+        }
+        .to_5etools_item(&source),
+        json!({
+            "weapon": true,
+            "weaponCategory": "martial",
+            "dmg1": "1d8",
+            "dmgType": "S",
+            "dmg2": "1d10",
+            "property": ["V", "CON|test-source"],
+        })
+    );
+    assert_eq!(
+        concealable.to_5etools_property(&source),
+        json!({
+            "name": "Concealable",
+            "abbreviation": "CON",
+            "source": "test-source",
+            "page": 0,
+        })
+    );
+    assert_eq!(
+        ArmorStats {
+            armor_class: 18,
+            strength: Some(15),
+            stealth_disadvantage: true,
+        }
+        .to_5etools_base(),
+        json!({"armor": true, "ac": 18, "strength": "15", "stealth": true})
+    );
+}
@@ -0,0 +1,93 @@
+use crate::{
+    models::{
+        common::{merge_json, Description, NamedEntry, Source, StatusCondition},
+        creatures::{AbilityScores, ArmorClass, DamageModifier, HitPoints, Size, Speed},
+    },
+    utils::traits::{option_to_5etools_creature, To5etools},
+};
+use serde_json::{json, Value};
+
+#[cfg(test)]
+mod tests;
+
+/// Kind of an object, e.g. a siege weapon.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectType {
+    /// An object with actions, e.g. a ballista or a cannon.
+    SiegeWeapon,
+    Generic,
+}
+
+impl To5etools for ObjectType {
+    fn to_5etools_base(&self) -> Value {
+        json!(match self {
+            Self::SiegeWeapon => "SW",
+            Self::Generic => "GEN",
+        })
+    }
+}
+
+/// Hit points of an object or vehicle, which 5etools expects as a plain number when known.
+pub(crate) fn hit_points_to_5etools(hit_points: &HitPoints) -> Value {
+    hit_points
+        .average()
+        .map_or_else(|| hit_points.to_5etools_base(), |average| json!(average))
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Object<'a> {
+    pub name: String,
+    pub source: Source<'a>,
+    pub size: Vec<Size>,
+    pub object_type: ObjectType,
+    pub armor_class: ArmorClass,
+    pub hit_points: HitPoints,
+    pub speed: Option<Speed>,
+    pub ability_scores: Option<AbilityScores>,
+    pub damage_resistance: Option<Vec<DamageModifier>>,
+    pub damage_immunity: Option<Vec<DamageModifier>>,
+    pub damage_vulnerability: Option<Vec<DamageModifier>>,
+    pub condition_immunities: Option<Vec<StatusCondition>>,
+    pub entries: Vec<Description>,
+    pub actions: Option<Vec<NamedEntry>>,
+}
+
+impl To5etools for Object<'_> {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "size": self.size.to_5etools_creature(),
+                "objectType": self.object_type.to_5etools_base(),
+                "ac": self.armor_class.to_5etools_creature(),
+                "hp": hit_points_to_5etools(&self.hit_points),
+                "entries": self.entries.to_5etools_base(),
+            }),
+            self.source.to_5etools_base(),
+            option_to_5etools_creature(self.speed.as_ref(), "speed"),
+            self.ability_scores
+                .as_ref()
+                .map_or_else(|| json!({}), To5etools::to_5etools_creature),
+            option_to_5etools_creature(self.damage_resistance.as_ref(), "resist"),
+            option_to_5etools_creature(self.damage_immunity.as_ref(), "immune"),
+            option_to_5etools_creature(self.damage_vulnerability.as_ref(), "vulnerable"),
+            option_to_5etools_creature(self.condition_immunities.as_ref(), "conditionImmune"),
+            self.actions.as_ref().map_or_else(
+                || json!({}),
+                |actions| {
+                    json!({
+                        "actionEntries": actions
+                            .iter()
+                            .map(|action| merge_json(vec![
+                                json!({ "type": "actions" }),
+                                action.to_5etools_creature(),
+                            ]))
+                            .collect::<Vec<_>>(),
+                    })
+                },
+            ),
+        ])
+    }
+}
@@ -0,0 +1,58 @@
+use serde_json::json;
+
+use crate::{
+    models::{
+        common::{DamageType, Description, NamedEntry, Source},
+        creatures::{ArmorClass, DamageModifier, HitPoints, Size},
+    },
+    utils::traits::To5etools,
+};
+
+use super::{Object, ObjectType};
+
+#[test]
+fn object() {
+    assert_eq!(
+        Object {
+            name: "Tide Cannon".to_string(),
+            source: Source {
+                source_book: "test-source",
+                page: 12,
+            },
+            size: vec![Size::Large],
+            object_type: ObjectType::SiegeWeapon,
+            armor_class: ArmorClass {
+                ac: 19,
+                armor_type: None,
+                condition: None,
+                braces: false,
+            },
+            hit_points: HitPoints::Special("equal to its hull".to_string()),
+            speed: None,
+            ability_scores: None,
+            damage_resistance: None,
+            damage_immunity: Some(vec![DamageModifier::Unconditional(DamageType::Poison)]),
+            damage_vulnerability: None,
+            condition_immunities: None,
+            entries: vec![Description::Entry("A cannon of coral.".to_string())],
+            actions: Some(vec![NamedEntry {
+                name: "Blast".to_string(),
+                entry: "It fires.".to_string(),
+                sub_entries: None,
+            }]),
+        }
+        .to_5etools_base(),
+        json!({
+            "name": "Tide Cannon",
+            "source": "test-source",
+            "page": 12,
+            "size": ["L"],
+            "objectType": "SW",
+            "ac": 19,
+            "hp": {"special": "equal to its hull"},
+            "immune": ["poison"],
+            "entries": ["A cannon of coral."],
+            "actionEntries": [{"type": "actions", "name": "Blast", "entries": ["It fires."]}],
+        })
+    );
+}
@@ -0,0 +1,122 @@
+use crate::{
+    models::common::{merge_json, Classes, Description, Source},
+    utils::traits::{option_to_5etools_base, To5etools},
+};
+use serde_json::{json, Value};
+
+#[cfg(test)]
+mod tests;
+
+/// Kind of an optional feature, which is chosen from a list of options of a class.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionalFeatureType {
+    EldritchInvocation,
+    /// A Battle Master maneuver.
+    Maneuver,
+    Metamagic,
+    ArtificerInfusion,
+    /// A fighting style of a class, e.g. Fighter or Paladin.
+    FightingStyle(Classes),
+}
+
+impl OptionalFeatureType {
+    /// The class that chooses features of this type.
+    #[must_use]
+    pub const fn class(self) -> Classes {
+        match self {
+            Self::EldritchInvocation => Classes::Warlock,
+            Self::Maneuver => Classes::Fighter,
+            Self::Metamagic => Classes::Sorcerer,
+            Self::ArtificerInfusion => Classes::Artificer,
+            Self::FightingStyle(class) => class,
+        }
+    }
+}
+
+impl To5etools for OptionalFeatureType {
+    fn to_5etools_base(&self) -> Value {
+        json!(match self {
+            Self::EldritchInvocation => "EI",
+            Self::Maneuver => "MV:B",
+            Self::Metamagic => "MM",
+            Self::ArtificerInfusion => "AI",
+            Self::FightingStyle(Classes::Bard) => "FS:B",
+            Self::FightingStyle(Classes::Paladin) => "FS:P",
+            Self::FightingStyle(Classes::Ranger) => "FS:R",
+            Self::FightingStyle(_) => "FS:F",
+        })
+    }
+}
+
+/// All prerequisites of an optional feature, which must all be met.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OptionalFeaturePrerequisites {
+    /// Minimum level in the class of the optional feature.
+    pub level: Option<u8>,
+    /// Pact boon, e.g. `Blade` for `Pact of the Blade feature`.
+    pub pact: Option<String>,
+    /// Spells the character must know in lowercase, cantrips with the suffix `#c`, e.g.
+    /// `eldritch blast#c`.
+    pub spells: Vec<String>,
+    /// Prerequisites without a 5etools structure.
+    pub other: Vec<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionalFeature<'a> {
+    pub name: String,
+    pub source: Source<'a>,
+    pub feature_type: OptionalFeatureType,
+    pub prerequisites: OptionalFeaturePrerequisites,
+    pub entries: Vec<Description>,
+}
+
+impl OptionalFeature<'_> {
+    fn prerequisites_to_5etools(&self) -> Value {
+        let prerequisites = &self.prerequisites;
+        if *prerequisites == OptionalFeaturePrerequisites::default() {
+            return json!({});
+        }
+        json!({
+            "prerequisite": [merge_json(vec![
+                prerequisites.level.map_or_else(
+                    || json!({}),
+                    |level| json!({
+                        "level": {
+                            "level": level,
+                            "class": self.feature_type.class().to_5etools_spell(),
+                        }
+                    }),
+                ),
+                option_to_5etools_base(prerequisites.pact.as_ref(), "pact"),
+                if prerequisites.spells.is_empty() {
+                    json!({})
+                } else {
+                    json!({ "spell": prerequisites.spells })
+                },
+                if prerequisites.other.is_empty() {
+                    json!({})
+                } else {
+                    json!({ "other": prerequisites.other.join(", ") })
+                },
+            ])]
+        })
+    }
+}
+
+impl To5etools for OptionalFeature<'_> {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "featureType": [self.feature_type.to_5etools_base()],
+                "entries": self.entries.to_5etools_base(),
+            }),
+            self.source.to_5etools_base(),
+            self.prerequisites_to_5etools(),
+        ])
+    }
+}
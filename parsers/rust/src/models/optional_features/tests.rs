@@ -0,0 +1,59 @@
+use serde_json::json;
+
+use crate::{
+    models::common::{Classes, Description, Source},
+    utils::traits::To5etools,
+};
+
+use super::{OptionalFeature, OptionalFeaturePrerequisites, OptionalFeatureType};
+
+#[test]
+fn feature_types() {
+    assert_eq!(
+        OptionalFeatureType::FightingStyle(Classes::Paladin).to_5etools_base(),
+        json!("FS:P")
+    );
+    assert_eq!(
+        OptionalFeatureType::FightingStyle(Classes::Druid).to_5etools_base(),
+        json!("FS:F")
+    );
+    assert_eq!(
+        OptionalFeatureType::ArtificerInfusion.class(),
+        Classes::Artificer
+    );
+}
+
+#[test]
+fn optional_feature() {
+    assert_eq!(
+        OptionalFeature {
+            name: "Drowned Blade".to_string(),
+            source: Source {
+                source_book: "test-source",
+                page: 7,
+            },
+            feature_type: OptionalFeatureType::EldritchInvocation,
+            prerequisites: OptionalFeaturePrerequisites {
+                level: Some(5),
+                pact: Some("Blade".to_string()),
+                spells: vec!["eldritch blast#c".to_string()],
+                other: vec!["a trident".to_string()],
+            },
+            entries: vec![Description::Entry("Cold steel.".to_string())],
+        }
+        .to_5etools_base(),
+        json!({
+            "name": "Drowned Blade",
+            "source": "test-source",
+            "page": 7,
+            "featureType": ["EI"],
+            "prerequisite": [{
+                "level": {"level": 5, "class": {"name": "Warlock", "source": "PHB"}},
+                "pact": "Blade",
+                "spell": ["eldritch blast#c"],
+                "other": "a trident",
+            }],
+            "entries": ["Cold steel."],
+        })
+    );
+}
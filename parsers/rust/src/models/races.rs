@@ -0,0 +1,177 @@
+use crate::{
+    models::{
+        backgrounds::LanguageProficiencies,
+        common::{merge_json, AbilityScore, Description, Source},
+        creatures::{Size, Speed},
+    },
+    utils::traits::{option_to_5etools_base, To5etools},
+};
+use serde_json::{json, Map, Value};
+
+#[cfg(test)]
+mod tests;
+
+/// Ability score increases of a race, e.g. `Your Charisma score increases by 2, and two other
+/// ability scores of your choice increase by 1`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RaceAbilities {
+    pub fixed: Vec<(AbilityScore, u8)>,
+    pub choose: Option<AbilityChoice>,
+}
+
+/// Increases of ability scores of the character's choice.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbilityChoice {
+    pub from: Vec<AbilityScore>,
+    /// Number of ability scores to choose.
+    pub count: u8,
+    /// Increase of each chosen ability score.
+    pub amount: u8,
+}
+
+impl To5etools for RaceAbilities {
+    fn to_5etools_base(&self) -> Value {
+        let mut abilities = self
+            .fixed
+            .iter()
+            .map(|(ability, amount)| {
+                (
+                    ability
+                        .to_5etools_base()
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                    json!(amount),
+                )
+            })
+            .collect::<Map<String, Value>>();
+        if let Some(choice) = &self.choose {
+            abilities.insert(
+                "choose".to_string(),
+                merge_json(vec![
+                    json!({
+                        "from": choice.from.to_5etools_base(),
+                        "count": choice.count,
+                    }),
+                    if choice.amount == 1 {
+                        json!({})
+                    } else {
+                        json!({ "amount": choice.amount })
+                    },
+                ]),
+            );
+        }
+        json!([abilities])
+    }
+}
+
+/// Age of adulthood and maximum age of a race in years.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Age {
+    pub mature: Option<u16>,
+    pub max: Option<u16>,
+}
+
+impl To5etools for Age {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            option_to_5etools_base(self.mature.as_ref(), "mature"),
+            option_to_5etools_base(self.max.as_ref(), "max"),
+        ])
+    }
+}
+
+/// Traits that races and subraces have in common.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RaceTraits {
+    pub ability: Option<RaceAbilities>,
+    /// Range of darkvision in feet.
+    pub darkvision: Option<u16>,
+    pub languages: LanguageProficiencies,
+    /// 5etools trait tags, e.g. `Powerful Build` or `Skill Proficiency`.
+    pub trait_tags: Vec<String>,
+    pub entries: Vec<Description>,
+}
+
+impl To5etools for RaceTraits {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({ "entries": self.entries.to_5etools_base() }),
+            option_to_5etools_base(self.ability.as_ref(), "ability"),
+            option_to_5etools_base(self.darkvision.as_ref(), "darkvision"),
+            if self.languages == LanguageProficiencies::default() {
+                json!({})
+            } else {
+                json!({ "languageProficiencies": self.languages.to_5etools_base() })
+            },
+            if self.trait_tags.is_empty() {
+                json!({})
+            } else {
+                json!({ "traitTags": self.trait_tags })
+            },
+        ])
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Subrace {
+    /// Name without the name of the race, e.g. `Hill` for `Hill Dwarf`.
+    pub name: String,
+    /// Speed if the subrace changes it, e.g. `Your base walking speed increases to 35 feet`.
+    pub speed: Option<Speed>,
+    pub traits: RaceTraits,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Race<'a> {
+    pub name: String,
+    pub source: Source<'a>,
+    pub size: Vec<Size>,
+    pub speed: Speed,
+    pub age: Option<Age>,
+    pub traits: RaceTraits,
+    pub subraces: Vec<Subrace>,
+}
+
+impl Race<'_> {
+    /// The subraces as 5etools `subrace` entries, which refer to the race by name and source.
+    #[must_use]
+    pub fn to_5etools_subraces(&self) -> Vec<Value> {
+        self.subraces
+            .iter()
+            .map(|subrace| {
+                merge_json(vec![
+                    json!({
+                        "name": subrace.name,
+                        "raceName": self.name,
+                        "raceSource": self.source.source_book,
+                    }),
+                    self.source.to_5etools_base(),
+                    option_to_5etools_base(subrace.speed.as_ref(), "speed"),
+                    subrace.traits.to_5etools_base(),
+                ])
+            })
+            .collect()
+    }
+}
+
+impl To5etools for Race<'_> {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "size": self.size.to_5etools_base(),
+                "speed": self.speed.to_5etools_base(),
+            }),
+            self.source.to_5etools_base(),
+            option_to_5etools_base(self.age.as_ref(), "age"),
+            self.traits.to_5etools_base(),
+        ])
+    }
+}
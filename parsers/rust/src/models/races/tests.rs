@@ -0,0 +1,99 @@
+use serde_json::json;
+
+use crate::{
+    models::{
+        backgrounds::LanguageProficiencies,
+        common::{AbilityScore, Description, Language, Source},
+        creatures::{Size, Speed, SpeedValue},
+    },
+    utils::traits::To5etools,
+};
+
+use super::{AbilityChoice, Age, Race, RaceAbilities, RaceTraits, Subrace};
+
+#[test]
+fn abilities() {
+    assert_eq!(
+        RaceAbilities {
+            fixed: vec![(AbilityScore::Dexterity, 2)],
+            choose: Some(AbilityChoice {
+                from: vec![AbilityScore::Strength, AbilityScore::Wisdom],
+                count: 1,
+                amount: 1,
+            }),
+        }
+        .to_5etools_base(),
+        json!([{"dex": 2, "choose": {"from": ["str", "wis"], "count": 1}}])
+    );
+}
+
+#[test]
+fn race_and_subraces() {
+    let race = Race {
+        name: "Stonekin".to_string(),
+        source: Source {
+            source_book: "test-source",
+            page: 3,
+        },
+        size: vec![Size::Medium],
+        speed: Speed {
+            walk: SpeedValue::from(25),
+            burrow: None,
+            climb: None,
+            crawl: None,
+            fly: None,
+            swim: None,
+            can_hover: false,
+            alternate: None,
+        },
+        age: Some(Age {
+            mature: Some(40),
+            max: Some(300),
+        }),
+        traits: RaceTraits {
+            ability: None,
+            darkvision: Some(60),
+            languages: LanguageProficiencies {
+                languages: vec![Language::Common],
+                any_standard: 1,
+            },
+            trait_tags: vec!["Powerful Build".to_string()],
+            entries: vec![Description::Entry("Hardy folk.".to_string())],
+        },
+        subraces: vec![Subrace {
+            name: "Deep".to_string(),
+            speed: None,
+            traits: RaceTraits {
+                darkvision: Some(120),
+                ..RaceTraits::default()
+            },
+        }],
+    };
+    assert_eq!(
+        race.to_5etools_base(),
+        json!({
+            "name": "Stonekin",
+            "source": "test-source",
+            "page": 3,
+            "size": ["M"],
+            "speed": {"walk": 25},
+            "age": {"mature": 40, "max": 300},
+            "darkvision": 60,
+            "languageProficiencies": [{"common": true, "anyStandard": 1}],
+            "traitTags": ["Powerful Build"],
+            "entries": ["Hardy folk."],
+        })
+    );
+    assert_eq!(
+        race.to_5etools_subraces(),
+        vec![json!({
+            "name": "Deep",
+            "source": "test-source",
+            "page": 3,
+            "raceName": "Stonekin",
+            "raceSource": "test-source",
+            "darkvision": 120,
+            "entries": [],
+        })]
+    );
+}
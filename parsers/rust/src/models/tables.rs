@@ -0,0 +1,87 @@
+use std::iter;
+
+use crate::{
+    models::common::{merge_json, Source},
+    utils::traits::To5etools,
+};
+use itertools::Itertools;
+use serde_json::{json, Value};
+
+#[cfg(test)]
+mod tests;
+
+/// The roll of a table row, e.g. `6` or `01–05`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableRoll {
+    pub min: u16,
+    pub max: u16,
+    /// Whether the roll is shown with leading zeros, e.g. `01–05`.
+    pub pad: bool,
+}
+
+impl To5etools for TableRoll {
+    fn to_5etools_base(&self) -> Value {
+        let roll = if self.min == self.max {
+            json!({ "exact": self.min })
+        } else {
+            json!({ "min": self.min, "max": self.max })
+        };
+        json!({
+            "type": "cell",
+            "roll": merge_json(vec![
+                roll,
+                if self.pad { json!({ "pad": true }) } else { json!({}) },
+            ]),
+        })
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableRow {
+    pub roll: TableRoll,
+    /// The other cells of the row, e.g. the result.
+    pub cells: Vec<String>,
+}
+
+/// A table to roll on, e.g. a random encounter or wild magic table.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table<'a> {
+    /// The caption of the table or, without a caption, the label of its result column.
+    pub name: String,
+    pub source: Source<'a>,
+    /// The dice of the roll column, e.g. `d100`.
+    pub dice: String,
+    /// Labels of the columns after the roll column.
+    pub col_labels: Vec<String>,
+    pub rows: Vec<TableRow>,
+}
+
+impl To5etools for Table<'_> {
+    fn to_5etools_base(&self) -> Value {
+        let column_width = 10 / self.col_labels.len().max(1);
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "colLabels": iter::once(format!("{{@dice {}}}", self.dice))
+                    .chain(self.col_labels.iter().cloned())
+                    .collect_vec(),
+                "colStyles": iter::once("col-2 text-center".to_string())
+                    .chain(self.col_labels.iter().map(|_| format!("col-{column_width}")))
+                    .collect_vec(),
+                "rows": self
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        iter::once(row.roll.to_5etools_base())
+                            .chain(row.cells.iter().map(|cell| json!(cell)))
+                            .collect_vec()
+                    })
+                    .collect_vec(),
+            }),
+            self.source.to_5etools_base(),
+        ])
+    }
+}
@@ -0,0 +1,50 @@
+use serde_json::json;
+
+use crate::{models::common::Source, utils::traits::To5etools};
+
+use super::{Table, TableRoll, TableRow};
+
+#[test]
+fn table() {
+    assert_eq!(
+        Table {
+            name: "Tide Pool Finds".to_string(),
+            source: Source {
+                source_book: "test-source",
+                page: 3,
+            },
+            dice: "d100".to_string(),
+            col_labels: vec!["Find".to_string()],
+            rows: vec![
+                TableRow {
+                    roll: TableRoll {
+                        min: 1,
+                        max: 50,
+                        pad: true,
+                    },
+                    cells: vec!["A crab".to_string()],
+                },
+                TableRow {
+                    roll: TableRoll {
+                        min: 100,
+                        max: 100,
+                        pad: false,
+                    },
+                    cells: vec!["A {@dice 1d4} gp pearl".to_string()],
+                },
+            ],
+        }
+        .to_5etools_base(),
+        json!({
+            "name": "Tide Pool Finds",
+            "source": "test-source",
+            "page": 3,
+            "colLabels": ["{@dice d100}", "Find"],
+            "colStyles": ["col-2 text-center", "col-10"],
+            "rows": [
+                [{"type": "cell", "roll": {"min": 1, "max": 50, "pad": true}}, "A crab"],
+                [{"type": "cell", "roll": {"exact": 100}}, "A {@dice 1d4} gp pearl"],
+            ],
+        })
+    );
+}
@@ -0,0 +1,84 @@
+use crate::{
+    models::common::{merge_json, Description, Source},
+    utils::traits::{option_to_5etools_base, To5etools},
+};
+use serde_json::{json, Value};
+
+#[cfg(test)]
+mod tests;
+
+/// Kind of a trap or hazard, as given in the line below its name, e.g. `*Mechanical trap*`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrapHazardType {
+    MechanicalTrap,
+    MagicTrap,
+    /// A trap with a trigger, an effect and countermeasures, e.g.
+    /// `*Simple trap (level 1–4, moderate threat)*`.
+    SimpleTrap {
+        tier: u8,
+        /// E.g. `setback`, `moderate` or `dangerous`.
+        threat: String,
+    },
+    Hazard,
+    EnvironmentalHazard,
+    WildernessHazard,
+    Weather,
+}
+
+impl TrapHazardType {
+    /// Whether this is a hazard rather than a trap, which 5etools lists separately.
+    #[must_use]
+    pub const fn is_hazard(&self) -> bool {
+        matches!(
+            self,
+            Self::Hazard | Self::EnvironmentalHazard | Self::WildernessHazard | Self::Weather
+        )
+    }
+}
+
+impl To5etools for TrapHazardType {
+    fn to_5etools_base(&self) -> Value {
+        match self {
+            Self::MechanicalTrap => json!({ "trapHazType": "MECH" }),
+            Self::MagicTrap => json!({ "trapHazType": "MAG" }),
+            Self::SimpleTrap { tier, threat } => json!({
+                "trapHazType": "SMPL",
+                "tier": tier,
+                "threat": threat,
+            }),
+            Self::Hazard => json!({ "trapHazType": "HAZ" }),
+            Self::EnvironmentalHazard => json!({ "trapHazType": "ENV" }),
+            Self::WildernessHazard => json!({ "trapHazType": "WLD" }),
+            Self::Weather => json!({ "trapHazType": "WTH" }),
+        }
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrapHazard<'a> {
+    pub name: String,
+    pub source: Source<'a>,
+    pub trap_hazard_type: TrapHazardType,
+    pub entries: Vec<Description>,
+    pub trigger: Option<Vec<Description>>,
+    pub effect: Option<Vec<Description>>,
+    pub countermeasures: Option<Vec<Description>>,
+}
+
+impl To5etools for TrapHazard<'_> {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "entries": self.entries.to_5etools_base(),
+            }),
+            self.source.to_5etools_base(),
+            self.trap_hazard_type.to_5etools_base(),
+            option_to_5etools_base(self.trigger.as_ref(), "trigger"),
+            option_to_5etools_base(self.effect.as_ref(), "effect"),
+            option_to_5etools_base(self.countermeasures.as_ref(), "countermeasures"),
+        ])
+    }
+}
@@ -0,0 +1,47 @@
+use serde_json::json;
+
+use crate::{
+    models::common::{Description, Source},
+    utils::traits::To5etools,
+};
+
+use super::{TrapHazard, TrapHazardType};
+
+#[test]
+fn trap_hazard_types() {
+    assert!(TrapHazardType::Weather.is_hazard());
+    assert!(!TrapHazardType::MagicTrap.is_hazard());
+}
+
+#[test]
+fn simple_trap() {
+    assert_eq!(
+        TrapHazard {
+            name: "Falling Anchor".to_string(),
+            source: Source {
+                source_book: "test-source",
+                page: 4,
+            },
+            trap_hazard_type: TrapHazardType::SimpleTrap {
+                tier: 1,
+                threat: "setback".to_string(),
+            },
+            entries: vec![],
+            trigger: Some(vec![Description::Entry("A rope is cut.".to_string())]),
+            effect: Some(vec![Description::Entry("An anchor falls.".to_string())]),
+            countermeasures: None,
+        }
+        .to_5etools_base(),
+        json!({
+            "name": "Falling Anchor",
+            "source": "test-source",
+            "page": 4,
+            "trapHazType": "SMPL",
+            "tier": 1,
+            "threat": "setback",
+            "entries": [],
+            "trigger": ["A rope is cut."],
+            "effect": ["An anchor falls."],
+        })
+    );
+}
@@ -0,0 +1,229 @@
+use crate::{
+    models::{
+        common::{merge_json, Description, NamedEntry, Source, StatusCondition},
+        creatures::{AbilityScores, ArmorClass, DamageModifier, HitPoints, Size, Speed},
+        objects::hit_points_to_5etools,
+    },
+    utils::traits::{option_to_5etools_base, option_to_5etools_creature, To5etools},
+};
+use serde_json::{json, Value};
+
+#[cfg(test)]
+mod tests;
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VehicleType {
+    /// A ship with a hull and components, e.g. a galley.
+    Ship,
+    /// An infernal war machine with action stations, e.g. a devil's ride.
+    InfernalWarMachine,
+}
+
+impl To5etools for VehicleType {
+    fn to_5etools_base(&self) -> Value {
+        json!(match self {
+            Self::Ship => "SHIP",
+            Self::InfernalWarMachine => "INFWAR",
+        })
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentType {
+    /// E.g. a helm.
+    Control,
+    /// E.g. oars or sails.
+    Movement,
+    Weapon,
+}
+
+impl ComponentType {
+    /// The key of the components of this type in a 5etools ship.
+    const fn key(self) -> &'static str {
+        match self {
+            Self::Control => "control",
+            Self::Movement => "movement",
+            Self::Weapon => "weapon",
+        }
+    }
+}
+
+/// A component of a ship, e.g. `Control: Helm` or `Weapons: Ballistae (4)`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VehicleComponent {
+    pub name: String,
+    pub component_type: ComponentType,
+    /// Number of identical components, e.g. 4 for `Ballistae (4)`.
+    pub count: Option<u8>,
+    pub armor_class: ArmorClass,
+    pub hit_points: HitPoints,
+    pub damage_threshold: Option<u16>,
+    pub entries: Vec<Description>,
+}
+
+impl To5etools for VehicleComponent {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "ac": self.armor_class.ac,
+                "hp": hit_points_to_5etools(&self.hit_points),
+                "entries": self.entries.to_5etools_base(),
+            }),
+            option_to_5etools_base(self.count.as_ref(), "count"),
+            option_to_5etools_base(self.damage_threshold.as_ref(), "dt"),
+        ])
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vehicle<'a> {
+    pub name: String,
+    pub source: Source<'a>,
+    pub vehicle_type: VehicleType,
+    pub size: Size,
+    /// Length and width of a ship, e.g. `130 ft.` and `20 ft.`.
+    pub dimensions: Option<Vec<String>>,
+    /// Weight in pounds.
+    pub weight: Option<u32>,
+    pub crew: Option<u16>,
+    pub passengers: Option<u16>,
+    /// Cargo capacity in pounds.
+    pub cargo: Option<u32>,
+    /// Travel pace in miles per hour.
+    pub pace: Option<u8>,
+    /// Armor class of the vehicle or, for ships, of the hull.
+    pub armor_class: ArmorClass,
+    /// Hit points of the vehicle or, for ships, of the hull.
+    pub hit_points: HitPoints,
+    pub damage_threshold: Option<u16>,
+    pub mishap_threshold: Option<u16>,
+    pub speed: Option<Speed>,
+    pub ability_scores: AbilityScores,
+    pub damage_resistance: Option<Vec<DamageModifier>>,
+    pub damage_immunity: Option<Vec<DamageModifier>>,
+    pub condition_immunities: Option<Vec<StatusCondition>>,
+    pub traits: Option<Vec<NamedEntry>>,
+    /// Introduction of the actions of a ship, e.g. how many actions it can take per turn.
+    pub actions: Vec<Description>,
+    pub action_stations: Option<Vec<NamedEntry>>,
+    pub reactions: Option<Vec<NamedEntry>>,
+    pub components: Vec<VehicleComponent>,
+}
+
+impl Vehicle<'_> {
+    /// Cargo capacity in tons, as 5etools expects it.
+    fn cargo_to_5etools(&self) -> Value {
+        self.cargo.map_or_else(
+            || json!({}),
+            |cargo| {
+                json!({
+                    "capCargo": if cargo % 2000 == 0 {
+                        json!(cargo / 2000)
+                    } else {
+                        json!(f64::from(cargo) / 2000.0)
+                    }
+                })
+            },
+        )
+    }
+
+    fn ship_to_5etools(&self) -> Value {
+        let components = [
+            ComponentType::Control,
+            ComponentType::Movement,
+            ComponentType::Weapon,
+        ]
+        .into_iter()
+        .filter_map(|component_type| {
+            let components = self
+                .components
+                .iter()
+                .filter(|component| component.component_type == component_type)
+                .map(To5etools::to_5etools_base)
+                .collect::<Vec<_>>();
+            (!components.is_empty()).then(|| json!({ component_type.key(): components }))
+        });
+        merge_json(
+            [
+                json!({
+                    "size": self.size.to_5etools_base(),
+                    "hull": merge_json(vec![
+                        json!({
+                            "ac": self.armor_class.ac,
+                            "hp": hit_points_to_5etools(&self.hit_points),
+                        }),
+                        option_to_5etools_base(self.damage_threshold.as_ref(), "dt"),
+                    ]),
+                }),
+                option_to_5etools_base(self.dimensions.as_ref(), "dimensions"),
+                option_to_5etools_base(self.crew.as_ref(), "capCrew"),
+                option_to_5etools_base(self.passengers.as_ref(), "capPassenger"),
+                option_to_5etools_base(self.pace.as_ref(), "pace"),
+                if self.actions.is_empty() {
+                    json!({})
+                } else {
+                    json!({ "action": self.actions.to_5etools_base() })
+                },
+            ]
+            .into_iter()
+            .chain(components)
+            .collect(),
+        )
+    }
+
+    fn infernal_war_machine_to_5etools(&self) -> Value {
+        merge_json(vec![
+            json!({
+                "size": self.size.to_5etools_base(),
+                "ac": self.armor_class.ac,
+                "hp": merge_json(vec![
+                    json!({ "hp": hit_points_to_5etools(&self.hit_points) }),
+                    option_to_5etools_base(self.damage_threshold.as_ref(), "dt"),
+                    option_to_5etools_base(self.mishap_threshold.as_ref(), "mt"),
+                ]),
+            }),
+            option_to_5etools_base(self.weight.as_ref(), "weight"),
+            // Infernal war machines only state the total number of creatures they can carry.
+            self.crew.or(self.passengers).map_or_else(
+                || json!({}),
+                |_| {
+                    json!({
+                        "capCreature": self.crew.unwrap_or(0) + self.passengers.unwrap_or(0)
+                    })
+                },
+            ),
+            self.speed
+                .as_ref()
+                .map_or_else(|| json!({}), |speed| json!({ "speed": speed.walk.speed })),
+            option_to_5etools_creature(self.action_stations.as_ref(), "actionStation"),
+            option_to_5etools_creature(self.reactions.as_ref(), "reaction"),
+        ])
+    }
+}
+
+impl To5etools for Vehicle<'_> {
+    fn to_5etools_base(&self) -> Value {
+        merge_json(vec![
+            json!({
+                "name": self.name,
+                "vehicleType": self.vehicle_type.to_5etools_base(),
+            }),
+            self.source.to_5etools_base(),
+            self.ability_scores.to_5etools_creature(),
+            self.cargo_to_5etools(),
+            option_to_5etools_creature(self.damage_resistance.as_ref(), "resist"),
+            option_to_5etools_creature(self.damage_immunity.as_ref(), "immune"),
+            option_to_5etools_creature(self.condition_immunities.as_ref(), "conditionImmune"),
+            option_to_5etools_creature(self.traits.as_ref(), "trait"),
+            match self.vehicle_type {
+                VehicleType::Ship => self.ship_to_5etools(),
+                VehicleType::InfernalWarMachine => self.infernal_war_machine_to_5etools(),
+            },
+        ])
+    }
+}
@@ -0,0 +1,94 @@
+use serde_json::json;
+
+use crate::{
+    models::{
+        common::{Description, Source},
+        creatures::{AbilityScores, ArmorClass, HitPoints, Size},
+    },
+    utils::traits::To5etools,
+};
+
+use super::{ComponentType, Vehicle, VehicleComponent, VehicleType};
+
+fn armor_class(ac: u8) -> ArmorClass {
+    ArmorClass {
+        ac,
+        armor_type: None,
+        condition: None,
+        braces: false,
+    }
+}
+
+#[test]
+fn ship() {
+    assert_eq!(
+        Vehicle {
+            name: "Coral Skiff".to_string(),
+            source: Source {
+                source_book: "test-source",
+                page: 8,
+            },
+            vehicle_type: VehicleType::Ship,
+            size: Size::Large,
+            dimensions: None,
+            weight: None,
+            crew: Some(2),
+            passengers: None,
+            cargo: Some(500),
+            pace: None,
+            armor_class: armor_class(11),
+            hit_points: HitPoints::Fixed(50),
+            damage_threshold: None,
+            mishap_threshold: None,
+            speed: None,
+            ability_scores: AbilityScores {
+                strength: 10,
+                dexterity: 12,
+                constitution: 10,
+                intelligence: 0,
+                wisdom: 0,
+                charisma: 0,
+            },
+            damage_resistance: None,
+            damage_immunity: None,
+            condition_immunities: None,
+            traits: None,
+            actions: vec![],
+            action_stations: None,
+            reactions: None,
+            components: vec![VehicleComponent {
+                name: "Sail".to_string(),
+                component_type: ComponentType::Movement,
+                count: None,
+                armor_class: armor_class(12),
+                hit_points: HitPoints::Fixed(10),
+                damage_threshold: Some(5),
+                entries: vec![Description::Entry("Speed (wind) 2 mph".to_string())],
+            }],
+        }
+        .to_5etools_base(),
+        json!({
+            "name": "Coral Skiff",
+            "source": "test-source",
+            "page": 8,
+            "vehicleType": "SHIP",
+            "size": "L",
+            "capCrew": 2,
+            "capCargo": 0.25,
+            "hull": {"ac": 11, "hp": 50},
+            "str": 10,
+            "dex": 12,
+            "con": 10,
+            "int": 0,
+            "wis": 0,
+            "cha": 0,
+            "movement": [{
+                "name": "Sail",
+                "ac": 12,
+                "hp": 10,
+                "dt": 5,
+                "entries": ["Speed (wind) 2 mph"],
+            }],
+        })
+    );
+}
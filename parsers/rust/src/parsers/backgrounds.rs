@@ -0,0 +1,294 @@
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{
+    models::{
+        backgrounds::{
+            Background, Equipment, LanguageProficiencies, SkillProficiencies, ToolProficiency,
+        },
+        common::{Description, Language, Skill, Source},
+        items::ItemValue,
+    },
+    utils::{
+        error::{OutOfBoundsError, Result},
+        markdown::{
+            document_lines, heading_block, heading_level, is_heading, parse_block_entries,
+            parse_count, split_list, split_top_level,
+        },
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Parse all backgrounds in a GM Binder or Homebrewery document.
+///
+/// A background is a heading whose text has a `Skill Proficiencies` line. It includes the
+/// subheadings below it, e.g. its feature and suggested characteristics.
+#[must_use]
+pub fn parse_backgrounds<'a>(document: &str, source: &Source<'a>) -> Vec<Result<Background<'a>>> {
+    extract_backgrounds(document)
+        .into_iter()
+        .map(|background| parse_background(&background, source.clone()))
+        .collect()
+}
+
+/// Parse a property line of a background, e.g. `**Skill Proficiencies:** Insight, Religion`.
+///
+/// Returns: The label, the value without emphasis and the label in lowercase.
+fn parse_property(line: &str) -> Option<(String, String, String)> {
+    let property_re = Regex::new(
+        r"(?i)^(?:[-•] )?[*_]*(?P<label>skill proficiencies|tool proficienc(?:y|ies)|languages|equipment|ability scores|feat)[*_]*[:.][*_]*\s*(?P<value>.+)$",
+    )
+    .unwrap();
+    property_re.captures(line).map(|captures| {
+        (
+            captures["label"].to_string(),
+            captures["value"].replace(['*', '_'], "").trim().to_string(),
+            captures["label"].to_lowercase(),
+        )
+    })
+}
+
+fn is_property_line(line: &str) -> bool {
+    parse_property(line).is_some()
+}
+
+/// Split a document into backgrounds, each starting with its heading and ending before the next
+/// heading of the same or a higher level.
+pub(crate) fn extract_backgrounds(document: &str) -> Vec<Vec<String>> {
+    let lines = document_lines(document);
+    lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| heading_level(line).map(|level| (index, level)))
+        .filter(|(index, _)| {
+            lines[index + 1..]
+                .iter()
+                .take_while(|line| !is_heading(line))
+                .any(|line| {
+                    parse_property(line).is_some_and(|(_, _, label)| label == "skill proficiencies")
+                })
+        })
+        .map(|(index, _)| heading_block(&lines, index))
+        .collect()
+}
+
+/// Parse a single background.
+///
+/// * `background` - Lines of the background, starting with its heading
+/// * `source` - Source book of the background
+///
+/// Returns: The parsed background.
+fn parse_background<'a>(background: &[String], source: Source<'a>) -> Result<Background<'a>> {
+    let [heading, lines @ ..] = background else {
+        return Err(OutOfBoundsError {
+            array: background.to_vec(),
+            index: 0,
+            parsing_step: "Background".to_string(),
+            problem: Some("Expected a heading".to_string()),
+        }
+        .into());
+    };
+    let mut skill_proficiencies = SkillProficiencies::default();
+    let mut tool_proficiencies = vec![];
+    let mut language_proficiencies = LanguageProficiencies::default();
+    let mut starting_equipment = vec![];
+    let properties = lines
+        .iter()
+        .filter_map(|line| parse_property(line))
+        .collect_vec();
+    for (_, value, label) in &properties {
+        match &label[..] {
+            "skill proficiencies" => skill_proficiencies = parse_skill_proficiencies(value)?,
+            "languages" => language_proficiencies = parse_language_proficiencies(value),
+            "equipment" => starting_equipment = parse_starting_equipment(value)?,
+            label if label.starts_with("tool") => {
+                tool_proficiencies = parse_tool_proficiencies(value);
+            }
+            _ => {}
+        }
+    }
+    Ok(Background {
+        name: heading.trim_start_matches('#').trim().to_string(),
+        source,
+        skill_proficiencies,
+        tool_proficiencies,
+        language_proficiencies,
+        starting_equipment,
+        entries: parse_background_entries(lines, properties),
+    })
+}
+
+/// Parse the entries of a background, where the property lines become a list in place of the
+/// first property line.
+fn parse_background_entries(
+    lines: &[String],
+    properties: Vec<(String, String, String)>,
+) -> Vec<Description> {
+    let first_property = lines
+        .iter()
+        .position(|line| is_property_line(line))
+        .unwrap_or(lines.len());
+    let other_lines = |lines: &[String]| {
+        lines
+            .iter()
+            .filter(|line| !is_property_line(line))
+            .cloned()
+            .collect_vec()
+    };
+    let mut entries = parse_block_entries(&other_lines(&lines[..first_property]));
+    if !properties.is_empty() {
+        entries.push(Description::List(
+            properties
+                .into_iter()
+                .map(|(label, value, _)| Description::Item {
+                    name: format!("{label}:"),
+                    entry: value,
+                })
+                .collect(),
+        ));
+    }
+    entries.extend(parse_block_entries(&other_lines(&lines[first_property..])));
+    entries
+}
+
+fn parse_skills(skills: &str) -> Result<Vec<Skill>> {
+    split_list(skills)
+        .into_iter()
+        .map(|skill| Skill::try_from(&skill.to_lowercase()[..]))
+        .collect()
+}
+
+/// Parse skill proficiencies, e.g. `Insight, Religion` or `Two of the following: Arcana,
+/// History, Nature`.
+pub(crate) fn parse_skill_proficiencies(skills: &str) -> Result<SkillProficiencies> {
+    let choose_re = Regex::new(
+        r"(?i)^(?:choose )?(?P<count>\w+) (?:of the following|(?:skills? )?from(?: among)?):? (?P<skills>.+?)\.?$",
+    )
+    .unwrap();
+    Ok(match choose_re.captures(skills) {
+        Some(captures) => SkillProficiencies {
+            skills: vec![],
+            choose: Some((
+                parse_skills(&captures["skills"])?,
+                parse_count(&captures["count"], "Skill proficiencies")?,
+            )),
+        },
+        None => SkillProficiencies {
+            skills: parse_skills(skills.trim_end_matches('.'))?,
+            choose: None,
+        },
+    })
+}
+
+/// Parse tool proficiencies, e.g. `One type of gaming set, vehicles (land)`.
+fn parse_tool_proficiencies(tools: &str) -> Vec<ToolProficiency> {
+    split_list(tools.trim_end_matches('.'))
+        .into_iter()
+        .filter(|tool| !matches!(*tool, "—" | "-" | "–") && !tool.eq_ignore_ascii_case("none"))
+        .flat_map(|tool| {
+            let lowercase = tool.to_lowercase();
+            let any_tool = [
+                ("artisan", ToolProficiency::AnyArtisansTool),
+                ("gaming set", ToolProficiency::AnyGamingSet),
+                ("musical instrument", ToolProficiency::AnyMusicalInstrument),
+            ]
+            .into_iter()
+            .find(|(name, _)| lowercase.contains(name))
+            .map(|(_, any_tool)| any_tool);
+            match any_tool {
+                Some(any_tool) => {
+                    let count = lowercase
+                        .split_whitespace()
+                        .next()
+                        .and_then(|count| parse_count(count, "Tool proficiencies").ok())
+                        .unwrap_or(1);
+                    vec![any_tool; count.into()]
+                }
+                None => vec![ToolProficiency::Tool(lowercase)],
+            }
+        })
+        .collect()
+}
+
+/// Parse language proficiencies, e.g. `Two of your choice` or `Elvish and one extra language of
+/// your choice`.
+pub(crate) fn parse_language_proficiencies(languages: &str) -> LanguageProficiencies {
+    let any_re = Regex::new(
+        r"(?i)^(?:any )?(?P<count>\w+) (?:other |extra |additional )?(?:(?:standard )?languages? ?)?(?:of your choice)?$",
+    )
+    .unwrap();
+    let mut proficiencies = LanguageProficiencies::default();
+    for language in split_list(languages.trim_end_matches('.')) {
+        if matches!(language, "—" | "-" | "–") || language.eq_ignore_ascii_case("none") {
+            continue;
+        }
+        match any_re
+            .captures(language)
+            .and_then(|captures| parse_count(&captures["count"], "Languages").ok())
+        {
+            Some(count) => proficiencies.any_standard += count,
+            None => proficiencies.languages.push(Language::from(language)),
+        }
+    }
+    proficiencies
+}
+
+/// Parse starting equipment, e.g. `A holy symbol, 5 sticks of incense, and a belt pouch
+/// containing 15 gp` or `Choose A or B: (A) Robe, 8 GP; or (B) 50 GP`.
+///
+/// Returns: The alternative sets of equipment.
+fn parse_starting_equipment(equipment: &str) -> Result<Vec<Vec<Equipment>>> {
+    let choose_re = Regex::new(r"(?i)^choose [a-z](?:, [a-z])*,? or [a-z]:\s*").unwrap();
+    let option_re = Regex::new(r";? ?(?:or )?\([A-Z]\) ").unwrap();
+    let equipment = choose_re.replace(equipment.trim().trim_end_matches('.'), "");
+    option_re
+        .split(&equipment)
+        .map(str::trim)
+        .filter(|option| !option.is_empty())
+        .map(|option| {
+            split_top_level(option)
+                .into_iter()
+                .map(|equipment| equipment.trim_start_matches("and ").trim())
+                .filter(|equipment| !equipment.is_empty())
+                .map(parse_equipment)
+                .collect()
+        })
+        .collect()
+}
+
+/// Parse a single piece of equipment, e.g. `5 sticks of incense`, `a belt pouch containing 15
+/// gp` or `10 gp`.
+fn parse_equipment(equipment: &str) -> Result<Equipment> {
+    let coins_re = Regex::new(r"(?i)^[\d,]+ ?(?:cp|sp|ep|gp|pp)$").unwrap();
+    let containing_re = Regex::new(r"(?i)^(?P<item>.+?) containing (?P<coins>.+)$").unwrap();
+    let quantity_re = Regex::new(r"^(?P<quantity>\d+) (?P<item>.+)$").unwrap();
+    if coins_re.is_match(equipment) {
+        return Ok(Equipment::Coins(ItemValue::try_from(equipment)?));
+    }
+    let (item, contains) = match containing_re.captures(equipment) {
+        Some(captures) => (
+            captures.name("item").map_or("", |item| item.as_str()),
+            Some(ItemValue::try_from(&captures["coins"])?),
+        ),
+        None => (equipment, None),
+    };
+    let item = item
+        .trim_start_matches("a ")
+        .trim_start_matches("an ")
+        .trim_start_matches("A ")
+        .trim_start_matches("An ");
+    Ok(match quantity_re.captures(item) {
+        Some(captures) => Equipment::Item {
+            name: captures["item"].to_string(),
+            quantity: parse_count(&captures["quantity"], "Equipment quantity")?,
+            contains,
+        },
+        None => Equipment::Item {
+            name: item.to_string(),
+            quantity: 1,
+            contains,
+        },
+    })
+}
@@ -0,0 +1,125 @@
+use std::fs;
+
+use serde_json::Value;
+
+use crate::{
+    models::{
+        backgrounds::{Equipment, LanguageProficiencies, SkillProficiencies, ToolProficiency},
+        common::{Language, Skill, Source},
+        items::{Currency, ItemValue},
+    },
+    utils::{compare::json_compare, traits::To5etools},
+};
+
+use super::{
+    extract_backgrounds, parse_backgrounds, parse_language_proficiencies,
+    parse_skill_proficiencies, parse_starting_equipment, parse_tool_proficiencies,
+};
+
+const SOURCE: Source = Source {
+    source_book: "test-source",
+    page: 0,
+};
+
+#[test]
+fn extract_backgrounds_test() {
+    let document = fs::read_to_string("resources/test/backgrounds/unit_input.md").unwrap();
+    let backgrounds = extract_backgrounds(&document);
+    assert_eq!(backgrounds.len(), 2);
+    assert_eq!(backgrounds[0].len(), 18);
+    assert_eq!(backgrounds[1][0], "### Archivist");
+    assert_eq!(backgrounds[1].len(), 7);
+}
+
+#[test]
+fn proficiencies() {
+    assert_eq!(
+        parse_skill_proficiencies("Insight and Religion"),
+        Ok(SkillProficiencies {
+            skills: vec![Skill::Insight, Skill::Religion],
+            choose: None,
+        })
+    );
+    assert_eq!(
+        parse_skill_proficiencies("Choose two from Animal Handling, Nature, or Survival"),
+        Ok(SkillProficiencies {
+            skills: vec![],
+            choose: Some((
+                vec![Skill::AnimalHandling, Skill::Nature, Skill::Survival],
+                2
+            )),
+        })
+    );
+    assert!(parse_skill_proficiencies("Sailing").is_err());
+    assert_eq!(
+        parse_tool_proficiencies("Two types of musical instruments, vehicles (water)"),
+        vec![
+            ToolProficiency::AnyMusicalInstrument,
+            ToolProficiency::AnyMusicalInstrument,
+            ToolProficiency::Tool("vehicles (water)".to_string()),
+        ]
+    );
+    assert_eq!(parse_tool_proficiencies("None"), vec![]);
+    assert_eq!(
+        parse_language_proficiencies("Dwarvish, Giant, and any one language"),
+        LanguageProficiencies {
+            languages: vec![Language::Dwarvish, Language::Giant],
+            any_standard: 1,
+        }
+    );
+    assert_eq!(
+        parse_language_proficiencies("Two of your choice"),
+        LanguageProficiencies {
+            languages: vec![],
+            any_standard: 2,
+        }
+    );
+}
+
+#[test]
+fn starting_equipment() {
+    let gold = |value| ItemValue {
+        value,
+        unit: Currency::Gold,
+    };
+    let item = |name: &str, quantity| Equipment::Item {
+        name: name.to_string(),
+        quantity,
+        contains: None,
+    };
+    assert_eq!(
+        parse_starting_equipment(
+            "A prayer book, 5 sticks of incense, and a belt pouch containing 15 gp"
+        ),
+        Ok(vec![vec![
+            item("prayer book", 1),
+            item("sticks of incense", 5),
+            Equipment::Item {
+                name: "belt pouch".to_string(),
+                quantity: 1,
+                contains: Some(gold(15)),
+            },
+        ]])
+    );
+    assert_eq!(
+        parse_starting_equipment("Choose A or B: (A) Robe, 8 GP; or (B) 50 GP"),
+        Ok(vec![
+            vec![item("Robe", 1), Equipment::Coins(gold(8))],
+            vec![Equipment::Coins(gold(50))],
+        ])
+    );
+}
+
+#[test]
+fn backgrounds() {
+    let document = fs::read_to_string("resources/test/backgrounds/unit_input.md").unwrap();
+    let backgrounds = parse_backgrounds(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let expected: Value = serde_json::from_str(
+        &fs::read_to_string("resources/test/backgrounds/unit_output.json").unwrap(),
+    )
+    .unwrap();
+    json_compare(backgrounds.to_5etools_base(), expected).unwrap();
+}
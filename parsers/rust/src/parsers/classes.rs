@@ -0,0 +1,562 @@
+use std::iter;
+
+use itertools::Itertools;
+use regex::{Captures, Regex};
+
+use crate::{
+    models::{
+        classes::{Class, ClassFeature, ClassTable, StartingProficiencies, Subclass},
+        common::{Classes, Description, Source},
+    },
+    parsers::{backgrounds::parse_skill_proficiencies, feats::parse_abilities},
+    utils::{
+        error::{OutOfBoundsError, ParseError, Result},
+        markdown::{
+            document_lines, heading_block, heading_level, heading_name, parse_block_entries,
+            parse_markdown_tables, split_list,
+        },
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Prefixes and suffixes of subclass names that are not part of their short names, e.g. `Oath
+/// of` in `Oath of Glory`.
+const SUBCLASS_PREFIXES: [&str; 12] = [
+    "path of the ",
+    "path of ",
+    "oath of the ",
+    "oath of ",
+    "college of the ",
+    "college of ",
+    "circle of the ",
+    "circle of ",
+    "way of the ",
+    "way of ",
+    "school of ",
+    "the ",
+];
+const SUBCLASS_SUFFIXES: [&str; 4] = [" domain", " conclave", " bloodline", " patron"];
+
+/// Names of the features gained at each level.
+type LevelFeatures = Vec<(u8, Vec<String>)>;
+
+/// A subclass with the headings it is nested in, from the closest to the farthest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SubclassBlock {
+    ancestors: Vec<String>,
+    pub(crate) lines: Vec<String>,
+}
+
+/// Parse all classes in a GM Binder or Homebrewery document.
+///
+/// A class is the heading of a progression table, i.e. a table with a `Level` and a `Features`
+/// column. Its features are the subheadings named in the table.
+#[must_use]
+pub fn parse_classes<'a>(document: &str, source: &Source<'a>) -> Vec<Result<Class<'a>>> {
+    extract_classes(document)
+        .into_iter()
+        .map(|class| parse_class(&class, source.clone()))
+        .collect()
+}
+
+/// Parse all subclasses in a GM Binder or Homebrewery document.
+///
+/// A subclass is a heading with feature subheadings, which start with the level of the feature,
+/// e.g. `*3rd-level Oath of Glory feature*` or `#### Level 3: Peerless Athlete`.
+#[must_use]
+pub fn parse_subclasses<'a>(document: &str, source: &Source<'a>) -> Vec<Result<Subclass<'a>>> {
+    extract_subclasses(document)
+        .into_iter()
+        .map(|subclass| parse_subclass(&subclass, source.clone()))
+        .collect()
+}
+
+/// Parse the number of an ordinal or a level, e.g. `3rd` or `Level 3`.
+fn parse_ordinal(text: &str) -> Option<u8> {
+    let ordinal_re = Regex::new(r"(?i)^(?:level )?(?P<level>\d+)(?:st|nd|rd|th)?$").unwrap();
+    ordinal_re.captures(text.trim())?["level"].parse().ok()
+}
+
+fn is_progression_table(col_labels: &[String]) -> bool {
+    col_labels
+        .first()
+        .is_some_and(|label| label.eq_ignore_ascii_case("level"))
+        && col_labels
+            .iter()
+            .any(|label| label.eq_ignore_ascii_case("features"))
+}
+
+/// Parse the level and name of a subclass feature from its heading and the line below it, e.g.
+/// `#### Level 3: Peerless Athlete` or `#### Peerless Athlete` with `*3rd-level Oath of Glory
+/// feature*`.
+fn parse_feature_heading(heading: &str, next_line: Option<&str>) -> Option<(String, u8)> {
+    let level_heading_re = Regex::new(r"(?i)^level (?P<level>\d+):\s*(?P<name>.+)$").unwrap();
+    let marker_re = Regex::new(
+        r"(?i)^[*_]+(?:(?P<ordinal>\d+)(?:st|nd|rd|th)[- ]level|level (?P<level>\d+))\b[^*_]*\bfeature[*_.]*$",
+    )
+    .unwrap();
+    let name = heading_name(heading);
+    if let Some(captures) = level_heading_re.captures(&name) {
+        return Some((
+            captures["name"].to_string(),
+            captures["level"].parse().ok()?,
+        ));
+    }
+    let captures = marker_re.captures(next_line?.trim())?;
+    let level = captures
+        .name("ordinal")
+        .or_else(|| captures.name("level"))?
+        .as_str()
+        .parse()
+        .ok()?;
+    Some((name, level))
+}
+
+fn is_feature_marker(line: &str) -> bool {
+    parse_feature_heading("Feature", Some(line)).is_some()
+}
+
+/// Indices of the headings of classes, i.e. the headings of progression tables, or their parent
+/// headings if they are named after them or as tables, e.g. `The Berserker` below `Berserker`.
+fn class_headings(lines: &[&str]) -> Vec<usize> {
+    let headings = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| heading_level(line).map(|level| (index, level)))
+        .collect_vec();
+    lines
+        .iter()
+        .tuple_windows()
+        .enumerate()
+        .filter(|(_, (header, separator))| {
+            header.starts_with('|')
+                && separator.starts_with('|')
+                && parse_markdown_tables(&[header, separator]).iter().any(|table| {
+                    matches!(table, Description::Table { col_labels, .. } if is_progression_table(col_labels))
+                })
+        })
+        .filter_map(|(index, _)| {
+            let (position, &(heading, level)) = headings
+                .iter()
+                .enumerate()
+                .rev()
+                .find(|(_, (heading, _))| *heading < index)?;
+            let name = heading_name(lines[heading]).to_lowercase();
+            Some(
+                headings[..position]
+                    .iter()
+                    .rev()
+                    .find(|(_, parent_level)| *parent_level < level)
+                    .filter(|(parent, _)| {
+                        name.contains("table")
+                            || name.contains(&heading_name(lines[*parent]).to_lowercase())
+                    })
+                    .map_or(heading, |(parent, _)| *parent),
+            )
+        })
+        .unique()
+        .collect()
+}
+
+/// Split a document into classes, each starting with its heading and ending before the next
+/// heading of the same or a higher level.
+pub(crate) fn extract_classes(document: &str) -> Vec<Vec<String>> {
+    let lines = document_lines(document);
+    class_headings(&lines)
+        .into_iter()
+        .map(|start| heading_block(&lines, start))
+        .collect()
+}
+
+/// Split a document into subclasses, each starting with its heading and ending before the next
+/// heading of the same or a higher level.
+pub(crate) fn extract_subclasses(document: &str) -> Vec<SubclassBlock> {
+    let lines = document_lines(document);
+    let class_headings = class_headings(&lines);
+    let headings = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| heading_level(line).map(|level| (index, level)))
+        .collect_vec();
+    let subclass_headings = headings
+        .iter()
+        .enumerate()
+        .filter(|(_, (index, _))| {
+            parse_feature_heading(lines[*index], lines.get(index + 1).copied()).is_some()
+        })
+        .filter_map(|(position, &(_, level))| {
+            headings[..position]
+                .iter()
+                .rev()
+                .find(|(_, parent_level)| *parent_level < level)
+        })
+        .filter(|(index, _)| !class_headings.contains(index))
+        .unique()
+        .collect_vec();
+
+    let mut subclasses = vec![];
+    let mut end = 0;
+    for &(start, level) in subclass_headings {
+        // Features of subclasses nested in subclasses, e.g. variants, are part of the outer one.
+        if start < end {
+            continue;
+        }
+        let mut ancestor_level = level;
+        let ancestors = headings
+            .iter()
+            .rev()
+            .filter(|(index, ancestor)| {
+                *index < start && *ancestor < ancestor_level && {
+                    ancestor_level = *ancestor;
+                    true
+                }
+            })
+            .map(|(index, _)| heading_name(lines[*index]))
+            .collect();
+        let subclass = heading_block(&lines, start);
+        end = start + subclass.len();
+        subclasses.push(SubclassBlock {
+            ancestors,
+            lines: subclass,
+        });
+    }
+    subclasses
+}
+
+/// Parse the progression table of a class.
+///
+/// Returns: The features gained at each level and the other columns.
+fn parse_progression_table(lines: &[String]) -> Option<(LevelFeatures, ClassTable)> {
+    let table_lines = lines
+        .iter()
+        .map(String::as_str)
+        .skip_while(|line| !line.starts_with('|'))
+        .take_while(|line| line.starts_with('|'))
+        .collect_vec();
+    let (col_labels, rows) = parse_markdown_tables(&table_lines)
+        .into_iter()
+        .find_map(|table| match table {
+            Description::Table {
+                col_labels, rows, ..
+            } if is_progression_table(&col_labels) => Some((col_labels, rows)),
+            _ => None,
+        })?;
+    let features_column = col_labels
+        .iter()
+        .position(|label| label.eq_ignore_ascii_case("features"))?;
+    let other_columns = (1..col_labels.len())
+        .filter(|column| {
+            *column != features_column
+                && !col_labels[*column].eq_ignore_ascii_case("proficiency bonus")
+        })
+        .collect_vec();
+    let features = rows
+        .iter()
+        .filter_map(|row| {
+            let level = parse_ordinal(row.first()?)?;
+            let features = row
+                .get(features_column)?
+                .split(',')
+                .map(|feature| feature.replace(['*', '_'], "").trim().to_string())
+                .filter(|feature| !feature.is_empty() && !matches!(&feature[..], "—" | "-" | "–"))
+                .collect();
+            Some((level, features))
+        })
+        .collect();
+    let table = ClassTable {
+        col_labels: other_columns
+            .iter()
+            .map(|column| col_labels[*column].clone())
+            .collect(),
+        rows: rows
+            .iter()
+            .map(|row| {
+                other_columns
+                    .iter()
+                    .map(|column| row.get(*column).cloned().unwrap_or_default())
+                    .collect()
+            })
+            .collect(),
+    };
+    Some((features, table))
+}
+
+/// Parse a property line of a class, e.g. `**Hit Dice:** 1d10 per berserker level`.
+///
+/// Returns: The label in lowercase and the value without emphasis.
+fn parse_class_property(line: &str) -> Option<(String, String)> {
+    let property_re = Regex::new(
+        r"(?i)^(?:[-•] )?[*_]*(?P<label>hit dice|saving throws|armor|weapons|tools|skills)[*_]*:[*_]*\s*(?P<value>.+)$",
+    )
+    .unwrap();
+    property_re.captures(line).map(|captures| {
+        (
+            captures["label"].to_lowercase(),
+            captures["value"].replace(['*', '_'], "").trim().to_string(),
+        )
+    })
+}
+
+/// Parse a list of proficiencies, e.g. `Light armor, medium armor, shields`.
+fn parse_proficiency_list(list: &str) -> Vec<String> {
+    split_list(list.trim_end_matches('.'))
+        .into_iter()
+        .filter(|item| !matches!(*item, "—" | "-" | "–") && !item.eq_ignore_ascii_case("none"))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Lines below the heading of a feature until the next heading of the same or a higher level,
+/// without the level marker of subclass features.
+fn feature_lines(lines: &[String], heading: usize) -> Vec<String> {
+    let lines = lines.iter().map(String::as_str).collect_vec();
+    heading_block(&lines, heading)
+        .into_iter()
+        .skip(1)
+        .enumerate()
+        .filter(|(offset, line)| *offset > 0 || !is_feature_marker(line))
+        .map(|(_, line)| line)
+        .collect()
+}
+
+/// Tag references to other features of a class in a line, e.g. `your Rage feature` becomes
+/// `your {@classFeature Rage|Berserker|HB|1} feature`.
+///
+/// * `reference_re` - Pattern of the feature names followed by `feature`
+/// * `references` - Names of the features with their references
+fn link_features(line: &str, reference_re: &Regex, references: &[(String, String)]) -> String {
+    reference_re
+        .replace_all(line, |captures: &Captures| {
+            references
+                .iter()
+                .find(|(name, _)| *name == captures["name"])
+                .map_or_else(
+                    || captures[0].to_string(),
+                    |(_, reference)| format!("{{@classFeature {reference}}} feature"),
+                )
+        })
+        .to_string()
+}
+
+/// Find the feature that grants the subclass, e.g. `Primal Path` for the features gained from it,
+/// e.g. `Path feature`.
+fn parse_subclass_title(level_features: &LevelFeatures) -> Option<String> {
+    let subclass_features = level_features
+        .iter()
+        .flat_map(|(_, features)| features)
+        .filter_map(|feature| {
+            feature
+                .to_lowercase()
+                .strip_suffix(" feature")
+                .map(str::to_string)
+        })
+        .unique()
+        .collect_vec();
+    level_features
+        .iter()
+        .flat_map(|(_, features)| features)
+        .find(|feature| {
+            let feature = feature.to_lowercase();
+            subclass_features.iter().any(|subclass_feature| {
+                feature == *subclass_feature || feature.ends_with(&format!(" {subclass_feature}"))
+            })
+        })
+        .cloned()
+}
+
+/// Parse a single class.
+///
+/// * `class` - Lines of the class, starting with its heading
+/// * `source` - Source book of the class
+///
+/// Returns: The parsed class with its features.
+fn parse_class<'a>(class: &[String], source: Source<'a>) -> Result<Class<'a>> {
+    let [heading, lines @ ..] = class else {
+        return Err(OutOfBoundsError {
+            array: class.to_vec(),
+            index: 0,
+            parsing_step: "Class".to_string(),
+            problem: Some("Expected a heading".to_string()),
+        }
+        .into());
+    };
+    let name = heading_name(heading);
+    let (level_features, table) = parse_progression_table(lines)
+        .ok_or_else(|| ParseError::new_with_problem(&name, "Class", "Missing progression table"))?;
+
+    let hit_die_re = Regex::new(r"(?i)\d+d(?P<faces>\d+)").unwrap();
+    let mut hit_die = None;
+    let mut saving_throws = vec![];
+    let mut starting_proficiencies = StartingProficiencies::default();
+    for (label, value) in lines.iter().filter_map(|line| parse_class_property(line)) {
+        match &label[..] {
+            "hit dice" => {
+                hit_die = hit_die_re
+                    .captures(&value)
+                    .and_then(|captures| captures["faces"].parse().ok());
+            }
+            "saving throws" => saving_throws = parse_abilities(value.trim_end_matches('.'))?,
+            "armor" => starting_proficiencies.armor = parse_proficiency_list(&value),
+            "weapons" => starting_proficiencies.weapons = parse_proficiency_list(&value),
+            "tools" => starting_proficiencies.tools = parse_proficiency_list(&value),
+            _ => starting_proficiencies.skills = Some(parse_skill_proficiencies(&value)?),
+        }
+    }
+
+    let subclass_title = parse_subclass_title(&level_features);
+    let feature_headings = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| heading_level(line).is_some())
+        .map(|(index, line)| (heading_name(line).to_lowercase(), index))
+        .collect_vec();
+    let mut features = level_features
+        .into_iter()
+        .flat_map(|(level, features)| features.into_iter().map(move |feature| (level, feature)))
+        .map(|(level, feature)| ClassFeature {
+            gain_subclass_feature: subclass_title.as_ref() == Some(&feature)
+                || feature.to_lowercase().ends_with(" feature"),
+            name: feature,
+            level,
+            entries: vec![],
+        })
+        .collect_vec();
+    let class = Class {
+        name,
+        source,
+        hit_die,
+        saving_throws,
+        starting_proficiencies,
+        table: Some(table),
+        subclass_title,
+        features: vec![],
+    };
+    let references = features
+        .iter()
+        .unique_by(|feature| &feature.name)
+        .map(|feature| (feature.name.clone(), class.feature_reference(feature)))
+        .collect_vec();
+    let reference_re = Regex::new(&format!(
+        r"\b(?P<name>{}) feature\b",
+        references
+            .iter()
+            .map(|(name, _)| regex::escape(name))
+            .join("|")
+    ))
+    .unwrap();
+    for feature in &mut features {
+        let heading = feature_headings
+            .iter()
+            .find(|(name, _)| *name == feature.name.to_lowercase());
+        feature.entries = match heading {
+            Some((_, heading)) => parse_block_entries(
+                &feature_lines(lines, *heading)
+                    .iter()
+                    .map(|line| link_features(line, &reference_re, &references))
+                    .collect_vec(),
+            ),
+            None if feature.gain_subclass_feature => vec![Description::Entry(format!(
+                "You gain a feature from your {}.",
+                class.subclass_title.as_deref().unwrap_or("subclass")
+            ))],
+            None => vec![],
+        };
+    }
+    Ok(Class { features, ..class })
+}
+
+/// Short name of a subclass, e.g. `Glory` for `Oath of Glory` or `Life` for `Life Domain`.
+fn subclass_short_name(name: &str) -> String {
+    let lowercase = name.to_lowercase();
+    let start = SUBCLASS_PREFIXES
+        .iter()
+        .find(|prefix| lowercase.starts_with(*prefix))
+        .map_or(0, |prefix| prefix.len());
+    let end = SUBCLASS_SUFFIXES
+        .iter()
+        .find(|suffix| lowercase.ends_with(*suffix))
+        .map_or(name.len(), |suffix| name.len() - suffix.len());
+    name.get(start..end)
+        .filter(|short_name| !short_name.is_empty())
+        .unwrap_or(name)
+        .to_string()
+}
+
+/// Find the class of a subclass in a text, e.g. `Paladin Subclass` or `paladins`.
+pub(crate) fn find_class(text: &str) -> Option<Classes> {
+    text.split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+        .find_map(|word| {
+            Classes::try_from(word)
+                .or_else(|_| Classes::try_from(word.strip_suffix('s').unwrap_or(word)))
+                .ok()
+        })
+}
+
+/// Parse a single subclass.
+///
+/// Features without a level, e.g. `Tenets of Glory`, are gained with the first feature.
+fn parse_subclass<'a>(subclass: &SubclassBlock, source: Source<'a>) -> Result<Subclass<'a>> {
+    let [heading, lines @ ..] = &subclass.lines[..] else {
+        return Err(OutOfBoundsError {
+            array: subclass.lines.clone(),
+            index: 0,
+            parsing_step: "Subclass".to_string(),
+            problem: Some("Expected a heading".to_string()),
+        }
+        .into());
+    };
+    let name = heading_name(heading);
+    let intro = lines
+        .iter()
+        .take_while(|line| heading_level(line).is_none())
+        .join(" ");
+    let class = iter::once(&name)
+        .chain(&subclass.ancestors)
+        .chain([&intro])
+        .find_map(|text| find_class(text))
+        .ok_or_else(|| ParseError::new_with_problem(&name, "Subclass", "Missing class"))?;
+
+    let feature_level = lines
+        .iter()
+        .filter_map(|line| heading_level(line))
+        .min()
+        .unwrap_or_default();
+    let headings = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| heading_level(line) == Some(feature_level))
+        .map(|(index, line)| {
+            (
+                index,
+                parse_feature_heading(line, lines.get(index + 1).map(String::as_str)),
+            )
+        })
+        .collect_vec();
+    let first_level = headings
+        .iter()
+        .find_map(|(_, feature)| feature.as_ref().map(|(_, level)| *level))
+        .unwrap_or(1);
+    let features = headings
+        .into_iter()
+        .map(|(index, feature)| {
+            let (name, level) =
+                feature.unwrap_or_else(|| (heading_name(&lines[index]), first_level));
+            ClassFeature {
+                name,
+                level,
+                gain_subclass_feature: false,
+                entries: parse_block_entries(&feature_lines(lines, index)),
+            }
+        })
+        .collect();
+    Ok(Subclass {
+        short_name: subclass_short_name(&name),
+        name,
+        source,
+        class,
+        features,
+    })
+}
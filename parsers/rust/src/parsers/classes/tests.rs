@@ -0,0 +1,95 @@
+use std::fs;
+
+use serde_json::{json, Value};
+
+use crate::{
+    models::{
+        classes::{Class, Subclass},
+        common::{Classes, Source},
+    },
+    utils::{compare::json_compare, traits::To5etools},
+};
+
+use super::{
+    extract_classes, find_class, parse_classes, parse_feature_heading, parse_subclasses,
+    subclass_short_name,
+};
+
+const SOURCE: Source = Source {
+    source_book: "test-source",
+    page: 0,
+};
+
+#[test]
+fn extract_classes_test() {
+    let document = fs::read_to_string("resources/test/classes/unit_input.md").unwrap();
+    let classes = extract_classes(&document);
+    assert_eq!(classes.len(), 1);
+    assert_eq!(classes[0][0], "## Tidecaller");
+    assert_eq!(classes[0].len(), 30);
+}
+
+#[test]
+fn feature_headings() {
+    assert_eq!(
+        parse_feature_heading("#### Level 3: Peerless Athlete", None),
+        Some(("Peerless Athlete".to_string(), 3))
+    );
+    assert_eq!(
+        parse_feature_heading(
+            "#### Aura of Alacrity",
+            Some("*7th-level Oath of Glory feature*")
+        ),
+        Some(("Aura of Alacrity".to_string(), 7))
+    );
+    assert_eq!(
+        parse_feature_heading(
+            "#### Tenets of Glory",
+            Some("A paladin who takes this oath…")
+        ),
+        None
+    );
+}
+
+#[test]
+fn subclass_names() {
+    assert_eq!(subclass_short_name("Oath of the Ancients"), "Ancients");
+    assert_eq!(subclass_short_name("Life Domain"), "Life");
+    assert_eq!(subclass_short_name("Champion"), "Champion");
+    assert_eq!(find_class("Druid Circles"), Some(Classes::Druid));
+    assert_eq!(
+        find_class("Wizards study arcane magic."),
+        Some(Classes::Wizard)
+    );
+    assert_eq!(find_class("Subclasses"), None);
+}
+
+#[test]
+fn classes_and_subclasses() {
+    let document = fs::read_to_string("resources/test/classes/unit_input.md").unwrap();
+    let classes = parse_classes(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let subclasses = parse_subclasses(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let expected: Value = serde_json::from_str(
+        &fs::read_to_string("resources/test/classes/unit_output.json").unwrap(),
+    )
+    .unwrap();
+    json_compare(
+        json!({
+            "class": classes.to_5etools_base(),
+            "classFeature": classes.iter().flat_map(Class::to_5etools_features).collect::<Vec<_>>(),
+            "subclass": subclasses.to_5etools_base(),
+            "subclassFeature": subclasses
+                .iter()
+                .flat_map(Subclass::to_5etools_features)
+                .collect::<Vec<_>>(),
+        }),
+        expected,
+    )
+    .unwrap();
+}
@@ -1,48 +1,271 @@
-use std::collections::HashMap;
+use std::collections::{hash_map::Entry, HashMap};
 
 use itertools::Itertools;
+use regex::Regex;
 
 use crate::{
     models::{
         common::{
             AbilityScore, Alignment, AlignmentAxis, AlignmentAxisMoral, AlignmentAxisOrder,
-            DamageType, NamedEntry, Skill, StatusCondition, ALL_DAMAGE_TYPES,
+            DamageType, Description, Language, NamedEntry, Skill, Source, StatusCondition,
+            ALL_DAMAGE_TYPES,
         },
         creatures::{
-            AbilityScores, ArmorClass, ChallengeRating, ConditionalDamageModifier, CreatureType,
-            CreatureTypeEnum, DamageModifier, DamageModifierType, FlySpeed, HitPoints,
-            HitPointsFormula, Size, Speed,
+            AbilityScores, AnyLanguages, ArmorClass, Bonus, Challenge, ChallengeRating,
+            ConditionalDamageModifier, Creature, CreatureType, CreatureTypeEnum, CreatureTypeTag,
+            DailySpells, DamageModifier, DamageModifierType, GearItem, HitPoints, HitPointsFormula,
+            Languages, LegendaryGroup, LegendaryHeader, SavingThrows, Sense, SenseType, Size,
+            Skills, Speed, SpeedMode, SpeedValue, SpellSlotLevel, Spellcasting,
         },
     },
-    utils::error::{Error, OutOfBoundsError, ParseError, Result},
+    parsers::{objects::is_object, traps::is_trap_hazard, vehicles::is_vehicle},
+    utils::{
+        error::{Error, OutOfBoundsError, ParseError, Result},
+        markdown::{parse_count, parse_entries, split_top_level},
+    },
 };
 
 type Name = String;
-type SavingThrows = HashMap<AbilityScore, i8>;
-type Skills = HashMap<Skill, i8>;
+type Sizes = Vec<Size>;
 type DamageResistances = Vec<DamageModifier>;
 type DamageImmunities = Vec<DamageModifier>;
 type DamageVulnerabilities = Vec<DamageModifier>;
 type ConditionImmunities = Vec<StatusCondition>;
-type Senses = Vec<String>;
+type Senses = Vec<Sense>;
 type PassivePerception = u8;
-type Languages = Vec<String>;
 type Traits = Vec<NamedEntry>;
+type Spellcastings = Vec<Spellcasting>;
 type Actions = Vec<NamedEntry>;
 type BonusActions = Vec<NamedEntry>;
 type Reactions = Vec<NamedEntry>;
 type LegendaryActions = Vec<NamedEntry>;
 type MythicHeader = String;
 type MythicActions = Vec<NamedEntry>;
+type LairActions = Vec<Description>;
+type Initiative = i8;
+type ArmorClasses = Vec<ArmorClass>;
+type Gear = Vec<GearItem>;
+type RegionalEffects = Vec<Description>;
+type FourthGroup = (
+    Option<Skills>,
+    Option<DamageResistances>,
+    Option<DamageImmunities>,
+    Option<DamageVulnerabilities>,
+    Option<ConditionImmunities>,
+    Option<Gear>,
+    Senses,
+    PassivePerception,
+    Languages,
+    Challenge,
+);
+type MiddleGroups = (
+    ArmorClasses,
+    Option<Initiative>,
+    HitPoints,
+    Speed,
+    AbilityScores,
+    Option<SavingThrows>,
+    FourthGroup,
+);
 
 #[cfg(test)]
 mod tests;
 
+/// Parse all stat blocks in a document. Vehicle, object and trap stat blocks are left to their
+/// own parsers.
+///
+/// * `document` - The document containing the stat blocks
+/// * `source` - Source book of the creatures
+/// Returns: One result per creature stat block found in the document.
+#[must_use]
+pub fn parse_creatures<'a>(document: String, source: &Source<'a>) -> Vec<Result<Creature<'a>>> {
+    extract_stat_blocks(document)
+        .into_iter()
+        .filter(|stat_block| {
+            !(is_vehicle(stat_block) || is_object(stat_block) || is_trap_hazard(stat_block))
+        })
+        .map(|stat_block| parse_stat_block(stat_block, source.clone()))
+        .collect()
+}
+
+/// Parse a single stat block in either the 2014 or the 2024 format.
+///
+/// * `stat_block` - Lines of the stat block, with the leading `>` removed
+/// * `source` - Source book of the creature
+/// Returns: The parsed creature.
+pub fn parse_stat_block(stat_block: Vec<String>, source: Source) -> Result<Creature> {
+    let groups = stat_block
+        .iter()
+        .group_by(|line| line.as_str() == "___")
+        .into_iter()
+        .filter_map(|(is_divider, lines)| {
+            (!is_divider).then(|| lines.filter(|line| !line.is_empty()).cloned().collect_vec())
+        })
+        .collect_vec();
+    let get_group = |index: usize, parsing_step: &str| -> Result<Vec<String>> {
+        groups.get(index).cloned().ok_or_else(|| {
+            OutOfBoundsError {
+                array: stat_block.clone(),
+                index: u32::try_from(index).unwrap_or(u32::MAX),
+                parsing_step: parsing_step.to_string(),
+                problem: Some("Too few `___` separated groups".to_string()),
+            }
+            .into()
+        })
+    };
+
+    let (name, size, creature_type, alignment) = parse_first_group(get_group(0, "First group")?)?;
+    let second_group = get_group(1, "Second group")?;
+    let third_group = get_group(2, "Third group")?;
+    let fourth_group = get_group(3, "Fourth group")?;
+
+    // The 2024 format has an initiative line and MOD and SAVE columns in the ability table.
+    let is_2024_format = second_group
+        .iter()
+        .chain(third_group.iter())
+        .any(|line| line.contains("**Initiative**") || line.contains("MOD"));
+
+    let (armor_class, initiative, hit_points, speed, ability_scores, saving_throws, fourth_group) =
+        if is_2024_format {
+            parse_middle_groups_2024(second_group, third_group, fourth_group)?
+        } else {
+            parse_middle_groups(second_group, third_group, fourth_group)?
+        };
+    let (
+        skills,
+        damage_resistance,
+        damage_immunity,
+        damage_vulnerability,
+        condition_immunities,
+        gear,
+        senses,
+        passive_perception,
+        languages,
+        challenge_rating,
+    ) = fourth_group;
+
+    let (
+        abilities,
+        spellcasting,
+        actions,
+        bonus_actions,
+        reactions,
+        legendary_header,
+        legendary_actions,
+        mythic_header,
+        mythic_actions,
+        lair_actions,
+        regional_effects,
+    ) = parse_fifth_group(groups.iter().skip(4).flatten().cloned().collect())?;
+
+    let legendary_group =
+        (lair_actions.is_some() || regional_effects.is_some()).then(|| LegendaryGroup {
+            name: name.clone(),
+            source: source.clone(),
+            lair_actions,
+            regional_effects,
+        });
+
+    Ok(Creature {
+        name,
+        source,
+        size,
+        creature_type,
+        alignment,
+        armor_class,
+        initiative,
+        hit_points,
+        speed,
+        ability_scores,
+        saving_throws,
+        skills,
+        senses: non_empty(senses),
+        passive_perception,
+        damage_resistance,
+        damage_immunity,
+        damage_vulnerability,
+        condition_immunities,
+        gear,
+        languages,
+        challenge_rating,
+        abilities,
+        spellcasting,
+        actions,
+        bonus_actions,
+        reactions,
+        legendary_header,
+        legendary_actions,
+        mythic_actions,
+        mythic_header,
+        legendary_group,
+    })
+}
+
+/// Parse the second to fourth group of a 2014 stat block.
+fn parse_middle_groups(
+    second_group: Vec<String>,
+    third_group: Vec<String>,
+    fourth_group: Vec<String>,
+) -> Result<MiddleGroups> {
+    let (armor_class, hit_points, speed) = parse_second_group(second_group)?;
+    let (
+        saving_throws,
+        skills,
+        damage_resistance,
+        damage_immunity,
+        damage_vulnerability,
+        condition_immunities,
+        senses,
+        passive_perception,
+        languages,
+        challenge_rating,
+    ) = parse_fourth_group(fourth_group)?;
+    Ok((
+        armor_class,
+        None,
+        hit_points,
+        speed,
+        parse_third_group(third_group)?,
+        saving_throws,
+        (
+            skills,
+            damage_resistance,
+            damage_immunity,
+            damage_vulnerability,
+            condition_immunities,
+            None,
+            senses,
+            passive_perception,
+            languages,
+            challenge_rating,
+        ),
+    ))
+}
+
+/// Parse the second to fourth group of a 2024 stat block.
+fn parse_middle_groups_2024(
+    second_group: Vec<String>,
+    third_group: Vec<String>,
+    fourth_group: Vec<String>,
+) -> Result<MiddleGroups> {
+    let (armor_class, initiative, hit_points, speed) = parse_second_group_2024(second_group)?;
+    let (ability_scores, saving_throws) = parse_third_group_2024(third_group)?;
+    Ok((
+        armor_class,
+        initiative,
+        hit_points,
+        speed,
+        ability_scores,
+        saving_throws,
+        parse_fourth_group_2024(fourth_group)?,
+    ))
+}
+
 /// Extract stat blocks from a document containing multiple stat blocks.
 ///
 /// * `document` - The document to extract stat blocks from
 /// Returns: Vector of raw stat blocks.
-fn extract_stat_blocks(document: String) -> Vec<Vec<String>> {
+pub(crate) fn extract_stat_blocks(document: String) -> Vec<Vec<String>> {
     document
         .split('\n')
         // Stat blocks always start with `>`
@@ -81,7 +304,28 @@ fn clean_stat_block_line(line: &String) -> Result<(String, &str)> {
         })
 }
 
-fn parse_first_group(first_group: Vec<String>) -> Result<(Name, Size, CreatureType, Alignment)> {
+/// Split the line below the name into size and type, e.g. `medium humanoid (human,
+/// shapechanger)`, and alignment at the last `, ` outside of parentheses. Alignments with
+/// chances, e.g. `chaotic good (75%), neutral evil (25%)`, are kept together.
+fn split_alignment(line: &str) -> Option<(&str, &str)> {
+    let mut depth = 0_usize;
+    let mut separators = vec![];
+    for (index, character) in line.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 && line[index..].starts_with(", ") => separators.push(index),
+            _ => {}
+        }
+    }
+    separators
+        .into_iter()
+        .rev()
+        .find(|&index| !line[..index].ends_with("%)"))
+        .map(|index| (&line[..index], &line[index + 2..]))
+}
+
+fn parse_first_group(first_group: Vec<String>) -> Result<(Name, Sizes, CreatureType, Alignment)> {
     fn clean_name(name: &String) -> Result<Name> {
         name.strip_prefix("## ")
             .ok_or_else(|| {
@@ -108,34 +352,36 @@ fn parse_first_group(first_group: Vec<String>) -> Result<(Name, Size, CreatureTy
         }),
     }?;
 
-    let (size_type, alignment) = match second_line.splitn(2, ", ").collect_vec()[..] {
-        [size_type, alignment] => Ok((size_type, Alignment::try_from(alignment)?)),
-        _ => Err(ParseError {
+    let (size_type, alignment) = match split_alignment(&second_line) {
+        Some((size_type, alignment)) => Ok((size_type, Alignment::try_from(alignment)?)),
+        None => Err(ParseError {
             string: second_line.clone(),
             parsing_step: "Separating size and type from alignment".to_string(),
             problem: Some("No `, ` separation found.".to_string()),
         }),
     }?;
 
-    let (size, creature_type) = match size_type.splitn(2, ' ').collect_vec()[..] {
-        [size, creature_type] => Ok((
-            Size::try_from(size)?,
-            CreatureType::try_from(creature_type)?,
-        )),
-        _ => Err(ParseError {
-            string: size_type.to_string(),
-            parsing_step: "Separating size and creature type".to_string(),
-            problem: Some("Could not separate by ` `".to_string()),
-        }),
-    }?;
+    // Creatures can have several sizes, e.g. `medium or small humanoid`.
+    let size_re = Regex::new(r"^(?P<sizes>[a-z]+(?:(?:, | or )[a-z]+)*) (?P<type>.+)$").unwrap();
+    let captures = size_re.captures(size_type).ok_or_else(|| ParseError {
+        string: size_type.to_string(),
+        parsing_step: "Separating size and creature type".to_string(),
+        problem: Some("Could not separate by ` `".to_string()),
+    })?;
+    let size = captures["sizes"]
+        .split(", ")
+        .flat_map(|sizes| sizes.split(" or "))
+        .map(Size::try_from)
+        .collect::<Result<_>>()?;
+    let creature_type = CreatureType::try_from(&captures["type"])?;
 
     Ok((name, size, creature_type, alignment))
 }
 
-fn parse_second_group(second_group: Vec<String>) -> Result<(ArmorClass, HitPoints, Speed)> {
+fn parse_second_group(second_group: Vec<String>) -> Result<(ArmorClasses, HitPoints, Speed)> {
     match &second_group[..] {
         [ac_line, hp_line, speed_line] => Ok((
-            clean_stat_block_line(ac_line)?.1.try_into()?,
+            parse_armor_class(clean_stat_block_line(ac_line)?.1)?,
             clean_stat_block_line(hp_line)?.1.try_into()?,
             clean_stat_block_line(speed_line)?.1.try_into()?,
         )),
@@ -149,7 +395,127 @@ fn parse_second_group(second_group: Vec<String>) -> Result<(ArmorClass, HitPoint
     }
 }
 
-fn parse_third_group(third_group: Vec<String>) -> Result<AbilityScores> {
+/// Parse the armor class, initiative, hit points and speed of a 2024 stat block.
+///
+/// Several values may share a line, e.g. `- **AC** 15 &nbsp; **Initiative** +2 (12)`.
+fn parse_second_group_2024(
+    second_group: Vec<String>,
+) -> Result<(ArmorClasses, Option<Initiative>, HitPoints, Speed)> {
+    let value_re = Regex::new(r"\*\*(?P<key>[^*]+)\*\*(?P<value>[^*]*)").unwrap();
+    let values: HashMap<String, String> = second_group
+        .iter()
+        .flat_map(|line| {
+            value_re
+                .captures_iter(&line.replace("&nbsp;", " "))
+                .map(|captures| {
+                    (
+                        captures["key"].trim().to_lowercase(),
+                        captures["value"].trim().to_string(),
+                    )
+                })
+                .collect_vec()
+        })
+        .collect();
+    let get_value = |keys: &[&str]| -> Result<&String> {
+        keys.iter().find_map(|key| values.get(*key)).ok_or_else(|| {
+            OutOfBoundsError {
+                array: second_group.clone(),
+                index: 0,
+                parsing_step: "Second group (2024)".to_string(),
+                problem: Some(format!("No `{}` line found", keys[0])),
+            }
+            .into()
+        })
+    };
+
+    let initiative = values
+        .get("initiative")
+        .map(|initiative| {
+            let modifier = initiative
+                .split(' ')
+                .next()
+                .unwrap_or_default()
+                .replace('−', "-");
+            modifier.parse().map_err(ParseError::from_intparse_error(
+                initiative.to_string(),
+                "Initiative".to_string(),
+            ))
+        })
+        .transpose()?;
+
+    Ok((
+        parse_armor_class(get_value(&["ac", "armor class"])?)?,
+        initiative,
+        get_value(&["hp", "hit points"])?.as_str().try_into()?,
+        get_value(&["speed"])?.as_str().try_into()?,
+    ))
+}
+
+/// Parse the 2024 ability table, where each ability has a score, modifier and save column.
+///
+/// Returns: Ability scores and the saving throws that differ from the modifier.
+fn parse_third_group_2024(
+    third_group: Vec<String>,
+) -> Result<(AbilityScores, Option<SavingThrows>)> {
+    const ABILITIES: [&str; 6] = ["str", "dex", "con", "int", "wis", "cha"];
+    let parse_modifier = |modifier: &str| -> Result<i8> {
+        modifier
+            .replace('−', "-")
+            .parse()
+            .map_err(ParseError::from_intparse_error(
+                modifier.to_string(),
+                "Ability scores (2024): modifier".to_string(),
+            ))
+            .map_err(Into::into)
+    };
+
+    let mut scores: HashMap<&str, u8> = HashMap::new();
+    let mut saving_throws = SavingThrows::default();
+    for line in &third_group {
+        let cells = line
+            .trim_matches('|')
+            .split('|')
+            .map(|cell| cell.replace('*', "").trim().to_lowercase())
+            .collect_vec();
+        for (index, cell) in cells.iter().enumerate() {
+            let Some(ability) = ABILITIES.iter().find(|ability| cell.starts_with(**ability)) else {
+                continue;
+            };
+            let [score, modifier, save, ..] = &cells[index + 1..] else {
+                return Err(OutOfBoundsError {
+                    array: cells.clone(),
+                    index: u32::try_from(index + 3).unwrap_or(u32::MAX),
+                    parsing_step: "Ability scores (2024)".to_string(),
+                    problem: Some("Expected score, modifier and save after ability".to_string()),
+                }
+                .into());
+            };
+            let score = score.parse().map_err(ParseError::from_intparse_error(
+                score.to_string(),
+                "Ability scores (2024): score".to_string(),
+            ))?;
+            scores.insert(ability, score);
+            // Only proficient saving throws are listed in 5etools.
+            let save = parse_modifier(save)?;
+            if save != parse_modifier(modifier)? {
+                saving_throws
+                    .saves
+                    .push(((*ability).try_into()?, save.into()));
+            }
+        }
+    }
+
+    Ok((
+        scores.try_into()?,
+        if saving_throws.saves.is_empty() {
+            None
+        } else {
+            Some(saving_throws)
+        },
+    ))
+}
+
+pub(crate) fn parse_third_group(third_group: Vec<String>) -> Result<AbilityScores> {
     fn strip_prefix_suffix(line: &String) -> Result<&str> {
         line.strip_prefix('|')
             .ok_or_else(|| ParseError {
@@ -224,7 +590,7 @@ fn parse_fourth_group(
     Senses,
     PassivePerception,
     Languages,
-    ChallengeRating,
+    Challenge,
 )> {
     use DamageModifierType::{Immunity, Resistance, Vulnerability};
     fn parse_line<T>(
@@ -286,37 +652,132 @@ fn parse_fourth_group(
     ))
 }
 
+/// Parse the fourth group of a 2024 stat block.
+///
+/// Compared to the 2014 format there are no saving throws (they are in the ability table),
+/// damage and condition immunities share the `Immunities` line, and there is a `Gear` line.
+fn parse_fourth_group_2024(fourth_group: Vec<String>) -> Result<FourthGroup> {
+    use DamageModifierType::{Resistance, Vulnerability};
+    let lines: HashMap<String, &str> = fourth_group
+        .iter()
+        .map(clean_stat_block_line)
+        .try_collect()?;
+    let get_line = |keys: &[&str]| keys.iter().find_map(|key| lines.get(*key).copied());
+    let missing_line = |line_type: &str| OutOfBoundsError {
+        array: fourth_group.clone(),
+        index: 0,
+        parsing_step: "Fourth group (2024)".to_string(),
+        problem: Some(format!("{line_type} line not found")),
+    };
+
+    let (passive_perception, senses) = parse_senses(
+        &get_line(&["senses"])
+            .ok_or_else(|| missing_line("Senses"))?
+            .replace("; ", ", "),
+    )?;
+    let (damage_immunity, condition_immunities) = get_line(&["immunities"])
+        .map(parse_immunities)
+        .transpose()?
+        .unzip();
+
+    Ok((
+        get_line(&["skills"]).map(parse_skills).transpose()?,
+        get_line(&["resistances", "damage resistances"])
+            .map(|line| parse_damage_modifier(Resistance, line))
+            .transpose()?,
+        damage_immunity.flatten(),
+        get_line(&["vulnerabilities", "damage vulnerabilities"])
+            .map(|line| parse_damage_modifier(Vulnerability, line))
+            .transpose()?,
+        condition_immunities.flatten(),
+        get_line(&["gear"]).map(parse_gear).transpose()?,
+        senses,
+        passive_perception,
+        parse_languages(get_line(&["languages"]).ok_or_else(|| missing_line("Languages"))?)?,
+        parse_challenge_rating(
+            get_line(&["cr", "challenge"]).ok_or_else(|| missing_line("Challenge rating"))?,
+        )?,
+    ))
+}
+
+/// Parse the 2024 `Immunities` line, e.g. `Fire, Poison; Charmed, Poisoned`.
+fn parse_immunities(
+    immunities_line: &str,
+) -> Result<(Option<DamageImmunities>, Option<ConditionImmunities>)> {
+    let mut damage_immunities = vec![];
+    let mut condition_immunities = vec![];
+    for part in immunities_line.split(';').map(str::trim) {
+        // A part is either damage types or conditions, try damage types first.
+        match parse_damage_modifier(DamageModifierType::Immunity, part) {
+            Ok(damage) => damage_immunities.extend(damage),
+            Err(_) => condition_immunities.extend(parse_condition_immunities(part)?),
+        }
+    }
+    Ok((
+        non_empty(damage_immunities),
+        non_empty(condition_immunities),
+    ))
+}
+
+/// Parse the 2024 `Gear` line, e.g. `Leather Armor, Arrows (20)`.
+fn parse_gear(gear_line: &str) -> Result<Gear> {
+    let item_re = Regex::new(r"^(?P<name>.+?)(?: \((?P<quantity>\d+)\))?$").unwrap();
+    gear_line
+        .split(", ")
+        .map(str::trim)
+        .map(|item| {
+            let captures = item_re
+                .captures(item)
+                .ok_or_else(|| ParseError::new_with_problem(item, "Gear", "Empty item"))?;
+            Ok(GearItem {
+                name: captures["name"].to_string(),
+                quantity: captures
+                    .name("quantity")
+                    .map(|quantity| {
+                        quantity
+                            .as_str()
+                            .parse()
+                            .map_err(ParseError::from_intparse_error(
+                                item.to_string(),
+                                "Gear: quantity".to_string(),
+                            ))
+                    })
+                    .transpose()?,
+            })
+        })
+        .collect()
+}
+
 fn parse_fifth_group(
     fifth_group: Vec<String>,
 ) -> Result<(
     Option<Traits>,
+    Option<Spellcastings>,
     Option<Actions>,
     Option<BonusActions>,
     Option<Reactions>,
+    Option<LegendaryHeader>,
     Option<LegendaryActions>,
     Option<MythicHeader>,
     Option<MythicActions>,
+    Option<LairActions>,
+    Option<RegionalEffects>,
 )> {
-    let sub_groups_str = fifth_group
-        .iter()
-        .filter(|line| !line.is_empty())
-        .join("\n");
+    // Leading newline so that a heading on the first line is also split off.
+    let sub_groups_str = format!(
+        "\n{}",
+        fifth_group
+            .iter()
+            .filter(|line| !line.is_empty())
+            .join("\n")
+    );
 
     let mut sub_groups = sub_groups_str.split("\n### ");
 
-    let Some(traits_str) = sub_groups.next() else { 
+    let Some(traits_str) = sub_groups.next() else {
         unreachable!("Split always returns at least one element")
     };
 
-    let traits = match &traits_str
-        .split("\n***")
-        .map(parse_named_entry)
-        .collect::<Result<Traits>>()?[..]
-    {
-        [] => None,
-        traits_arr => Some(traits_arr.to_vec()),
-    };
-
     let group_map: HashMap<String, &str> = sub_groups
         .map(|group| {
             group
@@ -332,6 +793,25 @@ fn parse_fifth_group(
         })
         .try_collect()?;
 
+    // 2024 stat blocks put traits under their own `### Traits` heading.
+    let traits_str = group_map.get("traits").copied().unwrap_or(traits_str);
+
+    let (spellcasting, traits): (Vec<NamedEntry>, Traits) = traits_str
+        .split("\n***")
+        .filter(|entry| !entry.trim().is_empty())
+        .map(parse_named_entry)
+        .collect::<Result<Traits>>()?
+        .into_iter()
+        .partition(is_spellcasting_trait);
+
+    let traits = non_empty(traits);
+    let spellcasting = non_empty(
+        spellcasting
+            .iter()
+            .map(parse_spellcasting)
+            .collect::<Result<Spellcastings>>()?,
+    );
+
     let get_entry_type = |entry_type: &str, lines_to_skip: u8| -> Result<Option<Vec<NamedEntry>>> {
         group_map
             .get(entry_type)
@@ -348,7 +828,12 @@ fn parse_fifth_group(
     let actions = get_entry_type("actions", 0)?;
     let bonus_actions = get_entry_type("bonus actions", 0)?;
     let reactions = get_entry_type("reactions", 0)?;
-    let legendary_actions = get_entry_type("legendary actions", 1)?;
+    let (legendary_header, legendary_actions) = group_map
+        .get("legendary actions")
+        .map(|entries_str| parse_legendary_actions(entries_str))
+        .transpose()?
+        .unzip();
+    let legendary_actions = legendary_actions.and_then(non_empty);
     let mythic_actions = get_entry_type("mythic actions", 1)?;
     let mythic_header = group_map.get("mythic actions").map(|entries_str| {
         entries_str
@@ -356,63 +841,168 @@ fn parse_fifth_group(
             .take_while(|char_| *char_ != '\n')
             .collect()
     });
+    let lair_actions = group_map
+        .get("lair actions")
+        .map(|section| parse_entries(section))
+        .and_then(non_empty);
+    let regional_effects = group_map
+        .get("regional effects")
+        .map(|section| parse_entries(section))
+        .and_then(non_empty);
 
     Ok((
         traits,
+        spellcasting,
         actions,
         bonus_actions,
         reactions,
+        legendary_header,
         legendary_actions,
         mythic_header,
         mythic_actions,
+        lair_actions,
+        regional_effects,
     ))
 }
 
-fn parse_saving_throws(saving_throws_line: &str) -> Result<SavingThrows> {
-    saving_throws_line
-        .to_lowercase()
-        .split(", ")
-        .map(|save| {
-            if let Some((ability, save_mod)) = save.split_once(' ') {
-                Ok((
-                    ability.try_into()?,
-                    save_mod
-                        .parse::<i8>()
-                        .map_err(ParseError::from_intparse_error(
-                            save_mod.to_string(),
-                            "Saving_throws".to_string(),
-                        ))?,
-                ))
-            } else {
-                Err(ParseError::new(save, "Saving throws").into())
-            }
+/// Parse the introductory text of a legendary actions section.
+///
+/// * `header` - E.g. `The dragon can take 3 legendary actions, ...` or the 2024 format
+///   `Legendary Action Uses: 3 (4 in Lair). ...`
+/// Returns: Number of legendary actions and the header if it is not the standard wording.
+fn parse_legendary_header(header: &str) -> Result<LegendaryHeader> {
+    let count_re = Regex::new(
+        r"(?i)(?:can take (?P<count>\d+) legendary actions?|legendary action uses:\s*(?P<uses>\d+)(?:\s*\((?P<lair>\d+) in lair\))?)",
+    )
+    .unwrap();
+    let standard_re = Regex::new(
+        r"^.+ can take \d+ legendary actions?, choosing from the options below\. Only one legendary action(?: option)? can be used at a time and only at the end of another creature's turn\. .+ regains spent legendary actions at the start of its turn\.$",
+    )
+    .unwrap();
+    let standard_2024_re = Regex::new(
+        r"^Legendary Action Uses: \d+(?: \(\d+ in Lair\))?\. Immediately after another creature's turn, .+ can expend a use to take one of the following actions\. .+ regains all expended uses at the start of each of its turns\.$",
+    )
+    .unwrap();
+
+    let parse_count = |count: &str| -> Result<u8> {
+        count.parse().map_err(|_| {
+            ParseError::new_with_problem(count, "Legendary header", "Could not parse as u8").into()
         })
-        .collect()
+    };
+
+    let header = header.lines().map(str::trim).join(" ");
+    let captures = count_re.captures(&header);
+    let actions_per_round = captures
+        .as_ref()
+        .and_then(|captures| captures.name("count").or_else(|| captures.name("uses")))
+        // 5etools assumes 3 legendary actions if nothing else is stated.
+        .map_or(Ok(3), |count| parse_count(count.as_str()))?;
+    let actions_in_lair = captures
+        .as_ref()
+        .and_then(|captures| captures.name("lair"))
+        .map(|lair| parse_count(lair.as_str()))
+        .transpose()?;
+    let is_standard = standard_re.is_match(&header) || standard_2024_re.is_match(&header);
+
+    Ok(LegendaryHeader {
+        actions_per_round,
+        actions_in_lair,
+        header: if is_standard || header.is_empty() {
+            None
+        } else {
+            Some(header)
+        },
+    })
+}
+
+/// Parse a legendary actions section into its header and the legendary actions.
+///
+/// * `section` - The section without the `### Legendary Actions` line.
+fn parse_legendary_actions(section: &str) -> Result<(LegendaryHeader, LegendaryActions)> {
+    let mut segments = section.split("\n***");
+    // Without introductory text the section starts directly with the first action.
+    let header = if section.starts_with("***") {
+        ""
+    } else {
+        segments.next().unwrap_or_default()
+    };
+    Ok((
+        parse_legendary_header(header)?,
+        segments.map(parse_named_entry).try_collect()?,
+    ))
+}
+
+/// Parse a bonus entry with an optional note, e.g. `Perception +5 (+10 with sight)`.
+///
+/// Returns: The lowercase name and the bonus.
+fn parse_bonus(entry: &str, parsing_step: &str) -> Result<(String, Bonus)> {
+    let bonus_re =
+        Regex::new(r"^(?P<name>[A-Za-z][A-Za-z ]*?) (?P<bonus>[+\-−]?\d+)(?: (?P<note>.+))?$")
+            .unwrap();
+    let captures = bonus_re
+        .captures(entry.trim())
+        .ok_or_else(|| ParseError::new(entry, parsing_step))?;
+    let bonus = captures["bonus"].replace('−', "-");
+    Ok((
+        captures["name"].to_lowercase(),
+        Bonus {
+            bonus: bonus.parse().map_err(ParseError::from_intparse_error(
+                bonus.clone(),
+                parsing_step.to_string(),
+            ))?,
+            note: captures.name("note").map(|note| note.as_str().to_string()),
+        },
+    ))
 }
 
+/// Parse a saving throws line, e.g. `Con +6, Wis -1`. Entries that are not an ability with a
+/// bonus are kept as special text.
+fn parse_saving_throws(saving_throws_line: &str) -> Result<SavingThrows> {
+    let mut saving_throws = SavingThrows::default();
+    let mut special = vec![];
+    for entry in split_top_level(saving_throws_line) {
+        match parse_bonus(entry, "Saving throws")
+            .and_then(|(ability, bonus)| Ok((AbilityScore::try_from(&ability[..])?, bonus)))
+        {
+            Ok(save) => saving_throws.saves.push(save),
+            Err(_) => special.push(entry),
+        }
+    }
+    saving_throws.special = (!special.is_empty()).then(|| special.join(", "));
+    Ok(saving_throws)
+}
+
+/// Parse a skills line, e.g. `Perception +5 (+10 with sight), Stealth +4, plus one of the
+/// following: Arcana +5 or History +5`.
 fn parse_skills(skills_line: &str) -> Result<Skills> {
-    skills_line
-        .to_lowercase()
-        .split(", ")
-        .map(|skill| {
-            if let Some((skill, skill_mod)) = skill.split_once(' ') {
-                Ok((
-                    skill.try_into()?,
-                    skill_mod
-                        .parse::<i8>()
-                        .map_err(ParseError::from_intparse_error(
-                            skill_mod.to_string(),
-                            "Skills".to_string(),
-                        ))?,
-                ))
-            } else {
-                Err(ParseError::new(skill, "Skills").into())
-            }
-        })
-        .collect()
+    let parse_skill_list = |skills: &str| -> Result<Vec<(Skill, Bonus)>> {
+        split_top_level(skills)
+            .into_iter()
+            .flat_map(|skills| skills.split(" or "))
+            .filter(|skill| !skill.trim().is_empty())
+            .map(|skill| {
+                let (name, bonus) = parse_bonus(skill, "Skills")?;
+                Ok((Skill::try_from(&name[..])?, bonus))
+            })
+            .collect()
+    };
+
+    let one_of_re = Regex::new(r"(?i),?\s*plus one of the following:").unwrap();
+    let (skills, one_of) = one_of_re
+        .find(skills_line)
+        .map_or((skills_line, None), |one_of| {
+            (
+                &skills_line[..one_of.start()],
+                Some(&skills_line[one_of.end()..]),
+            )
+        });
+    Ok(Skills {
+        skills: parse_skill_list(skills)?,
+        one_of: one_of.map(parse_skill_list).transpose()?,
+    })
 }
 
-fn parse_damage_modifier(
+pub(crate) fn parse_damage_modifier(
     modifier_type: DamageModifierType,
     damage_modifier_line: &str,
 ) -> Result<Vec<DamageModifier>> {
@@ -512,7 +1102,9 @@ fn parse_damage_modifier(
     }
 }
 
-fn parse_condition_immunities(condition_immunities_line: &str) -> Result<ConditionImmunities> {
+pub(crate) fn parse_condition_immunities(
+    condition_immunities_line: &str,
+) -> Result<ConditionImmunities> {
     condition_immunities_line
         .to_lowercase()
         .split(", ")
@@ -531,21 +1123,21 @@ fn parse_senses(senses_line: &str) -> Result<(PassivePerception, Senses)> {
 
     let mut passive_perception: Option<u8> = None;
     let mut senses = vec![];
-    senses_line
+    for sense in senses_line
         // Passive perception is usually last, so for performance reasons we start at the back.
         .rsplit(", ")
-        .for_each(|sense| {
+    {
+        if passive_perception.is_none() {
+            // Try to parse passive perception
+            passive_perception = parse_passive_perception(sense);
             if passive_perception.is_some() {
-                senses.push(sense.to_string())
-            } else {
-                // Try to parse passive perception
-                passive_perception = parse_passive_perception(sense);
-                if passive_perception.is_none() {
-                    // passive perception could not be parsed, so the sense is added to the list.
-                    senses.push(sense.to_string())
-                }
+                continue;
             }
-        });
+        }
+        // passive perception could not be parsed, so the sense is added to the list.
+        senses.push(Sense::try_from(sense)?);
+    }
+    senses.reverse();
 
     passive_perception
         .ok_or_else(|| {
@@ -555,31 +1147,277 @@ fn parse_senses(senses_line: &str) -> Result<(PassivePerception, Senses)> {
         .map(|passive| (passive, senses))
 }
 
+/// Parse a languages line, e.g. `Common, Draconic, telepathy 60 ft.`,
+/// `understands Abyssal but can't speak`, `any two languages` or `—`.
 fn parse_languages(languages_line: &str) -> Result<Languages> {
-    Ok(languages_line
-        .split(", ")
-        .map(ToString::to_string)
-        .collect())
+    let telepathy_re = Regex::new(r"(?i)^telepathy (?P<range>\d+) ft\.?$").unwrap();
+    let understands_re =
+        Regex::new(r"(?i)^understands (?P<languages>.+?),? but can't speak(?: (?:it|them))?$")
+            .unwrap();
+    let any_re =
+        Regex::new(r"(?i)^any (?P<count>\w+)(?: other)? languages?(?: (?P<note>.+))?$").unwrap();
+
+    let mut languages = Languages::default();
+    for part in split_top_level(&languages_line.replace("; ", ", ")) {
+        let part = part.replace('’', "'");
+        if matches!(&part[..], "" | "—" | "-" | "–" | "none") {
+            continue;
+        }
+        if let Some(captures) = telepathy_re.captures(&part) {
+            languages.telepathy = Some(captures["range"].parse().map_err(
+                ParseError::from_intparse_error(part.clone(), "Languages: telepathy".to_string()),
+            )?);
+        } else if let Some(captures) = understands_re.captures(&part) {
+            languages.understood.extend(
+                captures["languages"]
+                    .split(", ")
+                    .flat_map(|language| language.split(" and "))
+                    .map(Language::from),
+            );
+        } else if let Some(captures) = any_re.captures(&part) {
+            languages.any = Some(AnyLanguages {
+                count: parse_count(&captures["count"], "Languages: any")?,
+                note: captures.name("note").map(|note| note.as_str().to_string()),
+            });
+        } else {
+            languages.spoken.push(Language::from(&part[..]));
+        }
+    }
+    Ok(languages)
 }
 
-fn parse_challenge_rating(challenge_rating_line: &str) -> Result<ChallengeRating> {
-    challenge_rating_line
-        .split_once(' ')
-        .unzip()
-        .0
-        .ok_or_else(|| {
-            {
+/// Parse the challenge rating line in the 2014 or 2024 format, e.g.
+/// `10 (5,900 XP, or 7,200 XP in lair)`, `3 (700 XP), or 5 (1,800 XP) when part of a coven` or
+/// `1/4 (XP 50; PB +2)`.
+fn parse_challenge_rating(challenge_rating_line: &str) -> Result<Challenge> {
+    let experience_points_re = Regex::new(r"(?:(?P<xp>[\d,]+) XP|XP (?P<xp_2024>[\d,]+))").unwrap();
+    let lair_re =
+        Regex::new(r"or (?P<xp>[\d,]+)(?: XP)? (?:when encountered )?in (?:its )?lair").unwrap();
+    let coven_re = Regex::new(r"or (?P<cr>[\d/]+) \([^)]*\) when part of a coven").unwrap();
+    let parse_xp = |xp: &str| {
+        xp.replace(',', "")
+            .parse::<u32>()
+            .map_err(ParseError::from_intparse_error(
+                challenge_rating_line.to_string(),
+                "Challenge Rating experience points".to_string(),
+            ))
+    };
+
+    let rating = challenge_rating_line
+        .split_whitespace()
+        .next()
+        .ok_or_else(|| ParseError::new(challenge_rating_line, "Challenge Rating"))?
+        .try_into()?;
+    let experience_points = experience_points_re
+        .captures(challenge_rating_line)
+        .and_then(|captures| captures.name("xp").or_else(|| captures.name("xp_2024")))
+        .map(|xp| parse_xp(xp.as_str()))
+        .transpose()?;
+    let lair = lair_re
+        .captures(challenge_rating_line)
+        .map(|captures| {
+            let xp = parse_xp(&captures["xp"])?;
+            ChallengeRating::from_experience_points(xp).ok_or_else(|| {
                 ParseError::new_with_problem(
                     challenge_rating_line,
-                    "Challenge Rating",
-                    "No separating ` ` found",
+                    "Challenge Rating in lair",
+                    "Experience points do not match any challenge rating",
                 )
+            })
+        })
+        .transpose()?;
+    let coven = coven_re
+        .captures(challenge_rating_line)
+        .map(|captures| ChallengeRating::try_from(&captures["cr"]))
+        .transpose()?;
+
+    Ok(Challenge {
+        rating,
+        experience_points,
+        lair,
+        coven,
+    })
+}
+
+pub(crate) fn non_empty<T>(vec: Vec<T>) -> Option<Vec<T>> {
+    if vec.is_empty() {
+        None
+    } else {
+        Some(vec)
+    }
+}
+
+fn is_spellcasting_trait(entry: &NamedEntry) -> bool {
+    let name = entry.name.trim().trim_end_matches('.').to_lowercase();
+    name.starts_with("spellcasting") || name.starts_with("innate spellcasting")
+}
+
+/// A single `<frequency>: <spells>` line of a spellcasting trait.
+enum SpellList {
+    AtWill(Vec<String>),
+    Daily(DailySpells),
+    Slots(SpellSlotLevel),
+}
+
+fn parse_spell_list(line: &str) -> Result<Option<SpellList>> {
+    let cantrip_re = Regex::new(r"^cantrips?\b").unwrap();
+    let slot_level_re = Regex::new(
+        r"^(?:(?P<lower>\d+)(?:st|nd|rd|th)?[-–])?(?P<level>\d+)(?:st|nd|rd|th)[- ]level(?: \((?P<slots>\d+)(?: \d+(?:st|nd|rd|th)[- ]level)? slots?\))?",
+    )
+    .unwrap();
+    let daily_re = Regex::new(r"^(?P<uses>\d+)/day(?P<each> each)?").unwrap();
+
+    let parse_number = |number: &str, parsing_step: &str| -> Result<u8> {
+        number.parse().map_err(|_| {
+            ParseError::new_with_problem(number, parsing_step, "Could not parse as u8").into()
+        })
+    };
+
+    let Some((frequency, spells)) = line.split_once(':') else {
+        return Ok(None);
+    };
+    // Remove list markers and emphasis, e.g. `- **1st level (4 slots)**`.
+    let frequency = frequency
+        .trim_start_matches(['-', '•', '*', ' '])
+        .replace('*', "")
+        .trim()
+        .to_lowercase();
+    // Spells are emphasized one by one, e.g. `*fire bolt*, *light*`, or as a whole list.
+    let spells = spells
+        .split(", ")
+        .map(|spell| {
+            spell
+                .replace(['*', '_'], "")
+                .trim()
+                .trim_end_matches('.')
+                .to_string()
+        })
+        .filter(|spell| !spell.is_empty())
+        .collect_vec();
+
+    if frequency.starts_with("at will") {
+        Ok(Some(SpellList::AtWill(spells)))
+    } else if cantrip_re.is_match(&frequency) {
+        Ok(Some(SpellList::Slots(SpellSlotLevel {
+            level: 0,
+            lower: None,
+            slots: None,
+            spells,
+        })))
+    } else if let Some(captures) = slot_level_re.captures(&frequency) {
+        Ok(Some(SpellList::Slots(SpellSlotLevel {
+            level: parse_number(&captures["level"], "Spellcasting: spell level")?,
+            lower: captures
+                .name("lower")
+                .map(|lower| parse_number(lower.as_str(), "Spellcasting: lowest level"))
+                .transpose()?,
+            slots: captures
+                .name("slots")
+                .map(|slots| parse_number(slots.as_str(), "Spellcasting: spell slots"))
+                .transpose()?,
+            spells,
+        })))
+    } else if let Some(captures) = daily_re.captures(&frequency) {
+        Ok(Some(SpellList::Daily(DailySpells {
+            uses: parse_number(&captures["uses"], "Spellcasting: uses per day")?,
+            each: captures.name("each").is_some(),
+            spells,
+        })))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Parse a `Spellcasting` or `Innate Spellcasting` trait.
+///
+/// * `entry` - The trait. The first lines are the header, followed by one
+///   `<frequency>: <spells>` line (or sub-entry) per spell list.
+/// Returns: The structured spellcasting trait.
+fn parse_spellcasting(entry: &NamedEntry) -> Result<Spellcasting> {
+    let ability_re =
+        Regex::new(r"(?i)\b(strength|dexterity|constitution|intelligence|wisdom|charisma)\b")
+            .unwrap();
+    let dc_re = Regex::new(r"(?i)DC (?P<dc>\d+)").unwrap();
+    let to_hit_re = Regex::new(r"(?P<to_hit>[+-]\d+) to hit").unwrap();
+
+    let lines = entry
+        .entry
+        .lines()
+        .map(ToString::to_string)
+        .chain(
+            entry
+                .sub_entries
+                .iter()
+                .flatten()
+                .map(|sub_entry| format!("{} {}", sub_entry.name, sub_entry.entry)),
+        )
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty());
+
+    let mut header_entries = vec![];
+    let mut footer_entries = vec![];
+    let mut at_will = vec![];
+    let mut daily = vec![];
+    let mut spell_slots = vec![];
+    for line in lines {
+        match parse_spell_list(&line)? {
+            Some(SpellList::AtWill(spells)) => at_will.extend(spells),
+            Some(SpellList::Daily(daily_spells)) => daily.push(daily_spells),
+            Some(SpellList::Slots(slot_level)) => spell_slots.push(slot_level),
+            // Lines that are not spell lists are part of the header until the first spell list.
+            None if at_will.is_empty() && daily.is_empty() && spell_slots.is_empty() => {
+                header_entries.push(line);
             }
-        })?
-        .try_into()
+            None => footer_entries.push(line),
+        }
+    }
+
+    let header = header_entries.join(" ");
+    if header.is_empty() {
+        return Err(ParseError::new_with_problem(
+            &entry.entry,
+            "Spellcasting",
+            "No spellcasting header found",
+        )
+        .into());
+    }
+
+    Ok(Spellcasting {
+        name: entry.name.trim().trim_end_matches('.').to_string(),
+        ability: ability_re
+            .find(&header)
+            .map(|ability| AbilityScore::try_from(&ability.as_str().to_lowercase()[..3]))
+            .transpose()?,
+        save_dc: dc_re
+            .captures(&header)
+            .map(|captures| {
+                captures["dc"]
+                    .parse()
+                    .map_err(ParseError::from_intparse_error(
+                        captures["dc"].to_string(),
+                        "Spellcasting: save DC".to_string(),
+                    ))
+            })
+            .transpose()?,
+        attack_bonus: to_hit_re
+            .captures(&header)
+            .map(|captures| {
+                let to_hit = captures["to_hit"].trim_start_matches('+');
+                to_hit.parse().map_err(ParseError::from_intparse_error(
+                    to_hit.to_string(),
+                    "Spellcasting: attack bonus".to_string(),
+                ))
+            })
+            .transpose()?,
+        header_entries,
+        at_will: non_empty(at_will),
+        daily: non_empty(daily),
+        spell_slots: non_empty(spell_slots),
+        footer_entries: non_empty(footer_entries),
+    })
 }
 
-fn parse_named_entry(entry: &str) -> Result<NamedEntry> {
+pub(crate) fn parse_named_entry(entry: &str) -> Result<NamedEntry> {
     let (name, entries) = entry
         .strip_prefix("***")
         .unwrap_or(entry)
@@ -678,24 +1516,62 @@ impl TryFrom<&str> for CreatureTypeEnum {
     }
 }
 
+/// The singular of a creature type as written in a swarm, e.g. `monstrosity` for
+/// `monstrosities`.
+fn singular_creature_type(plural: &str) -> Result<&'static str> {
+    Ok(match plural {
+        "aberrations" => "aberration",
+        "beasts" => "beast",
+        "celestials" => "celestial",
+        "constructs" => "construct",
+        "dragons" => "dragon",
+        "elementals" => "elemental",
+        "fey" => "fey",
+        "fiends" => "fiend",
+        "giants" => "giant",
+        "humanoids" => "humanoid",
+        "monstrosities" => "monstrosity",
+        "oozes" => "ooze",
+        "plants" => "plant",
+        "undead" => "undead",
+        _ => return Err(ParseError::new(plural, "Swarm creature type").into()),
+    })
+}
+
+/// Parse a creature type with its tags, e.g. `fiend (demon, shapechanger)` or
+/// `swarm of tiny beasts`.
 impl TryFrom<&str> for CreatureType {
     type Error = Error;
     fn try_from(value: &str) -> Result<Self> {
+        let swarm_re =
+            Regex::new(r"^swarm of (?P<size>[a-z]+) (?P<type>[a-z]+)(?: (?P<tags>.+))?$").unwrap();
+        if let Some(captures) = swarm_re.captures(value) {
+            let main_type = singular_creature_type(&captures["type"])?;
+            return Ok(Self {
+                swarm_size: Some(captures["size"].try_into()?),
+                ..captures.name("tags").map_or_else(
+                    || main_type.try_into(),
+                    |tags| format!("{main_type} {}", tags.as_str())[..].try_into(),
+                )?
+            });
+        }
+
         match value.replace('/', " ").splitn(2, ' ').collect_vec()[..] {
-            [main_type] => Ok(CreatureType {
+            [main_type] => Ok(Self {
                 main_type: main_type.try_into()?,
                 subtypes: None,
+                swarm_size: None,
             }),
-            [main_type, subtypes] => Ok(CreatureType {
+            [main_type, subtypes] => Ok(Self {
                 main_type: main_type.try_into()?,
                 subtypes: Some(
                     subtypes
-                        .replace('(', "")
-                        .replace(')', "")
+                        .replace(['(', ')'], "")
                         .split(", ")
-                        .map(ToString::to_string)
+                        .map(CreatureTypeTag::from)
                         .collect_vec(),
                 ),
+                swarm_size: None,
             }),
             _ => Err(ParseError {
                 string: value.to_string(),
@@ -707,6 +1583,23 @@ impl TryFrom<&str> for CreatureType {
     }
 }
 
+/// Tags of several words have a prefix, e.g. `chondathan human`, except for choices such as
+/// `any race`.
+impl From<&str> for CreatureTypeTag {
+    fn from(value: &str) -> Self {
+        match value.trim().rsplit_once(' ') {
+            Some((prefix, tag)) if !prefix.starts_with("any") => Self {
+                tag: tag.to_string(),
+                prefix: Some(prefix.to_string()),
+            },
+            _ => Self {
+                tag: value.trim().to_string(),
+                prefix: None,
+            },
+        }
+    }
+}
+
 impl TryFrom<&str> for AlignmentAxisMoral {
     type Error = Error;
     fn try_from(value: &str) -> Result<Self> {
@@ -754,9 +1647,48 @@ impl TryFrom<&str> for AlignmentAxis {
     }
 }
 
+/// Parse an alignment, e.g. `chaotic evil`, `any non-good alignment`, `typically lawful evil`,
+/// `neutral good (50%) or neutral evil (50%)` or `any alignment (as its creator)`.
+/// Alignments that cannot be expressed with the axes are kept as special text, unless they
+/// contain parentheses or commas.
 impl TryFrom<&str> for Alignment {
     type Error = Error;
     fn try_from(value: &str) -> Result<Self> {
+        let value = value.trim();
+        let chance_re = Regex::new(r"^(?P<alignment>.+) \((?P<chance>\d+)%\)$").unwrap();
+        let note_re = Regex::new(r"^(?P<alignment>[^(]+) \((?P<note>[^)]+)\)$").unwrap();
+
+        if let Some(alignment) = value.strip_prefix("typically ") {
+            return Ok(Self::Typically(Box::new(alignment.try_into()?)));
+        }
+        if value.ends_with("%)") {
+            return value
+                .split(" or ")
+                .flat_map(|part| part.split(", "))
+                .map(|part| {
+                    let captures = chance_re.captures(part).ok_or_else(|| {
+                        ParseError::new_with_problem(part, "Alignment", "Expected a chance")
+                    })?;
+                    Ok((
+                        captures["alignment"].try_into()?,
+                        captures["chance"]
+                            .parse()
+                            .map_err(ParseError::from_intparse_error(
+                                part.to_string(),
+                                "Alignment: chance".to_string(),
+                            ))?,
+                    ))
+                })
+                .collect::<Result<_>>()
+                .map(Self::Chances);
+        }
+        if let Some(captures) = note_re.captures(value) {
+            return Ok(Self::Note {
+                alignment: Box::new(captures["alignment"].try_into()?),
+                note: captures["note"].to_string(),
+            });
+        }
+
         match value
             .splitn(3, ' ')
             .filter(|word| !word.contains("alignment"))
@@ -768,60 +1700,98 @@ impl TryFrom<&str> for Alignment {
                 order: AlignmentAxisOrder::Neutral,
                 moral: AlignmentAxisMoral::Neutral,
             }),
-            ["any", single_axis] => Ok(Self::OneAxis(single_axis.try_into()?)),
+            ["any", single_axis] => Ok(single_axis.strip_prefix("non-").map_or_else(
+                || single_axis.try_into().map(Self::OneAxis),
+                |excluded_axis| excluded_axis.try_into().map(Self::ExcludedAxis),
+            )?),
             [order_axis, moral_axis] => Ok(Self::TwoAxes {
                 order: order_axis.try_into()?,
                 moral: moral_axis.try_into()?,
             }),
-            _ => Err(ParseError {
-                string: value.to_string(),
-                parsing_step: "Alignment".to_string(),
-                problem: None,
+            // Free text, e.g. `as its creator`. Leftover parentheses or commas are a mis-split
+            // creature line rather than an alignment.
+            _ if value.is_empty() || value.contains(['(', ')', ',', '%']) => {
+                Err(ParseError::new_with_problem(
+                    value,
+                    "Alignment",
+                    "Expected e.g. `chaotic evil`, `any non-good alignment` or free text",
+                )
+                .into())
             }
-            .into()),
+            _ => Ok(Self::Special(value.to_string())),
         }
     }
 }
 
+/// Parse an armor class line with one or more entries, e.g.
+/// `17 (natural armor), 19 with shield of faith` or `12 (15 with mage armor)`.
+fn parse_armor_class(armor_class_line: &str) -> Result<ArmorClasses> {
+    split_top_level(armor_class_line)
+        .into_iter()
+        .map(|entry| -> Result<ArmorClasses> {
+            let Some((main, braced)) = entry
+                .strip_suffix(')')
+                .and_then(|entry| entry.split_once(" ("))
+            else {
+                return Ok(vec![entry.try_into()?]);
+            };
+
+            // Alternative armor classes can be given in the parenthesis next to the armor.
+            let (alternatives, armor): (Vec<_>, Vec<_>) = split_top_level(braced)
+                .into_iter()
+                .partition(|part| part.starts_with(|c: char| c.is_ascii_digit()));
+            let main = if armor.is_empty() {
+                main.try_into()?
+            } else {
+                format!("{main} ({})", armor.join(", "))
+                    .as_str()
+                    .try_into()?
+            };
+            let alternatives = alternatives.into_iter().map(|alternative| {
+                ArmorClass::try_from(alternative).map(|alternative| ArmorClass {
+                    braces: true,
+                    ..alternative
+                })
+            });
+            std::iter::once(Ok(main)).chain(alternatives).collect()
+        })
+        .flatten_ok()
+        .collect()
+}
+
 impl TryFrom<&str> for ArmorClass {
     type Error = Error;
+    /// Parse a single armor class entry, e.g. `16 (chain mail, shield)` or `19 with shield of faith`.
     fn try_from(value: &str) -> Result<Self> {
         let error = |problem: &str| -> ParseError {
-            ParseError {
-                string: value.to_string(),
-                parsing_step: "Armor class".to_string(),
-                problem: Some(problem.to_string()),
-            }
+            ParseError::new_with_problem(value, "Armor class", problem)
         };
 
-        value.split_once(' ').map_or_else(
-            // No whitespace found, assume everything is AC.
-            || {
-                Ok(Self {
-                    ac: value.parse().map_err(|_| error("Could not parse as u8"))?,
-                    armor_type: None,
-                })
-            },
-            // Whitespace found, so there is both AC and armor type.
-            |(ac, armor_types)| {
-                Ok(Self {
-                    ac: ac.parse().map_err(|_| error("Could not parse AC as u8"))?,
-                    armor_type: armor_types
-                        .strip_prefix('(')
-                        .ok_or_else(|| error("No leading `(` found for armor type"))?
-                        .strip_suffix(')')
-                        .ok_or_else(|| error("No trailing `)` found for armor type"))
-                        .map(|armor_types| {
-                            Some(
-                                armor_types
-                                    .split(", ")
-                                    .map(ToString::to_string)
-                                    .collect_vec(),
-                            )
-                        })?,
-                })
-            },
-        )
+        let (ac, rest) = value
+            .split_once(' ')
+            .map_or((value, None), |(ac, rest)| (ac, Some(rest.trim())));
+        let armor_type = rest
+            .and_then(|rest| rest.strip_prefix('('))
+            .map(|armor_types| {
+                armor_types
+                    .strip_suffix(')')
+                    .ok_or_else(|| error("No trailing `)` found for armor type"))
+                    .map(|armor_types| {
+                        split_top_level(armor_types)
+                            .into_iter()
+                            .map(ToString::to_string)
+                            .collect_vec()
+                    })
+            })
+            .transpose()?;
+        Ok(Self {
+            ac: ac.parse().map_err(|_| error("Could not parse AC as u8"))?,
+            condition: rest
+                .filter(|_| armor_type.is_none())
+                .map(ToString::to_string),
+            armor_type,
+            braces: false,
+        })
     }
 }
 
@@ -836,7 +1806,7 @@ impl TryFrom<&str> for HitPointsFormula {
             }
         };
 
-        let parse_dice = |die_formula: &str| -> Result<(u8, u8)> {
+        let parse_dice = |die_formula: &str| -> Result<(u16, u8)> {
             die_formula
                 .split_once('d')
                 .ok_or_else(|| error("No `d` found in die formula"))
@@ -844,7 +1814,7 @@ impl TryFrom<&str> for HitPointsFormula {
                     Ok((
                         number_of_dice
                             .parse()
-                            .map_err(|_| error("Number of dice could not be parsed as u8"))?,
+                            .map_err(|_| error("Number of dice could not be parsed as u16"))?,
                         die_size
                             .parse()
                             .map_err(|_| error("Die size could not be parsed as u8"))?,
@@ -893,128 +1863,123 @@ impl TryFrom<&str> for HitPointsFormula {
 
 impl TryFrom<&str> for HitPoints {
     type Error = Error;
+    /// Parse hit points with a formula (`10 (1d10 + 4)`), without one (`45`) or as free text
+    /// (`equal to the summoner's hit point maximum`). Free text may not start with a digit.
     fn try_from(value: &str) -> Result<Self> {
-        let error = |problem: &str| -> ParseError {
-            ParseError {
-                string: value.to_string(),
-                parsing_step: "Hit Points".to_string(),
-                problem: Some(problem.to_string()),
-            }
-        };
+        let value = value.trim();
+        let rolled_re = Regex::new(r"^(?P<average>\d+) (?P<formula>\(.*\))$").unwrap();
 
-        value
-            .split_once(' ')
-            .ok_or_else(|| error("No separating ` ` found"))
-            .map(|(average, formula)| {
-                Ok(Self {
-                    average: average
-                        .parse()
-                        .map_err(|_| error("Could not parse average as u16"))?,
-                    formula: formula.try_into()?,
+        if let Ok(average) = value.parse() {
+            return Ok(Self::Fixed(average));
+        }
+        rolled_re.captures(value).map_or_else(
+            || {
+                if value.starts_with(|c: char| c.is_ascii_digit()) {
+                    Err(ParseError::new_with_problem(
+                        value,
+                        "Hit Points",
+                        "Expected e.g. `45` or `10 (1d10 + 4)`",
+                    )
+                    .into())
+                } else {
+                    Ok(Self::Special(value.to_string()))
+                }
+            },
+            |captures| {
+                Ok(Self::Rolled {
+                    average: captures["average"].parse().map_err(|_| {
+                        ParseError::new_with_problem(
+                            value,
+                            "Hit Points",
+                            "Could not parse average as u16",
+                        )
+                    })?,
+                    formula: captures["formula"].try_into()?,
                 })
-            })?
+            },
+        )
     }
 }
 
-impl TryFrom<&str> for Speed {
+impl TryFrom<&str> for SpeedMode {
     type Error = Error;
     fn try_from(value: &str) -> Result<Self> {
-        let cleaned_speeds = value.replace(" ft.", "");
-        let mut speeds = cleaned_speeds.split(", ").map(str::trim).into_iter();
-        let walk = speeds
-            .next()
-            .ok_or_else(|| OutOfBoundsError {
-                array: value
-                    .replace(" ft.", "")
-                    .split(", ")
-                    .map(ToString::to_string)
-                    .collect_vec(),
-                index: 0,
-                parsing_step: "Speed".to_string(),
-                problem: None,
-            })?
-            .parse()
-            .map_err(|_| ParseError {
-                string: value.to_string(),
-                parsing_step: "Walking speed".to_string(),
-                problem: None,
-            })?;
+        use SpeedMode::*;
+        match value.to_lowercase().as_str() {
+            "walk" => Ok(Walk),
+            "burrow" => Ok(Burrow),
+            "climb" => Ok(Climb),
+            "crawl" => Ok(Crawl),
+            "fly" => Ok(Fly),
+            "swim" => Ok(Swim),
+            _ => Err(ParseError::new(value, "Speed mode").into()),
+        }
+    }
+}
 
-        let mut speed_map = speeds
-            .map(|speed| {
-                speed.split_once(' ').ok_or_else(|| {
-                    ParseError {
-                        string: speed.to_string(),
-                        parsing_step: "Speed types".to_string(),
-                        problem: Some("No separating ` ` found".to_string()),
-                    }
-                    .into()
-                })
+impl TryFrom<&str> for Speed {
+    type Error = Error;
+    /// Parse speeds with conditions and alternate forms, e.g.
+    /// `30 ft. (40 ft. in bear form), climb 30 ft. (bear form only)` or `0 ft., fly 60 ft. (hover)`.
+    fn try_from(value: &str) -> Result<Self> {
+        let speed_re =
+            Regex::new(r"^(?:(?P<mode>[A-Za-z]+) )?(?P<speed>\d+) ft\.?(?: (?P<condition>.+))?$")
+                .unwrap();
+        let alternate_re = Regex::new(r"^\((?P<speed>\d+) ft\.? (?P<condition>[^)]+)\)$").unwrap();
+        let parse_speed = |speed: &str| -> Result<u16> {
+            speed.parse().map_err(|_| {
+                ParseError::new_with_problem(value, "Speed", "Could not parse as u16").into()
             })
-            .collect::<Result<HashMap<&str, &str>>>()?;
-
-        let mut pop_speed = |speed_type: &str| -> Result<Option<u16>> {
-            speed_map
-                .remove(speed_type)
-                .map(|speed| {
-                    speed.trim().parse().map_err(|_| {
-                        ParseError {
-                            string: speed.to_string(),
-                            parsing_step: format!("{speed_type} speed"),
-                            problem: Some("Could not parse as u16".to_string()),
-                        }
-                        .into()
-                    })
-                })
-                .transpose()
         };
 
-        let fly = pop_speed("fly").map_or_else(
-            |err| {
-                if let Error::Parse(ParseError { string, .. }) = &err {
-                    if let Some((speed, hover)) = string.split_once(' ') {
-                        if hover.contains("hover") {
-                            Ok(Some(FlySpeed {
-                                speed: speed.parse().map_err(|_| ParseError {
-                                    string: speed.to_string(),
-                                    parsing_step: "flying speed".to_string(),
-                                    problem: Some("Could not parse as u16".to_string()),
-                                })?,
-                                hover: true,
-                            }))
-                        } else {
-                            Err(ParseError {
-                                string: string.clone(),
-                                parsing_step: "flying speed".to_string(),
-                                problem: Some("Multiple words with no `hover`".to_string()),
-                            }
-                            .into())
-                        }
-                    } else {
-                        Err(err)
-                    }
-                } else {
-                    Err(err)
+        let mut speeds: HashMap<SpeedMode, SpeedValue> = HashMap::new();
+        let mut alternate: HashMap<SpeedMode, Vec<SpeedValue>> = HashMap::new();
+        for part in split_top_level(value) {
+            let captures = speed_re.captures(part).ok_or_else(|| {
+                ParseError::new_with_problem(part, "Speed", "Expected e.g. `fly 30 ft.`")
+            })?;
+            let mode = captures
+                .name("mode")
+                .map_or(Ok(SpeedMode::Walk), |mode| mode.as_str().try_into())?;
+            let mut speed = SpeedValue::from(parse_speed(&captures["speed"])?);
+            let condition = captures
+                .name("condition")
+                .map(|condition| condition.as_str());
+
+            // `30 ft. (40 ft. in bear form)` has an alternate speed in parentheses.
+            if let Some(alternate_captures) = condition.and_then(|c| alternate_re.captures(c)) {
+                alternate.entry(mode).or_default().push(SpeedValue {
+                    speed: parse_speed(&alternate_captures["speed"])?,
+                    condition: Some(format!("({})", &alternate_captures["condition"])),
+                });
+            } else {
+                speed.condition = condition.map(ToString::to_string);
+            }
+
+            match speeds.entry(mode) {
+                Entry::Occupied(_) => alternate.entry(mode).or_default().push(speed),
+                Entry::Vacant(entry) => {
+                    entry.insert(speed);
                 }
-            },
-            |maybe_speed| {
-                Ok(maybe_speed.map(|speed| FlySpeed {
-                    speed,
-                    hover: false,
-                }))
-            },
-        )?;
-
-        let speed = Self {
-            walk,
-            burrow: pop_speed("burrow")?,
-            climb: pop_speed("climb")?,
-            crawl: pop_speed("crawl")?,
-            fly,
-            swim: pop_speed("swim")?,
-        };
+            }
+        }
 
-        Ok(speed)
+        let can_hover = speeds
+            .get(&SpeedMode::Fly)
+            .and_then(|fly| fly.condition.as_ref())
+            .is_some_and(|condition| condition.contains("hover"));
+        Ok(Self {
+            walk: speeds
+                .remove(&SpeedMode::Walk)
+                .unwrap_or_else(|| SpeedValue::from(0)),
+            burrow: speeds.remove(&SpeedMode::Burrow),
+            climb: speeds.remove(&SpeedMode::Climb),
+            crawl: speeds.remove(&SpeedMode::Crawl),
+            fly: speeds.remove(&SpeedMode::Fly),
+            swim: speeds.remove(&SpeedMode::Swim),
+            can_hover,
+            alternate: (!alternate.is_empty()).then_some(alternate),
+        })
     }
 }
 
@@ -1051,6 +2016,84 @@ impl TryFrom<HashMap<&str, u8>> for AbilityScores {
     }
 }
 
+impl TryFrom<&str> for Sense {
+    type Error = Error;
+    /// Parse a sense, e.g. `darkvision 120 ft. (with devil's sight)`. Senses without a 5etools
+    /// tag, e.g. `sense magic 30 ft.`, and senses without a range are kept as written.
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        use SenseType::*;
+        let sense_re = Regex::new(
+            r"(?i)^(?P<type>[a-z][a-z' -]*?) (?P<range>\d+) ft\.?(?: \((?P<note>[^)]+)\))?$",
+        )
+        .unwrap();
+        let value = value.trim();
+        let Some(captures) = sense_re.captures(value) else {
+            return Ok(Self {
+                sense_type: Other(value.to_string()),
+                range: None,
+                blind_beyond_radius: false,
+                note: None,
+            });
+        };
+        let sense_type = match &captures["type"].to_lowercase()[..] {
+            "blindsight" => Blindsight,
+            "darkvision" => Darkvision,
+            "tremorsense" => Tremorsense,
+            "truesight" => Truesight,
+            _ => Other(captures["type"].to_string()),
+        };
+        let note = captures.name("note").map(|note| note.as_str().to_string());
+        let blind_beyond_radius = note
+            .as_ref()
+            .is_some_and(|note| note.eq_ignore_ascii_case("blind beyond this radius"));
+        Ok(Self {
+            sense_type,
+            range: Some(
+                captures["range"]
+                    .parse()
+                    .map_err(ParseError::from_intparse_error(
+                        value.to_string(),
+                        "Sense".to_string(),
+                    ))?,
+            ),
+            blind_beyond_radius,
+            note: note.filter(|_| !blind_beyond_radius),
+        })
+    }
+}
+
+impl From<&str> for Language {
+    fn from(value: &str) -> Self {
+        use Language::*;
+        match &value.trim().to_lowercase()[..] {
+            "common" => Common,
+            "dwarvish" => Dwarvish,
+            "elvish" => Elvish,
+            "giant" => Giant,
+            "gnomish" => Gnomish,
+            "goblin" => Goblin,
+            "halfling" => Halfling,
+            "orc" => Orc,
+            "abyssal" => Abyssal,
+            "celestial" => Celestial,
+            "draconic" => Draconic,
+            "deep speech" => DeepSpeech,
+            "infernal" => Infernal,
+            "primordial" => Primordial,
+            "sylvan" => Sylvan,
+            "undercommon" => Undercommon,
+            "aquan" => Aquan,
+            "auran" => Auran,
+            "ignan" => Ignan,
+            "terran" => Terran,
+            "druidic" => Druidic,
+            "thieves' cant" => ThievesCant,
+            "gith" => Gith,
+            _ => Other(value.trim().to_string()),
+        }
+    }
+}
+
 impl TryFrom<&str> for AbilityScore {
     type Error = Error;
     fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
@@ -9,20 +9,25 @@ use crate::{
     models::{
         common::{
             AbilityScore, Alignment, AlignmentAxis, AlignmentAxisMoral, AlignmentAxisOrder,
-            DamageType, NamedEntry, Skill, StatusCondition, ALL_DAMAGE_TYPES,
+            DamageType, Description, Language, NamedEntry, Skill, Source, StatusCondition,
+            ALL_DAMAGE_TYPES,
         },
         creatures::{
-            AbilityScores, ArmorClass, ChallengeRating, ConditionalDamageModifier, CreatureType,
-            CreatureTypeEnum, DamageModifier, DamageModifierType, FlySpeed, HitPoints,
-            HitPointsFormula, Size, Speed,
+            AbilityScores, AnyLanguages, ArmorClass, Bonus, Challenge, ChallengeRating,
+            ConditionalDamageModifier, CreatureType, CreatureTypeEnum, CreatureTypeTag,
+            DailySpells, DamageModifier, DamageModifierType, GearItem, HitPoints, HitPointsFormula,
+            Languages, LegendaryHeader, SavingThrows, Sense, SenseType, Size, Skills, Speed,
+            SpeedMode, SpeedValue, SpellSlotLevel, Spellcasting,
         },
     },
     parsers::creatures::{
-        extract_stat_blocks, parse_challenge_rating, parse_condition_immunities,
-        parse_damage_modifier, parse_first_group, parse_fourth_group, parse_languages,
-        parse_named_entry, parse_saving_throws, parse_second_group, parse_senses, parse_skills,
-        parse_third_group, SavingThrows, Skills,
+        extract_stat_blocks, parse_armor_class, parse_challenge_rating, parse_condition_immunities,
+        parse_creatures, parse_damage_modifier, parse_first_group, parse_fourth_group,
+        parse_fourth_group_2024, parse_languages, parse_legendary_actions, parse_legendary_header,
+        parse_named_entry, parse_saving_throws, parse_second_group, parse_second_group_2024,
+        parse_senses, parse_skills, parse_spellcasting, parse_third_group, parse_third_group_2024,
     },
+    utils::{compare::json_compare, traits::To5etools},
 };
 
 use super::parse_fifth_group;
@@ -48,6 +53,21 @@ fn extract_stat_blocks_test() {
     )
 }
 
+#[test]
+fn skips_other_stat_blocks() {
+    let source = Source {
+        source_book: "book",
+        page: 0,
+    };
+    let read = |filename| read_to_string(BufReader::new(File::open(filename).unwrap())).unwrap();
+    assert!(parse_creatures(read("resources/test/vehicles/unit_input.md"), &source).is_empty());
+    // Only the creature among the objects is parsed as one.
+    assert_eq!(
+        parse_creatures(read("resources/test/objects/unit_input.md"), &source).len(),
+        1
+    );
+}
+
 #[test]
 fn parse_first_group_test() {
     let first_group = vec![
@@ -56,10 +76,11 @@ fn parse_first_group_test() {
     ];
     let expected_result = Ok((
         "test".to_string(),
-        Size::Medium,
+        vec![Size::Medium],
         CreatureType {
             main_type: CreatureTypeEnum::Beast,
             subtypes: None,
+            swarm_size: None,
         },
         Alignment::Unaligned,
     ));
@@ -82,11 +103,13 @@ fn parse_second_group_test() {
             "- **Speed** 30 ft.".to_string(),
         ]),
         Ok((
-            ArmorClass {
+            vec![ArmorClass {
                 ac: 10,
                 armor_type: None,
-            },
-            HitPoints {
+                condition: None,
+                braces: false,
+            }],
+            HitPoints::Rolled {
                 average: 10,
                 formula: HitPointsFormula {
                     number_of_dice: 1,
@@ -95,12 +118,14 @@ fn parse_second_group_test() {
                 },
             },
             Speed {
-                walk: 30,
+                walk: 30.into(),
                 burrow: None,
                 climb: None,
                 crawl: None,
                 fly: None,
                 swim: None,
+                can_hover: false,
+                alternate: None,
             }
         ))
     )
@@ -125,6 +150,7 @@ fn parse_third_group_test() {
     )
 }
 
+#[allow(clippy::too_many_lines)]
 #[test]
 fn parse_fourth_group_test() {
     use AbilityScore::{Constitution, Wisdom};
@@ -150,18 +176,18 @@ fn parse_fourth_group_test() {
 
     assert_eq!(
         saves,
-        Some(HashMap::from_iter(vec![
-            (Constitution, 3 as i8),
-            (Wisdom, 2 as i8),
-        ]))
+        Some(SavingThrows {
+            saves: vec![(Constitution, 3.into()), (Wisdom, 2.into())],
+            special: None,
+        })
     );
 
     assert_eq!(
         skills,
-        Some(HashMap::from_iter(vec![
-            (Athletics, 5 as i8),
-            (Perception, 3 as i8),
-        ]))
+        Some(Skills {
+            skills: vec![(Athletics, 5.into()), (Perception, 3.into())],
+            one_of: None,
+        })
     );
 
     assert_eq!(
@@ -179,13 +205,27 @@ fn parse_fourth_group_test() {
 
     assert_eq!(condimm, Some(vec![Charmed, Frightened]));
 
-    assert_eq!(senses, vec!["blindsight 60 ft.".to_string()]);
+    assert_eq!(
+        senses,
+        vec![Sense {
+            sense_type: SenseType::Blindsight,
+            range: Some(60),
+            blind_beyond_radius: false,
+            note: None,
+        }]
+    );
 
     assert_eq!(passperc, 15);
 
-    assert_eq!(langs, vec!["Common".to_string(), "Giant".to_string()]);
+    assert_eq!(
+        langs,
+        Languages {
+            spoken: vec![Language::Common, Language::Giant],
+            ..Default::default()
+        }
+    );
 
-    assert_eq!(cr, ChallengeRating::WholeNumber(16));
+    assert_eq!(cr.rating, ChallengeRating::WholeNumber(16));
 
     let (saves, skills, damres, damimm, damvul, condimm, senses, passperc, langs, cr) =
         match parse_fourth_group(vec![
@@ -218,20 +258,37 @@ fn parse_fourth_group_test() {
 
     assert_eq!(condimm, None);
 
-    assert_eq!(senses, vec!["blindsight 60 ft.".to_string()]);
+    assert_eq!(
+        senses,
+        vec![Sense {
+            sense_type: SenseType::Blindsight,
+            range: Some(60),
+            blind_beyond_radius: false,
+            note: None,
+        }]
+    );
 
     assert_eq!(passperc, 15);
 
-    assert_eq!(langs, vec!["Common".to_string(), "Giant".to_string()]);
+    assert_eq!(
+        langs,
+        Languages {
+            spoken: vec![Language::Common, Language::Giant],
+            ..Default::default()
+        }
+    );
 
-    assert_eq!(cr, ChallengeRating::WholeNumber(16));
+    assert_eq!(cr.rating, ChallengeRating::WholeNumber(16));
 }
 
 #[test]
+#[allow(clippy::too_many_lines)]
 fn parse_fifth_group_test() {
     let group = [
         "***Ability.*** Desc",
         "***Ability 2.*** Desc 1",
+        "***Innate Spellcasting.*** Its spellcasting ability is Wisdom (spell save DC 12).",
+        "At will: *light*",
         "",
         "### Actions",
         "***Attack.*** Melee Weapon Attack: +1 to hit",
@@ -250,12 +307,27 @@ fn parse_fifth_group_test() {
         "Mythic header",
         "",
         "***Mythic 1*** Desc 4",
+        "### Lair Actions",
+        "On initiative count 20, the beast takes a lair action:",
+        "- Lair 1",
+        "- Lair 2",
     ]
     .map(ToString::to_string)
     .to_vec();
 
-    let (traits_p, actions_p, bonus_p, reactions_p, la_p, mh_p, ma_p) =
-        parse_fifth_group(group).unwrap();
+    let (
+        traits_p,
+        spellcasting_p,
+        actions_p,
+        bonus_p,
+        reactions_p,
+        leg_header_p,
+        la_p,
+        mh_p,
+        ma_p,
+        lair_p,
+        regional_p,
+    ) = parse_fifth_group(group).unwrap();
 
     assert_eq!(
         traits_p,
@@ -274,6 +346,24 @@ fn parse_fifth_group_test() {
         "\nTraits\n"
     );
 
+    assert_eq!(
+        spellcasting_p,
+        Some(vec![Spellcasting {
+            name: "Innate Spellcasting".to_string(),
+            header_entries: vec![
+                "Its spellcasting ability is Wisdom (spell save DC 12).".to_string()
+            ],
+            ability: Some(AbilityScore::Wisdom),
+            save_dc: Some(12),
+            attack_bonus: None,
+            at_will: Some(vec!["light".to_string()]),
+            daily: None,
+            spell_slots: None,
+            footer_entries: None,
+        }]),
+        "\nSpellcasting\n"
+    );
+
     assert_eq!(
         actions_p,
         Some(vec![NamedEntry {
@@ -303,6 +393,16 @@ fn parse_fifth_group_test() {
         "\nReactions\n"
     );
 
+    assert_eq!(
+        leg_header_p,
+        Some(LegendaryHeader {
+            actions_per_round: 3,
+            actions_in_lair: None,
+            header: Some("Can take 3 legendary actions".to_string()),
+        }),
+        "\nLegendary header\n"
+    );
+
     assert_eq!(
         la_p,
         Some(vec![NamedEntry {
@@ -324,6 +424,22 @@ fn parse_fifth_group_test() {
         }]),
         "\nMythic actions\n"
     );
+
+    assert_eq!(
+        lair_p,
+        Some(vec![
+            Description::Entry(
+                "On initiative count 20, the beast takes a lair action:".to_string()
+            ),
+            Description::List(vec![
+                Description::Entry("Lair 1".to_string()),
+                Description::Entry("Lair 2".to_string()),
+            ]),
+        ]),
+        "\nLair actions\n"
+    );
+
+    assert_eq!(regional_p, None, "Regional effects");
 }
 
 #[test]
@@ -332,7 +448,8 @@ fn creature_type() {
         "fiend (demon)".try_into(),
         Ok(CreatureType {
             main_type: CreatureTypeEnum::Fiend,
-            subtypes: Some(vec!["demon".to_string()])
+            subtypes: Some(vec!["demon".into()]),
+            swarm_size: None,
         })
     );
 
@@ -340,7 +457,8 @@ fn creature_type() {
         "fiend/undead".try_into(),
         Ok(CreatureType {
             main_type: CreatureTypeEnum::Fiend,
-            subtypes: Some(vec!["undead".to_string()])
+            subtypes: Some(vec!["undead".into()]),
+            swarm_size: None,
         })
     );
 
@@ -349,14 +467,100 @@ fn creature_type() {
         Ok(CreatureType {
             main_type: CreatureTypeEnum::Fiend,
             subtypes: None,
+            swarm_size: None,
         })
     );
+
+    assert_eq!(
+        "humanoid (chondathan human, shapechanger)".try_into(),
+        Ok(CreatureType {
+            main_type: CreatureTypeEnum::Humanoid,
+            subtypes: Some(vec![
+                CreatureTypeTag {
+                    tag: "human".to_string(),
+                    prefix: Some("chondathan".to_string()),
+                },
+                "shapechanger".into(),
+            ]),
+            swarm_size: None,
+        })
+    );
+
+    assert_eq!(
+        "swarm of tiny beasts".try_into(),
+        Ok(CreatureType {
+            main_type: CreatureTypeEnum::Beast,
+            subtypes: None,
+            swarm_size: Some(Size::Tiny),
+        })
+    );
+    assert_eq!(
+        "swarm of tiny monstrosities".try_into(),
+        Ok(CreatureType {
+            main_type: CreatureTypeEnum::Monstrosity,
+            subtypes: None,
+            swarm_size: Some(Size::Tiny),
+        })
+    );
+    assert_eq!(
+        "swarm of small undead".try_into(),
+        Ok(CreatureType {
+            main_type: CreatureTypeEnum::Undead,
+            subtypes: None,
+            swarm_size: Some(Size::Small),
+        })
+    );
+    assert!(CreatureType::try_from("swarm of tiny dinosaurs").is_err());
+}
+
+#[test]
+fn first_group_sizes() {
+    let parse = |line: &str| parse_first_group(vec!["## test".to_string(), line.to_string()]);
+
+    let (_, size, creature_type, _) =
+        parse("*Medium or Small Humanoid (Any Race), Any Alignment*").unwrap();
+    assert_eq!(size, vec![Size::Medium, Size::Small]);
+    assert_eq!(creature_type.main_type, CreatureTypeEnum::Humanoid);
+    assert_eq!(creature_type.subtypes, Some(vec!["any race".into()]));
+
+    let (_, size, creature_type, _) = parse("*Medium swarm of Tiny beasts, unaligned*").unwrap();
+    assert_eq!(size, vec![Size::Medium]);
+    assert_eq!(creature_type.swarm_size, Some(Size::Tiny));
+
+    assert!(parse("*Enormous beast, unaligned*").is_err());
+}
+
+#[test]
+fn first_group_multiple_tags() {
+    let parse = |line: &str| parse_first_group(vec!["## Werebear".to_string(), line.to_string()]);
+
+    let (_, size, creature_type, alignment) =
+        parse("*Medium humanoid (human, shapechanger), neutral good*").unwrap();
+    assert_eq!(size, vec![Size::Medium]);
+    assert_eq!(creature_type.main_type, CreatureTypeEnum::Humanoid);
+    assert_eq!(
+        creature_type.subtypes,
+        Some(vec!["human".into(), "shapechanger".into()])
+    );
+    assert_eq!(
+        alignment,
+        Alignment::TwoAxes {
+            order: AlignmentAxisOrder::Neutral,
+            moral: AlignmentAxisMoral::Good,
+        }
+    );
+
+    let (_, _, _, alignment) =
+        parse("*Medium humanoid (any race), chaotic good (75%), neutral evil (25%)*").unwrap();
+    assert!(matches!(alignment, Alignment::Chances(chances) if chances.len() == 2));
 }
 
 #[test]
 fn alignment() {
-    use Alignment::{Any, OneAxis, TwoAxes, Unaligned};
-    use AlignmentAxis::Order;
+    use Alignment::{
+        Any, Chances, ExcludedAxis, Note, OneAxis, Special, TwoAxes, Typically, Unaligned,
+    };
+    use AlignmentAxis::{Moral, Order};
     use AlignmentAxisMoral::Evil;
     use AlignmentAxisOrder::Chaotic;
 
@@ -383,7 +587,55 @@ fn alignment() {
             order: AlignmentAxisOrder::Neutral,
             moral: AlignmentAxisMoral::Neutral,
         })
-    )
+    );
+
+    assert_eq!(
+        "any non-good alignment".try_into(),
+        Ok(ExcludedAxis(Moral(AlignmentAxisMoral::Good)))
+    );
+
+    assert_eq!(
+        "typically lawful evil".try_into(),
+        Ok(Typically(Box::new(TwoAxes {
+            order: AlignmentAxisOrder::Lawful,
+            moral: Evil,
+        })))
+    );
+
+    assert_eq!(
+        "neutral good (50%) or neutral evil (50%)".try_into(),
+        Ok(Chances(vec![
+            (
+                TwoAxes {
+                    order: AlignmentAxisOrder::Neutral,
+                    moral: AlignmentAxisMoral::Good,
+                },
+                50
+            ),
+            (
+                TwoAxes {
+                    order: AlignmentAxisOrder::Neutral,
+                    moral: Evil,
+                },
+                50
+            ),
+        ]))
+    );
+
+    assert_eq!(
+        "any alignment (as its creator)".try_into(),
+        Ok(Note {
+            alignment: Box::new(Any),
+            note: "as its creator".to_string(),
+        })
+    );
+
+    assert_eq!(
+        "as its creator".try_into(),
+        Ok(Special("as its creator".to_string()))
+    );
+    assert!(Alignment::try_from("shapechanger), neutral good").is_err());
+    assert!(Alignment::try_from("").is_err());
 }
 
 #[test]
@@ -393,6 +645,8 @@ fn armor_class() {
         Ok(ArmorClass {
             ac: 10,
             armor_type: None,
+            condition: None,
+            braces: false,
         })
     );
 
@@ -401,15 +655,57 @@ fn armor_class() {
         Ok(ArmorClass {
             ac: 10,
             armor_type: Some(vec!["Natural Armor".to_string(), "Shield".to_string()]),
+            condition: None,
+            braces: false,
         })
     );
+
+    let armor_class =
+        |ac, armor_type: Option<&[&str]>, condition: Option<&str>, braces| ArmorClass {
+            ac,
+            armor_type: armor_type
+                .map(|armor_type| armor_type.iter().map(ToString::to_string).collect()),
+            condition: condition.map(ToString::to_string),
+            braces,
+        };
+    assert_eq!(
+        parse_armor_class("17 (natural armor), 19 with shield of faith"),
+        Ok(vec![
+            armor_class(17, Some(&["natural armor"]), None, false),
+            armor_class(19, None, Some("with shield of faith"), false),
+        ])
+    );
+    assert_eq!(
+        parse_armor_class("12 (15 with mage armor)"),
+        Ok(vec![
+            armor_class(12, None, None, false),
+            armor_class(15, None, Some("with mage armor"), true),
+        ])
+    );
+    assert_eq!(
+        parse_armor_class("16 (chain mail, shield)"),
+        Ok(vec![armor_class(
+            16,
+            Some(&["chain mail", "shield"]),
+            None,
+            false
+        )])
+    );
+    assert_eq!(
+        parse_armor_class("14 (natural armor, 16 with shield (+1))"),
+        Ok(vec![
+            armor_class(14, Some(&["natural armor"]), None, false),
+            armor_class(16, None, Some("with shield (+1)"), true),
+        ])
+    );
+    assert!(parse_armor_class("AC 12").is_err());
 }
 
 #[test]
 fn hit_points() {
     assert_eq!(
         "10 (1d10 + 4)".try_into(),
-        Ok(HitPoints {
+        Ok(HitPoints::Rolled {
             average: 10,
             formula: HitPointsFormula {
                 number_of_dice: 1,
@@ -421,7 +717,7 @@ fn hit_points() {
 
     assert_eq!(
         "2 (1d10-4)".try_into(),
-        Ok(HitPoints {
+        Ok(HitPoints::Rolled {
             average: 2,
             formula: HitPointsFormula {
                 number_of_dice: 1,
@@ -433,7 +729,7 @@ fn hit_points() {
 
     assert_eq!(
         "6 (1d10)".try_into(),
-        Ok(HitPoints {
+        Ok(HitPoints::Rolled {
             average: 6,
             formula: HitPointsFormula {
                 number_of_dice: 1,
@@ -442,6 +738,31 @@ fn hit_points() {
             }
         })
     );
+
+    assert_eq!(
+        "346 (33d20)".try_into(),
+        Ok(HitPoints::Rolled {
+            average: 346,
+            formula: HitPointsFormula {
+                number_of_dice: 33,
+                die_size: 20,
+                modifier: 0,
+            }
+        })
+    );
+
+    assert_eq!("45".try_into(), Ok(HitPoints::Fixed(45)));
+
+    assert_eq!(
+        "equal to the summoner's hit point maximum".try_into(),
+        Ok(HitPoints::Special(
+            "equal to the summoner's hit point maximum".to_string()
+        ))
+    );
+
+    assert!(HitPoints::try_from("10 (1d10 plus 4)").is_err());
+    assert!(HitPoints::try_from("1O (3d8)").is_err());
+    assert!(HitPoints::try_from("45 hit points").is_err());
 }
 
 #[test]
@@ -449,24 +770,28 @@ fn speed() {
     assert_eq!(
         "30 ft.".try_into(),
         Ok(Speed {
-            walk: 30,
+            walk: 30.into(),
             burrow: None,
             climb: None,
             crawl: None,
             fly: None,
             swim: None,
+            can_hover: false,
+            alternate: None,
         })
     );
 
     assert_eq!(
         "30 ft., climb 30 ft., burrow 30 ft.".try_into(),
         Ok(Speed {
-            walk: 30,
-            burrow: Some(30),
-            climb: Some(30),
+            walk: 30.into(),
+            burrow: Some(30.into()),
+            climb: Some(30.into()),
             crawl: None,
             fly: None,
             swim: None,
+            can_hover: false,
+            alternate: None,
         })
     );
 
@@ -474,17 +799,61 @@ fn speed() {
         "30 ft., burrow 30 ft., climb 30 ft., crawl 30 ft., fly 30 ft. (hover), swim 30 ft."
             .try_into(),
         Ok(Speed {
-            walk: 30,
-            burrow: Some(30),
-            climb: Some(30),
-            crawl: Some(30),
-            fly: Some(FlySpeed {
+            walk: 30.into(),
+            burrow: Some(30.into()),
+            climb: Some(30.into()),
+            crawl: Some(30.into()),
+            fly: Some(SpeedValue {
                 speed: 30,
-                hover: true,
+                condition: Some("(hover)".to_string()),
             }),
-            swim: Some(30),
+            swim: Some(30.into()),
+            can_hover: true,
+            alternate: None,
         })
     );
+
+    assert_eq!(
+        "30 ft. (40 ft. in bear form), climb 30 ft. (bear form only)".try_into(),
+        Ok(Speed {
+            walk: 30.into(),
+            burrow: None,
+            climb: Some(SpeedValue {
+                speed: 30,
+                condition: Some("(bear form only)".to_string()),
+            }),
+            crawl: None,
+            fly: None,
+            swim: None,
+            can_hover: false,
+            alternate: Some(HashMap::from([(
+                SpeedMode::Walk,
+                vec![SpeedValue {
+                    speed: 40,
+                    condition: Some("(in bear form)".to_string()),
+                }]
+            )])),
+        })
+    );
+
+    assert_eq!(
+        "walk 20 ft., swim 40 ft. (only while in water)".try_into(),
+        Ok(Speed {
+            walk: 20.into(),
+            burrow: None,
+            climb: None,
+            crawl: None,
+            fly: None,
+            swim: Some(SpeedValue {
+                speed: 40,
+                condition: Some("(only while in water)".to_string()),
+            }),
+            can_hover: false,
+            alternate: None,
+        })
+    );
+
+    assert!(Speed::try_from("30 ft., teleport 30 ft.").is_err());
 }
 
 #[test]
@@ -492,17 +861,49 @@ fn saving_throws() {
     use AbilityScore::{Charisma, Strength};
     assert_eq!(
         parse_saving_throws("STR +3, CHA -2"),
-        Ok(HashMap::from([(Strength, 3), (Charisma, -2)]))
+        Ok(SavingThrows {
+            saves: vec![(Strength, 3.into()), (Charisma, (-2).into())],
+            special: None,
+        })
+    );
+
+    assert_eq!(
+        parse_saving_throws("Str −1, advantage against spells"),
+        Ok(SavingThrows {
+            saves: vec![(Strength, (-1).into())],
+            special: Some("advantage against spells".to_string()),
+        })
     );
 }
 
 #[test]
 fn skills() {
-    use Skill::{Athletics, Perception};
+    use Skill::{Arcana, Athletics, History, Perception};
     assert_eq!(
         parse_skills("Athletics +3, Perception -2"),
-        Ok(HashMap::from([(Athletics, 3), (Perception, -2)]))
+        Ok(Skills {
+            skills: vec![(Athletics, 3.into()), (Perception, (-2).into())],
+            one_of: None,
+        })
     );
+
+    assert_eq!(
+        parse_skills(
+            "Perception +5 (+10 with sight), plus one of the following: Arcana +7 or History +7"
+        ),
+        Ok(Skills {
+            skills: vec![(
+                Perception,
+                Bonus {
+                    bonus: 5,
+                    note: Some("(+10 with sight)".to_string()),
+                }
+            )],
+            one_of: Some(vec![(Arcana, 7.into()), (History, 7.into())]),
+        })
+    );
+
+    assert!(parse_skills("Juggling +3").is_err());
 }
 
 #[test]
@@ -552,25 +953,179 @@ fn condition_immunities() {
 
 #[test]
 fn senses() {
+    let sense = |sense_type, range, blind_beyond_radius| Sense {
+        sense_type,
+        range: Some(range),
+        blind_beyond_radius,
+        note: None,
+    };
     assert_eq!(
         parse_senses("Darkvision 60 ft., Passive Perception 17"),
-        Ok((17, vec!["Darkvision 60 ft.".to_string()]))
-    )
+        Ok((17, vec![sense(SenseType::Darkvision, 60, false)]))
+    );
+    assert_eq!(
+        parse_senses(
+            "blindsight 30 ft. (blind beyond this radius), tremorsense 60 ft., passive Perception 10"
+        ),
+        Ok((
+            10,
+            vec![
+                sense(SenseType::Blindsight, 30, true),
+                sense(SenseType::Tremorsense, 60, false),
+            ]
+        ))
+    );
+    assert_eq!(
+        parse_senses("truesight 120 ft., passive Perception 22"),
+        Ok((22, vec![sense(SenseType::Truesight, 120, false)]))
+    );
+    assert_eq!(
+        parse_senses("sense magic 30 ft., passive Perception 10"),
+        Ok((
+            10,
+            vec![sense(
+                SenseType::Other("sense magic".to_string()),
+                30,
+                false
+            )]
+        ))
+    );
+    assert_eq!(
+        parse_senses("darkvision 120 ft. (with devil's sight), passive Perception 13"),
+        Ok((
+            13,
+            vec![Sense {
+                note: Some("with devil's sight".to_string()),
+                ..sense(SenseType::Darkvision, 120, false)
+            }]
+        ))
+    );
+    assert_eq!(
+        parse_senses("sees invisible creatures, passive Perception 10"),
+        Ok((
+            10,
+            vec![Sense {
+                sense_type: SenseType::Other("sees invisible creatures".to_string()),
+                range: None,
+                blind_beyond_radius: false,
+                note: None,
+            }]
+        ))
+    );
 }
 
 #[test]
 fn languages() {
+    use Language::{Abyssal, Auran, Common, Draconic, Other, ThievesCant};
     assert_eq!(
         parse_languages("Common, Auran"),
-        Ok(vec!["Common".to_string(), "Auran".to_string()])
-    )
+        Ok(Languages {
+            spoken: vec![Common, Auran],
+            ..Default::default()
+        })
+    );
+    assert_eq!(parse_languages("—"), Ok(Languages::default()));
+    assert_eq!(
+        parse_languages("Abyssal, Thieves’ cant, telepathy 120 ft."),
+        Ok(Languages {
+            spoken: vec![Abyssal, ThievesCant],
+            telepathy: Some(120),
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        parse_languages("understands Common and Draconic but can't speak"),
+        Ok(Languages {
+            understood: vec![Common, Draconic],
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        parse_languages("understands the languages it knew in life but can't speak"),
+        Ok(Languages {
+            understood: vec![Other("the languages it knew in life".to_string())],
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn any_languages() {
+    assert_eq!(
+        parse_languages("any two languages"),
+        Ok(Languages {
+            any: Some(AnyLanguages {
+                count: 2,
+                note: None
+            }),
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        parse_languages("any one language (usually Common)"),
+        Ok(Languages {
+            any: Some(AnyLanguages {
+                count: 1,
+                note: Some("(usually Common)".to_string())
+            }),
+            ..Default::default()
+        })
+    );
 }
 
 #[test]
 fn challenge_rating() {
     use ChallengeRating::{Quarter, WholeNumber};
-    assert_eq!(parse_challenge_rating("11 (7,200 XP)"), Ok(WholeNumber(11)));
-    assert_eq!(parse_challenge_rating("1/4 (400 XP)"), Ok(Quarter));
+    let challenge = |rating, experience_points, lair, coven| Challenge {
+        rating,
+        experience_points,
+        lair,
+        coven,
+    };
+    assert_eq!(
+        parse_challenge_rating("11 (7,200 XP)"),
+        Ok(challenge(WholeNumber(11), Some(7200), None, None))
+    );
+    assert_eq!(
+        parse_challenge_rating("1/4 (400 XP)"),
+        Ok(challenge(Quarter, Some(400), None, None))
+    );
+    assert_eq!(
+        parse_challenge_rating("0 (0 or 10 XP)"),
+        Ok(challenge(WholeNumber(0), Some(10), None, None))
+    );
+    assert_eq!(
+        parse_challenge_rating("10 (5,900 XP, or 7,200 XP in lair)"),
+        Ok(challenge(
+            WholeNumber(10),
+            Some(5900),
+            Some(WholeNumber(11)),
+            None
+        ))
+    );
+    assert_eq!(
+        parse_challenge_rating("10 (XP 5,900, or 7,200 in lair; PB +4)"),
+        Ok(challenge(
+            WholeNumber(10),
+            Some(5900),
+            Some(WholeNumber(11)),
+            None
+        ))
+    );
+    assert_eq!(
+        parse_challenge_rating("3 (700 XP), or 5 (1,800 XP) when part of a coven"),
+        Ok(challenge(
+            WholeNumber(3),
+            Some(700),
+            None,
+            Some(WholeNumber(5))
+        ))
+    );
+    assert_eq!(
+        parse_challenge_rating("12"),
+        Ok(challenge(WholeNumber(12), None, None, None))
+    );
+    assert!(parse_challenge_rating("10 (5,900 XP, or 7,000 XP in lair)").is_err());
 }
 
 #[test]
@@ -597,3 +1152,393 @@ fn named_entry() {
         })
     );
 }
+
+#[test]
+fn spellcasting() {
+    let spells = |spells: &[&str]| spells.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+    assert_eq!(
+        parse_spellcasting(&NamedEntry {
+            name: "Spellcasting.".to_string(),
+            entry: [
+                "The mage is a 9th-level spellcaster. Its spellcasting ability is Intelligence (spell save DC 14, +6 to hit with spell attacks). The mage has the following wizard spells prepared:",
+                "Cantrips (at will): *fire bolt, light, mage hand*",
+                "1st level (4 slots): *detect magic, mage armor (self only), shield*",
+                "5th level (1 slot): *cone of cold*",
+            ]
+            .join("\n"),
+            sub_entries: None,
+        }),
+        Ok(Spellcasting {
+            name: "Spellcasting".to_string(),
+            header_entries: vec!["The mage is a 9th-level spellcaster. Its spellcasting ability is Intelligence (spell save DC 14, +6 to hit with spell attacks). The mage has the following wizard spells prepared:".to_string()],
+            ability: Some(AbilityScore::Intelligence),
+            save_dc: Some(14),
+            attack_bonus: Some(6),
+            at_will: None,
+            daily: None,
+            spell_slots: Some(vec![
+                SpellSlotLevel {
+                    level: 0,
+                    lower: None,
+                    slots: None,
+                    spells: spells(&["fire bolt", "light", "mage hand"]),
+                },
+                SpellSlotLevel {
+                    level: 1,
+                    lower: None,
+                    slots: Some(4),
+                    spells: spells(&["detect magic", "mage armor (self only)", "shield"]),
+                },
+                SpellSlotLevel {
+                    level: 5,
+                    lower: None,
+                    slots: Some(1),
+                    spells: spells(&["cone of cold"]),
+                },
+            ]),
+            footer_entries: None,
+        })
+    );
+
+    // Spells emphasized one by one.
+    assert_eq!(
+        parse_spellcasting(&NamedEntry {
+            name: "Spellcasting.".to_string(),
+            entry: [
+                "The acolyte is a 1st-level spellcaster. Its spellcasting ability is Wisdom (spell save DC 12).",
+                "Cantrips (at will): *fire bolt*, *light*",
+                "1st level (3 slots): _bless_, *shield* (self only).",
+            ]
+            .join("\n"),
+            sub_entries: None,
+        })
+        .map(|spellcasting| {
+            spellcasting
+                .spell_slots
+                .unwrap_or_default()
+                .into_iter()
+                .map(|level| level.spells)
+                .collect::<Vec<_>>()
+        }),
+        Ok(vec![
+            spells(&["fire bolt", "light"]),
+            spells(&["bless", "shield (self only)"]),
+        ])
+    );
+}
+
+#[test]
+fn innate_spellcasting() {
+    let spells = |spells: &[&str]| spells.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+    assert_eq!(
+        parse_spellcasting(&NamedEntry {
+            name: "Innate Spellcasting.".to_string(),
+            entry: "The djinni's innate spellcasting ability is Charisma (spell save DC 17). It can innately cast the following spells:".to_string(),
+            sub_entries: Some(vec![
+                NamedEntry {
+                    name: "At will:".to_string(),
+                    entry: "*detect magic, thunderwave*".to_string(),
+                    sub_entries: None,
+                },
+                NamedEntry {
+                    name: "3/day each:".to_string(),
+                    entry: "*tongues, wind walk*".to_string(),
+                    sub_entries: None,
+                },
+                NamedEntry {
+                    name: "1/day:".to_string(),
+                    entry: "*plane shift*".to_string(),
+                    sub_entries: None,
+                },
+            ]),
+        }),
+        Ok(Spellcasting {
+            name: "Innate Spellcasting".to_string(),
+            header_entries: vec!["The djinni's innate spellcasting ability is Charisma (spell save DC 17). It can innately cast the following spells:".to_string()],
+            ability: Some(AbilityScore::Charisma),
+            save_dc: Some(17),
+            attack_bonus: None,
+            at_will: Some(spells(&["detect magic", "thunderwave"])),
+            daily: Some(vec![
+                DailySpells {
+                    uses: 3,
+                    each: true,
+                    spells: spells(&["tongues", "wind walk"]),
+                },
+                DailySpells {
+                    uses: 1,
+                    each: false,
+                    spells: spells(&["plane shift"]),
+                },
+            ]),
+            spell_slots: None,
+            footer_entries: None,
+        })
+    );
+
+    assert_eq!(
+        parse_spellcasting(&NamedEntry {
+            name: "Spellcasting.".to_string(),
+            entry: [
+                "The fiend is a 5th-level spellcaster. Its spellcasting ability is Charisma (spell save DC 13).",
+                "1st-3rd level (2 3rd-level slots): *hex, hold person*",
+                "The fiend regains its spell slots on a short rest.",
+            ]
+            .join("\n"),
+            sub_entries: None,
+        })
+        .map(|spellcasting| (spellcasting.spell_slots, spellcasting.footer_entries)),
+        Ok((
+            Some(vec![SpellSlotLevel {
+                level: 3,
+                lower: Some(1),
+                slots: Some(2),
+                spells: spells(&["hex", "hold person"]),
+            }]),
+            Some(vec!["The fiend regains its spell slots on a short rest.".to_string()]),
+        ))
+    );
+}
+
+#[test]
+fn legendary_header() {
+    assert_eq!(
+        parse_legendary_header(
+            "The dragon can take 3 legendary actions, choosing from the options below. Only one \
+            legendary action option can be used at a time and only at the end of another \
+            creature's turn. The dragon regains spent legendary actions at the start of its turn."
+        ),
+        Ok(LegendaryHeader {
+            actions_per_round: 3,
+            actions_in_lair: None,
+            header: None,
+        })
+    );
+
+    assert_eq!(
+        parse_legendary_header(
+            "The lich can take 4 legendary actions, choosing from the options below. Only one \
+            legendary action option can be used at a time and only at the end of another \
+            creature's turn. The lich regains spent legendary actions at the start of its turn."
+        ),
+        Ok(LegendaryHeader {
+            actions_per_round: 4,
+            actions_in_lair: None,
+            header: None,
+        })
+    );
+
+    assert_eq!(
+        parse_legendary_header(
+            "Legendary Action Uses: 3 (4 in Lair). Immediately after another creature's turn, \
+            the dragon can expend a use to take one of the following actions. The dragon \
+            regains all expended uses at the start of each of its turns."
+        ),
+        Ok(LegendaryHeader {
+            actions_per_round: 3,
+            actions_in_lair: Some(4),
+            header: None,
+        })
+    );
+
+    assert_eq!(
+        parse_legendary_header(
+            "The tarrasque can take 2 legendary actions, but only while it is enraged."
+        ),
+        Ok(LegendaryHeader {
+            actions_per_round: 2,
+            actions_in_lair: None,
+            header: Some(
+                "The tarrasque can take 2 legendary actions, but only while it is enraged."
+                    .to_string()
+            ),
+        })
+    );
+}
+
+#[test]
+fn legendary_actions() {
+    assert_eq!(
+        parse_legendary_actions("***Detect.*** The dragon makes a Wisdom (Perception) check."),
+        Ok((
+            LegendaryHeader {
+                actions_per_round: 3,
+                actions_in_lair: None,
+                header: None,
+            },
+            vec![NamedEntry {
+                name: "Detect.".to_string(),
+                entry: "The dragon makes a Wisdom (Perception) check.".to_string(),
+                sub_entries: None,
+            }]
+        ))
+    );
+}
+
+#[test]
+fn second_group_2024() {
+    assert_eq!(
+        parse_second_group_2024(vec![
+            "- **AC** 15 &nbsp;&nbsp; **Initiative** −1 (9)".to_string(),
+            "- **HP** 10 (3d6)".to_string(),
+            "- **Speed** 30 ft.".to_string(),
+        ]),
+        Ok((
+            vec![ArmorClass {
+                ac: 15,
+                armor_type: None,
+                condition: None,
+                braces: false,
+            }],
+            Some(-1),
+            HitPoints::Rolled {
+                average: 10,
+                formula: HitPointsFormula {
+                    number_of_dice: 3,
+                    die_size: 6,
+                    modifier: 0,
+                },
+            },
+            Speed {
+                walk: 30.into(),
+                burrow: None,
+                climb: None,
+                crawl: None,
+                fly: None,
+                swim: None,
+                can_hover: false,
+                alternate: None,
+            }
+        ))
+    );
+}
+
+#[test]
+fn third_group_2024() {
+    use AbilityScore::{Constitution, Dexterity};
+    assert_eq!(
+        parse_third_group_2024(vec![
+            "| | | MOD | SAVE | | | MOD | SAVE | | | MOD | SAVE |".to_string(),
+            "|:--|:-:|:-:|:-:|:--|:-:|:-:|:-:|:--|:-:|:-:|:-:|".to_string(),
+            "| **Str** | 8 | −1 | −1 | **Dex** | 15 | +2 | +4 | **Con** | 12 | +1 | +3 |"
+                .to_string(),
+            "| **Int** | 10 | +0 | +0 | **Wis** | 8 | −1 | −1 | **Cha** | 8 | −1 | −1 |"
+                .to_string(),
+        ]),
+        Ok((
+            AbilityScores {
+                strength: 8,
+                dexterity: 15,
+                constitution: 12,
+                intelligence: 10,
+                wisdom: 8,
+                charisma: 8,
+            },
+            Some(SavingThrows {
+                saves: vec![(Dexterity, 4.into()), (Constitution, 3.into())],
+                special: None,
+            })
+        ))
+    );
+}
+
+#[test]
+fn fourth_group_2024() {
+    use DamageModifier::Unconditional;
+    use DamageType::{Fire, Poison};
+    use StatusCondition::Charmed;
+    let (skills, damres, damimm, damvul, condimm, gear, senses, passperc, langs, cr) =
+        parse_fourth_group_2024(vec![
+            "- **Skills** Stealth +6".to_string(),
+            "- **Resistances** Fire".to_string(),
+            "- **Immunities** Poison; Charmed".to_string(),
+            "- **Gear** Scimitar, Arrows (20)".to_string(),
+            "- **Senses** Darkvision 60 ft.; Passive Perception 9".to_string(),
+            "- **Languages** Common, Goblin".to_string(),
+            "- **CR** 1/4 (XP 50; PB +2)".to_string(),
+        ])
+        .unwrap();
+
+    assert_eq!(
+        skills,
+        Some(Skills {
+            skills: vec![(Skill::Stealth, 6.into())],
+            one_of: None,
+        })
+    );
+    assert_eq!(damres, Some(vec![Unconditional(Fire)]));
+    assert_eq!(damimm, Some(vec![Unconditional(Poison)]));
+    assert_eq!(damvul, None);
+    assert_eq!(condimm, Some(vec![Charmed]));
+    assert_eq!(
+        gear,
+        Some(vec![
+            GearItem {
+                name: "Scimitar".to_string(),
+                quantity: None,
+            },
+            GearItem {
+                name: "Arrows".to_string(),
+                quantity: Some(20),
+            },
+        ])
+    );
+    assert_eq!(
+        senses,
+        vec![Sense {
+            sense_type: SenseType::Darkvision,
+            range: Some(60),
+            blind_beyond_radius: false,
+            note: None,
+        }]
+    );
+    assert_eq!(passperc, 9);
+    assert_eq!(
+        langs,
+        Languages {
+            spoken: vec![Language::Common, Language::Goblin],
+            ..Default::default()
+        }
+    );
+    assert_eq!(cr.rating, ChallengeRating::Quarter);
+}
+
+#[test]
+fn stat_block() {
+    let source = Source {
+        source_book: "book",
+        page: 0,
+    };
+    let document = read_to_string(BufReader::new(
+        File::open("resources/test/creatures/unit_input.md").unwrap(),
+    ))
+    .unwrap();
+    let creatures = parse_creatures(document, &source);
+    assert_eq!(creatures.len(), 3);
+    assert!(creatures[0].is_err(), "Unparsable entity should not parse");
+    let creature = creatures[1].as_ref().unwrap();
+    assert_eq!(creature.name, "test");
+    assert_eq!(creature.passive_perception, 15);
+    assert_eq!(creature.initiative, None);
+    assert_eq!(creature.actions.as_ref().map(Vec::len), Some(1));
+}
+
+#[test]
+fn stat_block_2024() {
+    let source = Source {
+        source_book: "book",
+        page: 0,
+    };
+    let document = read_to_string(BufReader::new(
+        File::open("resources/test/creatures/unit_input_2024.md").unwrap(),
+    ))
+    .unwrap();
+    let expected_json: serde_json::Value = serde_json::from_reader(BufReader::new(
+        File::open("resources/test/creatures/unit_output_2024.json").unwrap(),
+    ))
+    .unwrap();
+    let creature = parse_creatures(document, &source).remove(0).unwrap();
+    json_compare(creature.to_5etools_creature(), expected_json).unwrap();
+}
@@ -0,0 +1,251 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{
+    models::{
+        common::{AbilityScore, Source},
+        feats::{AbilityIncrease, Feat, FeatCategory, Prerequisites},
+    },
+    parsers::optional_features::extract_optional_feature_lists,
+    utils::{
+        error::{OutOfBoundsError, ParseError, Result},
+        markdown::{extract_blocks, parse_entries, remove_emphasis, split_top_level},
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Races from the Player's Handbook that may be a prerequisite of a feat.
+const RACES: [&str; 12] = [
+    "aasimar",
+    "dragonborn",
+    "dwarf",
+    "elf",
+    "gnome",
+    "goliath",
+    "half-elf",
+    "half-orc",
+    "halfling",
+    "human",
+    "orc",
+    "tiefling",
+];
+
+pub(crate) const ABILITIES: &str = "strength|dexterity|constitution|intelligence|wisdom|charisma";
+
+/// Parse all feats in a GM Binder or Homebrewery document.
+///
+/// A feat is a heading followed by a line with its prerequisites, e.g.
+/// `*Prerequisite: 4th level*`, or with its 2024 category, e.g.
+/// `*General Feat (Prerequisite: Level 4+)*`.
+#[must_use]
+pub fn parse_feats<'a>(document: &str, source: &Source<'a>) -> Vec<Result<Feat<'a>>> {
+    extract_feats(document)
+        .into_iter()
+        .map(|feat| parse_feat(&feat, source.clone()))
+        .collect()
+}
+
+pub(crate) fn is_feat_line(line: &str) -> bool {
+    let feat_line_re =
+        Regex::new(r"(?i)^(?:prerequisites?:|(?:origin|general|fighting style|epic boon) feat\b)")
+            .unwrap();
+    (line.starts_with('*') || line.starts_with('_'))
+        && feat_line_re.is_match(&remove_emphasis(line))
+}
+
+/// Split a document into feats, each starting with its heading and prerequisite or category
+/// line. Optional features with prerequisites, e.g. eldritch invocations, are not feats.
+fn extract_feats(document: &str) -> Vec<Vec<String>> {
+    let optional_feature_lines = extract_optional_feature_lists(document)
+        .into_iter()
+        .flat_map(|(_, list)| list)
+        .collect::<HashSet<_>>();
+    extract_blocks(document, is_feat_line)
+        .into_iter()
+        .filter(|feat| {
+            feat.first()
+                .is_none_or(|heading| !optional_feature_lines.contains(heading))
+        })
+        .collect()
+}
+
+/// Parse a single feat.
+///
+/// * `feat` - Lines of the feat: heading, prerequisite or category line and entries
+/// * `source` - Source book of the feat
+///
+/// Returns: The parsed feat.
+fn parse_feat<'a>(feat: &[String], source: Source<'a>) -> Result<Feat<'a>> {
+    let [heading, feat_line, entries @ ..] = feat else {
+        return Err(OutOfBoundsError {
+            array: feat.to_vec(),
+            index: 1,
+            parsing_step: "Feat".to_string(),
+            problem: Some("Expected a heading and a prerequisite or category line".to_string()),
+        }
+        .into());
+    };
+    let feat_line = remove_emphasis(feat_line);
+    let (category, prerequisites) = parse_feat_line(&feat_line)?;
+    let text = entries.join("\n");
+    Ok(Feat {
+        name: heading.trim_start_matches('#').trim().to_string(),
+        source,
+        category,
+        prerequisites: prerequisites
+            .map(parse_prerequisites)
+            .transpose()?
+            .unwrap_or_default(),
+        ability: parse_ability_increase(&text)?,
+        entries: parse_entries(&text),
+    })
+}
+
+/// Parse the line below the heading of a feat, e.g. `Prerequisite: 4th level` or
+/// `General Feat (Prerequisite: Level 4+)`.
+///
+/// Returns: The category and the prerequisites, if any.
+fn parse_feat_line(feat_line: &str) -> Result<(Option<FeatCategory>, Option<&str>)> {
+    let category_re = Regex::new(
+        r"(?i)^(?P<category>origin|general|fighting style|epic boon) feat(?: \(prerequisites?: (?P<prerequisites>.+)\))?$",
+    )
+    .unwrap();
+    let prerequisites_re = Regex::new(r"(?i)^prerequisites?:\s*(?P<prerequisites>.+)$").unwrap();
+    if let Some(captures) = category_re.captures(feat_line) {
+        let category = match &captures["category"].to_lowercase()[..] {
+            "origin" => FeatCategory::Origin,
+            "general" => FeatCategory::General,
+            "fighting style" => FeatCategory::FightingStyle,
+            _ => FeatCategory::EpicBoon,
+        };
+        return Ok((
+            Some(category),
+            captures
+                .name("prerequisites")
+                .map(|prerequisites| prerequisites.as_str()),
+        ));
+    }
+    prerequisites_re
+        .captures(feat_line)
+        .and_then(|captures| captures.name("prerequisites"))
+        .map(|prerequisites| (None, Some(prerequisites.as_str())))
+        .ok_or_else(|| ParseError::new_with_problem(feat_line, "Feat", "Invalid format").into())
+}
+
+/// Parse the full name of an ability score, e.g. `Strength`.
+pub(crate) fn parse_ability(ability: &str) -> Result<AbilityScore> {
+    ability
+        .trim()
+        .to_lowercase()
+        .get(..3)
+        .ok_or_else(|| ParseError::new(ability, "Ability score").into())
+        .and_then(AbilityScore::try_from)
+}
+
+/// Parse a list of ability scores, e.g. `Intelligence, Wisdom, or Charisma`.
+pub(crate) fn parse_abilities(abilities: &str) -> Result<Vec<AbilityScore>> {
+    let separator_re = Regex::new(r",? (?:or|and) |, ").unwrap();
+    separator_re.split(abilities).map(parse_ability).collect()
+}
+
+/// Parse the prerequisites of a feat, e.g. `4th level, Strength or Dexterity 13 or higher`.
+fn parse_prerequisites(prerequisites: &str) -> Result<Prerequisites> {
+    let ability_re = Regex::new(&format!(
+        r"(?i)\b(?P<abilities>(?:{ABILITIES})(?:,? (?:or )?(?:{ABILITIES}))*) (?P<score>\d+)(?: or higher|\+)?"
+    ))
+    .unwrap();
+    let level_re =
+        Regex::new(r"(?i)^(?:(?P<ordinal>\d+)(?:st|nd|rd|th) level|level (?P<level>\d+)\+?)$")
+            .unwrap();
+    let feat_re = Regex::new(r"(?i)^(?:the )?(?P<feat>.+) feat$").unwrap();
+    let parse_number = |number: &str| -> Result<u8> {
+        number.parse().map_err(|_| {
+            ParseError::new_with_problem(prerequisites, "Feat prerequisites", "Invalid number")
+                .into()
+        })
+    };
+
+    let mut result = Prerequisites::default();
+    if let Some(captures) = ability_re.captures(prerequisites) {
+        let score = parse_number(&captures["score"])?;
+        result.abilities = parse_abilities(&captures["abilities"])?
+            .into_iter()
+            .map(|ability| (ability, score))
+            .collect();
+    }
+    let rest = ability_re.replace(prerequisites, "");
+    for prerequisite in split_top_level(&rest)
+        .into_iter()
+        .map(|prerequisite| prerequisite.trim_start_matches("and ").trim())
+        .filter(|prerequisite| !prerequisite.is_empty())
+    {
+        let lowercase = prerequisite.to_lowercase();
+        if let Some(captures) = level_re.captures(prerequisite) {
+            let level = captures
+                .name("ordinal")
+                .or_else(|| captures.name("level"))
+                .map_or("", |level| level.as_str());
+            result.level = Some(parse_number(level)?);
+        } else if lowercase.contains("cast at least one spell")
+            || lowercase.contains("spellcasting")
+        {
+            result.spellcasting = true;
+        } else if let Some(captures) = feat_re.captures(prerequisite) {
+            result.feats.push(captures["feat"].to_lowercase());
+        } else if lowercase
+            .split(" or ")
+            .all(|race| RACES.contains(&race.trim()))
+        {
+            result
+                .races
+                .extend(lowercase.split(" or ").map(|race| race.trim().to_string()));
+        } else {
+            result.other.push(prerequisite.to_string());
+        }
+    }
+    Ok(result)
+}
+
+/// Parse the ability score increase of a feat, e.g. `Increase your Strength or Dexterity by 1`
+/// or `Increase one ability score of your choice by 1`.
+fn parse_ability_increase(text: &str) -> Result<Option<AbilityIncrease>> {
+    let increase_re = Regex::new(&format!(
+        r"(?i)\bincrease (?:your |one )?(?P<abilities>ability score of your choice|(?:{ABILITIES})(?:,? (?:or |and )?(?:{ABILITIES}))*)(?: scores?)? by (?P<amount>\d+)"
+    ))
+    .unwrap();
+    let Some(captures) = increase_re.captures(text) else {
+        return Ok(None);
+    };
+    let amount = captures["amount"]
+        .parse()
+        .map_err(|_| ParseError::new(&captures["amount"], "Feat ability increase"))?;
+    let abilities = &captures["abilities"];
+    if abilities.eq_ignore_ascii_case("ability score of your choice") {
+        return Ok(Some(AbilityIncrease::Choose {
+            from: [
+                AbilityScore::Strength,
+                AbilityScore::Dexterity,
+                AbilityScore::Constitution,
+                AbilityScore::Intelligence,
+                AbilityScore::Wisdom,
+                AbilityScore::Charisma,
+            ]
+            .to_vec(),
+            amount,
+        }));
+    }
+    let from = parse_abilities(abilities)?;
+    Ok(Some(if abilities.contains(" or ") {
+        AbilityIncrease::Choose { from, amount }
+    } else {
+        AbilityIncrease::Fixed(
+            from.into_iter()
+                .map(|ability| (ability, amount))
+                .collect_vec(),
+        )
+    }))
+}
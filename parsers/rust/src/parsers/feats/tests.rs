@@ -0,0 +1,155 @@
+use std::fs;
+
+use serde_json::Value;
+
+use crate::{
+    models::{
+        common::{AbilityScore, Source},
+        feats::{AbilityIncrease, FeatCategory, Prerequisites},
+    },
+    utils::{compare::json_compare, traits::To5etools},
+};
+
+use super::{
+    extract_feats, parse_ability_increase, parse_feat_line, parse_feats, parse_prerequisites,
+};
+
+const SOURCE: Source = Source {
+    source_book: "test-source",
+    page: 0,
+};
+
+#[test]
+fn extract_feats_test() {
+    let document = fs::read_to_string("resources/test/feats/unit_input.md").unwrap();
+    let feats = extract_feats(&document);
+    assert_eq!(feats.len(), 5);
+    assert_eq!(
+        feats[3],
+        vec![
+            "### Lucky Find",
+            "*Origin Feat*",
+            "Once per long rest, you can reroll one ability check.",
+        ]
+    );
+    // Eldritch invocations with prerequisites are optional features.
+    let document = fs::read_to_string("resources/test/optional_features/unit_input.md").unwrap();
+    assert!(extract_feats(&document).is_empty());
+    // A feat named after a type of optional features is still a feat.
+    let document = "### Metamagic Adept\n*Prerequisite: Spellcasting or Pact Magic feature*\n\nYou gain two Metamagic options.";
+    assert_eq!(
+        extract_feats(document),
+        vec![vec![
+            "### Metamagic Adept",
+            "*Prerequisite: Spellcasting or Pact Magic feature*",
+            "You gain two Metamagic options.",
+        ]]
+    );
+}
+
+#[test]
+fn feat_line() {
+    assert_eq!(
+        parse_feat_line("Prerequisite: 4th level"),
+        Ok((None, Some("4th level")))
+    );
+    assert_eq!(
+        parse_feat_line("Origin Feat"),
+        Ok((Some(FeatCategory::Origin), None))
+    );
+    assert_eq!(
+        parse_feat_line("Epic Boon Feat (Prerequisite: Level 19+)"),
+        Ok((Some(FeatCategory::EpicBoon), Some("Level 19+")))
+    );
+    assert!(parse_feat_line("Uncommon feat").is_err());
+}
+
+#[test]
+fn prerequisites() {
+    assert_eq!(
+        parse_prerequisites("4th level, Strength or Dexterity 13 or higher"),
+        Ok(Prerequisites {
+            level: Some(4),
+            abilities: vec![(AbilityScore::Strength, 13), (AbilityScore::Dexterity, 13)],
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        parse_prerequisites("Level 4+, Intelligence, Wisdom, or Charisma 13+"),
+        Ok(Prerequisites {
+            level: Some(4),
+            abilities: vec![
+                (AbilityScore::Intelligence, 13),
+                (AbilityScore::Wisdom, 13),
+                (AbilityScore::Charisma, 13)
+            ],
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        parse_prerequisites("Dwarf or Half-Orc, the ability to cast at least one spell"),
+        Ok(Prerequisites {
+            races: vec!["dwarf".to_string(), "half-orc".to_string()],
+            spellcasting: true,
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        parse_prerequisites("Grappler feat, proficiency with heavy armor"),
+        Ok(Prerequisites {
+            feats: vec!["grappler".to_string()],
+            other: vec!["proficiency with heavy armor".to_string()],
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn ability_increase() {
+    assert_eq!(
+        parse_ability_increase("Increase your Strength or Dexterity by 1, to a maximum of 20."),
+        Ok(Some(AbilityIncrease::Choose {
+            from: vec![AbilityScore::Strength, AbilityScore::Dexterity],
+            amount: 1
+        }))
+    );
+    assert_eq!(
+        parse_ability_increase("Increase your Intelligence, Wisdom, or Charisma score by 1."),
+        Ok(Some(AbilityIncrease::Choose {
+            from: vec![
+                AbilityScore::Intelligence,
+                AbilityScore::Wisdom,
+                AbilityScore::Charisma
+            ],
+            amount: 1
+        }))
+    );
+    assert_eq!(
+        parse_ability_increase("Increase your Constitution score by 1."),
+        Ok(Some(AbilityIncrease::Fixed(vec![(
+            AbilityScore::Constitution,
+            1
+        )])))
+    );
+    assert_eq!(
+        parse_ability_increase("Increase your Strength and Constitution scores by 1."),
+        Ok(Some(AbilityIncrease::Fixed(vec![
+            (AbilityScore::Strength, 1),
+            (AbilityScore::Constitution, 1)
+        ])))
+    );
+    assert_eq!(parse_ability_increase("You can read minds."), Ok(None));
+}
+
+#[test]
+fn feats() {
+    let document = fs::read_to_string("resources/test/feats/unit_input.md").unwrap();
+    let feats = parse_feats(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let expected: Value =
+        serde_json::from_str(&fs::read_to_string("resources/test/feats/unit_output.json").unwrap())
+            .unwrap();
+    json_compare(feats.to_5etools_base(), expected).unwrap();
+}
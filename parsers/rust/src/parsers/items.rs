@@ -0,0 +1,781 @@
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{
+    models::{
+        common::{Classes, DamageType, Source},
+        items::{
+            ArmorStats, Attunement, BaseItemReference, Item, ItemBonus, ItemProperty, ItemType,
+            ItemValue, Rarity, Recharge, RechargePeriod, WeaponCategory, WeaponDamage,
+            WeaponProperty, WeaponStats,
+        },
+    },
+    utils::{
+        error::{Error, OutOfBoundsError, ParseError, Result},
+        markdown::{
+            extract_blocks, heading_name, is_table_separator, parse_entries, split_table_row,
+            split_top_level, strip_emphasis,
+        },
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+type BaseItem = Option<String>;
+type BonusConstructor = fn(i8) -> ItemBonus;
+type WeaponSection = (Option<WeaponCategory>, Option<ItemType>);
+type Range = Option<(u16, u16)>;
+
+/// Armor from the Player's Handbook, by armor type.
+const ARMOR: [(&str, ItemType); 13] = [
+    ("padded", ItemType::LightArmor),
+    ("leather", ItemType::LightArmor),
+    ("studded leather", ItemType::LightArmor),
+    ("hide", ItemType::MediumArmor),
+    ("chain shirt", ItemType::MediumArmor),
+    ("scale mail", ItemType::MediumArmor),
+    ("breastplate", ItemType::MediumArmor),
+    ("half plate", ItemType::MediumArmor),
+    ("ring mail", ItemType::HeavyArmor),
+    ("chain mail", ItemType::HeavyArmor),
+    ("splint", ItemType::HeavyArmor),
+    ("plate", ItemType::HeavyArmor),
+    ("shield", ItemType::Shield),
+];
+
+/// Weapons from the Player's Handbook. Weapons not listed as ranged are melee weapons.
+const WEAPONS: [&str; 37] = [
+    "club",
+    "dagger",
+    "greatclub",
+    "handaxe",
+    "javelin",
+    "light hammer",
+    "mace",
+    "quarterstaff",
+    "sickle",
+    "spear",
+    "light crossbow",
+    "dart",
+    "shortbow",
+    "sling",
+    "battleaxe",
+    "flail",
+    "glaive",
+    "greataxe",
+    "greatsword",
+    "halberd",
+    "lance",
+    "longsword",
+    "maul",
+    "morningstar",
+    "pike",
+    "rapier",
+    "scimitar",
+    "shortsword",
+    "trident",
+    "war pick",
+    "warhammer",
+    "whip",
+    "blowgun",
+    "hand crossbow",
+    "heavy crossbow",
+    "longbow",
+    "net",
+];
+
+const RANGED_WEAPONS: [&str; 9] = [
+    "light crossbow",
+    "dart",
+    "shortbow",
+    "sling",
+    "blowgun",
+    "hand crossbow",
+    "heavy crossbow",
+    "longbow",
+    "net",
+];
+
+/// Parse all magic items in a GM Binder or Homebrewery document.
+///
+/// An item is a heading followed by an italic line with its type and rarity, e.g.
+/// `*Weapon (longsword), rare (requires attunement)*`.
+#[must_use]
+pub fn parse_items<'a>(document: &str, source: &Source<'a>) -> Vec<Result<Item<'a>>> {
+    // Magic items can be based on the mundane items in the equipment tables of the document.
+    let base_items = parse_equipment_tables(document, source)
+        .into_iter()
+        .flatten()
+        .collect_vec();
+    extract_items(document)
+        .into_iter()
+        .map(|item| parse_item(&item, source.clone(), &base_items))
+        .collect()
+}
+
+fn is_type_line(line: &str) -> bool {
+    let type_re = Regex::new(
+        r"(?i)^(?:armor|weapon|wondrous item|potion|ring|rod|scroll|staff|wand|shield|ammunition)\b.*,",
+    )
+    .unwrap();
+    strip_emphasis(line).is_some_and(|line| type_re.is_match(line))
+}
+
+/// Split a document into items, each starting with its heading and type line.
+fn extract_items(document: &str) -> Vec<Vec<String>> {
+    extract_blocks(document, is_type_line)
+}
+
+/// Parse a single item.
+///
+/// * `item` - Lines of the item: heading, type line and entries
+/// * `source` - Source book of the item
+/// * `base_items` - Mundane items of the same document
+///
+/// Returns: The parsed item.
+fn parse_item<'a>(item: &[String], source: Source<'a>, base_items: &[Item]) -> Result<Item<'a>> {
+    let [heading, type_line, entries @ ..] = item else {
+        return Err(OutOfBoundsError {
+            array: item.to_vec(),
+            index: 1,
+            parsing_step: "Item".to_string(),
+            problem: Some("Expected a heading and a type line".to_string()),
+        }
+        .into());
+    };
+    let name = heading_name(heading);
+    let (item_type, base_item, rarity, attunement) = parse_type_line(
+        strip_emphasis(type_line).ok_or_else(|| {
+            ParseError::new_with_problem(type_line, "Item type", "Type line is not emphasized")
+        })?,
+        base_items,
+    )?;
+
+    let property_re =
+        Regex::new(r"(?i)^\**(?P<property>weight|cost|value|price):?\**:?\s*(?P<value>.+)$")
+            .unwrap();
+    let mut weight = None;
+    let mut value = None;
+    let mut text = vec![];
+    for line in entries {
+        match property_re.captures(line) {
+            Some(captures) if captures["property"].eq_ignore_ascii_case("weight") => {
+                weight = Some(parse_weight(&captures["value"])?);
+            }
+            Some(captures) => value = Some(captures["value"].try_into()?),
+            None => text.push(line.as_str()),
+        }
+    }
+    let text = text.join("\n");
+
+    let base_item = base_item.map(|name| BaseItemReference {
+        source: base_items
+            .iter()
+            .any(|item| item.name.eq_ignore_ascii_case(&name))
+            .then(|| source.clone()),
+        name,
+    });
+    Ok(Item {
+        name,
+        source,
+        item_type,
+        base_item,
+        rarity,
+        attunement,
+        weight,
+        value,
+        charges: parse_charges(&text)?,
+        recharge: parse_recharge(&text),
+        bonuses: parse_bonuses(&text)?,
+        weapon: None,
+        armor: None,
+        entries: parse_entries(&text),
+    })
+}
+
+/// Parse the type line of an item, e.g. `Armor (plate), very rare (requires attunement)`.
+///
+/// * `type_line` - The type line without emphasis
+/// * `base_items` - Mundane items of the same document, which items can be based on in
+///   addition to those of the PHB
+///
+/// Returns: The item type, base item, rarity and attunement.
+fn parse_type_line(
+    type_line: &str,
+    base_items: &[Item],
+) -> Result<(ItemType, BaseItem, Rarity, Option<Attunement>)> {
+    let type_line_re = Regex::new(
+        r"(?i)^(?P<type>[a-z ]+?)(?: \((?P<base>[^)]+)\))?, (?P<rarity>[a-z ]+?)(?: \((?P<attunement>requires attunement[^)]*)\))?$",
+    )
+    .unwrap();
+    let captures = type_line_re
+        .captures(type_line.trim())
+        .ok_or_else(|| ParseError::new_with_problem(type_line, "Item type", "Invalid format"))?;
+    let base = captures
+        .name("base")
+        .map(|base| base.as_str().to_lowercase());
+    let (item_type, base_item) = parse_item_type(
+        &captures["type"].to_lowercase(),
+        base.as_deref(),
+        base_items,
+    )?;
+    Ok((
+        item_type,
+        base_item,
+        captures["rarity"].try_into()?,
+        captures
+            .name("attunement")
+            .map(|attunement| parse_attunement(attunement.as_str())),
+    ))
+}
+
+/// Returns: The item type and the base item if it is a known mundane item.
+fn parse_item_type(
+    item_type: &str,
+    base: Option<&str>,
+    base_items: &[Item],
+) -> Result<(ItemType, BaseItem)> {
+    let error = || ParseError::new_with_problem(item_type, "Item type", "Unknown item type");
+    if let Some(base_item) = base.and_then(|base| {
+        base_items
+            .iter()
+            .find(|item| item.name.eq_ignore_ascii_case(base))
+    }) {
+        return Ok((base_item.item_type, Some(base_item.name.to_lowercase())));
+    }
+    match (item_type, base) {
+        ("armor", Some(base)) => {
+            let armor_type = ARMOR
+                .iter()
+                .find(|(armor, _)| *armor == base)
+                .map(|(_, armor_type)| *armor_type)
+                .or_else(|| {
+                    // Generic armor, e.g. `any medium or heavy`.
+                    [
+                        ("light", ItemType::LightArmor),
+                        ("medium", ItemType::MediumArmor),
+                        ("heavy", ItemType::HeavyArmor),
+                    ]
+                    .into_iter()
+                    .find(|(weight, _)| base.contains(weight))
+                    .map(|(_, armor_type)| armor_type)
+                })
+                .ok_or_else(error)?;
+            Ok((
+                armor_type,
+                ARMOR
+                    .iter()
+                    .any(|(armor, _)| *armor == base)
+                    .then(|| base.to_string()),
+            ))
+        }
+        ("weapon", Some(base)) => Ok((
+            if RANGED_WEAPONS.contains(&base) {
+                ItemType::RangedWeapon
+            } else {
+                ItemType::MeleeWeapon
+            },
+            WEAPONS.contains(&base).then(|| base.to_string()),
+        )),
+        ("weapon", None) => Ok((ItemType::MeleeWeapon, None)),
+        ("shield", _) => Ok((ItemType::Shield, Some("shield".to_string()))),
+        ("ammunition", _) => Ok((ItemType::Ammunition, None)),
+        ("wondrous item", _) => Ok((ItemType::WondrousItem, None)),
+        ("potion", _) => Ok((ItemType::Potion, None)),
+        ("ring", _) => Ok((ItemType::Ring, None)),
+        ("rod", _) => Ok((ItemType::Rod, None)),
+        ("scroll", _) => Ok((ItemType::Scroll, None)),
+        ("staff", _) => Ok((ItemType::Staff, None)),
+        ("wand", _) => Ok((ItemType::Wand, None)),
+        _ => Err(error().into()),
+    }
+}
+
+/// Parse the attunement requirement, e.g. `requires attunement by a sorcerer, warlock, or
+/// wizard`.
+fn parse_attunement(attunement: &str) -> Attunement {
+    // The type line regex guarantees the case-insensitive `requires attunement` prefix.
+    let prerequisite = attunement
+        .get("requires attunement".len()..)
+        .unwrap_or_default()
+        .trim();
+    if prerequisite.is_empty() {
+        return Attunement::Required;
+    }
+    let classes = prerequisite
+        .trim_start_matches("by a ")
+        .trim_start_matches("by an ")
+        .split([',', ' '])
+        .filter_map(|word| Classes::try_from(word).ok())
+        .collect();
+    Attunement::RequiredBy {
+        prerequisite: prerequisite.to_string(),
+        classes,
+    }
+}
+
+/// Parse a weight, e.g. `3 lb.` or `1/2 lb.`.
+fn parse_weight(weight: &str) -> Result<f32> {
+    let error = || -> Error { ParseError::new(weight, "Item weight").into() };
+    let number = weight
+        .split_whitespace()
+        .next()
+        .ok_or_else(error)?
+        .replace(',', "");
+    match number.split_once('/') {
+        Some((numerator, denominator)) => Ok(numerator.parse::<f32>().map_err(|_| error())?
+            / denominator.parse::<f32>().map_err(|_| error())?),
+        None => number.parse().map_err(|_| error()),
+    }
+}
+
+fn parse_charges(text: &str) -> Result<Option<u8>> {
+    let charges_re = Regex::new(r"(?i)\bhas (?P<charges>\d+) charges\b").unwrap();
+    charges_re
+        .captures(text)
+        .map(|captures| {
+            captures["charges"]
+                .parse()
+                .map_err(|_| ParseError::new(&captures["charges"], "Item charges").into())
+        })
+        .transpose()
+}
+
+fn parse_recharge(text: &str) -> Option<Recharge> {
+    let recharge_re = Regex::new(
+        r"(?i)\bregains? (?:(?P<all>all)|(?P<amount>\d+d\d+(?: ?[+-] ?\d+)?|\d+)) (?:of its )?(?:expended |spent )?charges?(?: daily)? (?:at|after)(?: the next)? (?:a )?(?P<period>dawn|dusk|midnight|short rest|long rest)",
+    )
+    .unwrap();
+    let captures = recharge_re.captures(text)?;
+    Some(Recharge {
+        period: match &captures["period"].to_lowercase()[..] {
+            "dusk" => RechargePeriod::Dusk,
+            "midnight" => RechargePeriod::Midnight,
+            "short rest" => RechargePeriod::ShortRest,
+            "long rest" => RechargePeriod::LongRest,
+            _ => RechargePeriod::Dawn,
+        },
+        amount: captures
+            .name("amount")
+            .map(|amount| amount.as_str().to_string()),
+    })
+}
+
+fn parse_bonuses(text: &str) -> Result<Vec<ItemBonus>> {
+    let patterns: [(&str, BonusConstructor); 5] = [
+        (r"bonus to attack and damage rolls", ItemBonus::Weapon),
+        (
+            r"bonus to (?:your )?(?:AC|Armor Class)",
+            ItemBonus::ArmorClass,
+        ),
+        (r"bonus to (?:all )?saving throws", ItemBonus::SavingThrow),
+        (r"bonus to spell attack rolls", ItemBonus::SpellAttack),
+        (
+            r"bonus to (?:the )?(?:your )?spell save DC",
+            ItemBonus::SpellSaveDc,
+        ),
+    ];
+    patterns
+        .into_iter()
+        .filter_map(|(pattern, bonus)| {
+            let bonus_re = Regex::new(&format!(r"(?i)\+(?P<bonus>\d+) {pattern}")).unwrap();
+            bonus_re.captures(text).map(|captures| {
+                captures["bonus"]
+                    .parse()
+                    .map(bonus)
+                    .map_err(|_| ParseError::new(&captures["bonus"], "Item bonus").into())
+            })
+        })
+        .collect()
+}
+
+/// Kind of an equipment table, decided by its header row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EquipmentTable {
+    Weapons,
+    Armor,
+}
+
+/// Parse all weapon and armor tables in a GM Binder or Homebrewery document into base items.
+///
+/// Weapon tables have a `Damage` column, armor tables an `Armor Class` or `AC` column. Rows
+/// with a single emphasized cell, e.g. `*Martial Ranged Weapons*`, start a section of the
+/// table and set the category or type of the following items.
+pub fn parse_equipment_tables<'a>(document: &str, source: &Source<'a>) -> Vec<Result<Item<'a>>> {
+    let mut items = vec![];
+    let mut table: Option<(EquipmentTable, Vec<String>)> = None;
+    let mut weapon_section: WeaponSection = (None, None);
+    let mut armor_section = None;
+    for line in document.lines().map(str::trim) {
+        if !line.starts_with('|') {
+            table = None;
+            continue;
+        }
+        let cells = split_table_row(line);
+        if is_table_separator(&cells) {
+            continue;
+        }
+        let Some((kind, header)) = &table else {
+            // Column names without notes, e.g. `Armor Class (AC)`.
+            let header = cells
+                .iter()
+                .map(|cell| {
+                    cell.split(" (")
+                        .next()
+                        .unwrap_or_default()
+                        .trim()
+                        .to_lowercase()
+                })
+                .collect_vec();
+            table = if header.iter().any(|column| column == "damage") {
+                Some((EquipmentTable::Weapons, header))
+            } else if header
+                .iter()
+                .any(|column| column == "armor class" || column == "ac")
+            {
+                Some((EquipmentTable::Armor, header))
+            } else {
+                None
+            };
+            weapon_section = (None, None);
+            armor_section = None;
+            continue;
+        };
+        if let Some(section) = cells
+            .first()
+            .filter(|_| cells.iter().skip(1).all(String::is_empty))
+            .and_then(|cell| strip_emphasis(cell))
+        {
+            match kind {
+                EquipmentTable::Weapons => weapon_section = parse_weapon_section(section),
+                EquipmentTable::Armor => armor_section = parse_armor_section(section),
+            }
+            continue;
+        }
+        let row = header
+            .iter()
+            .map(String::as_str)
+            .zip(cells.iter().map(String::as_str))
+            .collect_vec();
+        items.push(match kind {
+            EquipmentTable::Weapons => parse_weapon_row(&row, weapon_section, source.clone()),
+            EquipmentTable::Armor => parse_armor_row(&row, armor_section, source.clone()),
+        });
+    }
+    items
+}
+
+/// Parse a weapon table section, e.g. `Simple Melee Weapons`.
+fn parse_weapon_section(section: &str) -> WeaponSection {
+    let section = section.to_lowercase();
+    (
+        if section.contains("simple") {
+            Some(WeaponCategory::Simple)
+        } else if section.contains("martial") {
+            Some(WeaponCategory::Martial)
+        } else {
+            None
+        },
+        if section.contains("ranged") {
+            Some(ItemType::RangedWeapon)
+        } else if section.contains("melee") {
+            Some(ItemType::MeleeWeapon)
+        } else {
+            None
+        },
+    )
+}
+
+/// Parse an armor table section, e.g. `Light Armor`.
+fn parse_armor_section(section: &str) -> Option<ItemType> {
+    let section = section.to_lowercase();
+    [
+        ("light", ItemType::LightArmor),
+        ("medium", ItemType::MediumArmor),
+        ("heavy", ItemType::HeavyArmor),
+        ("shield", ItemType::Shield),
+    ]
+    .into_iter()
+    .find(|(name, _)| section.contains(name))
+    .map(|(_, item_type)| item_type)
+}
+
+/// Get the cell of a table row by column name, treating `—` as an empty cell.
+fn table_cell<'b>(row: &[(&str, &'b str)], columns: &[&str]) -> Option<&'b str> {
+    row.iter()
+        .find(|(column, _)| columns.contains(column))
+        .map(|(_, cell)| *cell)
+        .filter(|cell| !cell.is_empty() && !["—", "–", "-"].contains(cell))
+}
+
+/// Parse the columns that weapon and armor tables have in common.
+///
+/// Returns: The name, weight and value of the item.
+fn parse_common_cells(row: &[(&str, &str)]) -> Result<(String, Option<f32>, Option<ItemValue>)> {
+    let name = row
+        .first()
+        .map(|(_, name)| (*name).to_string())
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| ParseError::new_with_problem("", "Equipment table", "Missing item name"))?;
+    Ok((
+        name,
+        table_cell(row, &["weight"]).map(parse_weight).transpose()?,
+        table_cell(row, &["cost", "price", "value"])
+            .map(ItemValue::try_from)
+            .transpose()?,
+    ))
+}
+
+const fn mundane_item(
+    name: String,
+    source: Source,
+    item_type: ItemType,
+    weight: Option<f32>,
+    value: Option<ItemValue>,
+) -> Item {
+    Item {
+        name,
+        source,
+        item_type,
+        base_item: None,
+        rarity: Rarity::None,
+        attunement: None,
+        weight,
+        value,
+        charges: None,
+        recharge: None,
+        bonuses: vec![],
+        weapon: None,
+        armor: None,
+        entries: vec![],
+    }
+}
+
+/// Parse a row of a weapon table, e.g. `| Longbow | 50 gp | 1d8 piercing | 2 lb. | Ammunition
+/// (range 150/600), heavy, two-handed |`.
+fn parse_weapon_row<'a>(
+    row: &[(&str, &str)],
+    (category, section_type): WeaponSection,
+    source: Source<'a>,
+) -> Result<Item<'a>> {
+    let (name, weight, value) = parse_common_cells(row)?;
+    let damage = table_cell(row, &["damage"])
+        .map(parse_weapon_damage)
+        .transpose()?;
+    let (properties, versatile_damage, range) =
+        parse_weapon_properties(table_cell(row, &["properties", "property"]).unwrap_or_default());
+    let item_type = section_type.unwrap_or_else(|| {
+        if properties.contains(&WeaponProperty::Ammunition) {
+            ItemType::RangedWeapon
+        } else {
+            ItemType::MeleeWeapon
+        }
+    });
+    Ok(Item {
+        weapon: Some(WeaponStats {
+            category,
+            damage,
+            versatile_damage,
+            range,
+            properties,
+        }),
+        ..mundane_item(name, source, item_type, weight, value)
+    })
+}
+
+/// Parse the damage of a weapon, e.g. `1d8 slashing` or `1 piercing`.
+fn parse_weapon_damage(damage: &str) -> Result<WeaponDamage> {
+    let (dice, damage_type) = damage
+        .trim()
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new_with_problem(damage, "Weapon damage", "Missing type"))?;
+    Ok(WeaponDamage {
+        dice: dice.to_string(),
+        damage_type: DamageType::try_from(damage_type.trim().to_lowercase().as_str())?,
+    })
+}
+
+/// Parse the properties of a weapon, e.g. `Finesse, thrown (range 20/60), versatile (1d10)`.
+///
+/// Properties that are not in the Player's Handbook become custom properties.
+///
+/// Returns: The properties, the versatile damage and the range.
+fn parse_weapon_properties(properties: &str) -> (Vec<WeaponProperty>, Option<String>, Range) {
+    let range_re = Regex::new(r"(?i)\brange (?P<normal>\d+)/(?P<long>\d+)").unwrap();
+    let versatile_re = Regex::new(r"(?i)^versatile \((?P<dice>[^)]+)\)").unwrap();
+    let mut range = None;
+    let mut versatile_damage = None;
+    let properties = split_top_level(properties)
+        .into_iter()
+        .filter(|property| !property.is_empty())
+        .filter_map(|property| {
+            if let Some(captures) = range_re.captures(property) {
+                range = captures["normal"]
+                    .parse()
+                    .ok()
+                    .zip(captures["long"].parse().ok());
+            }
+            if let Some(captures) = versatile_re.captures(property) {
+                versatile_damage = Some(captures["dice"].to_string());
+            }
+            let name = property
+                .split_once(" (")
+                .map_or(property, |(name, _)| name)
+                .trim();
+            match &name.to_lowercase()[..] {
+                "ammunition" => Some(WeaponProperty::Ammunition),
+                "finesse" => Some(WeaponProperty::Finesse),
+                "heavy" => Some(WeaponProperty::Heavy),
+                "light" => Some(WeaponProperty::Light),
+                "loading" => Some(WeaponProperty::Loading),
+                "reach" => Some(WeaponProperty::Reach),
+                "special" => Some(WeaponProperty::Special),
+                "thrown" => Some(WeaponProperty::Thrown),
+                "two-handed" => Some(WeaponProperty::TwoHanded),
+                "versatile" => Some(WeaponProperty::Versatile),
+                // A range on its own is not a property.
+                "range" => None,
+                _ if range_re.is_match(name) => None,
+                _ => Some(WeaponProperty::Custom(custom_item_property(name))),
+            }
+        })
+        .collect();
+    (properties, versatile_damage, range)
+}
+
+/// Create a homebrew property, abbreviated by the first three letters of its name.
+fn custom_item_property(name: &str) -> ItemProperty {
+    let mut characters = name.chars();
+    let name = characters.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(characters).collect()
+    });
+    ItemProperty {
+        abbreviation: name
+            .chars()
+            .filter(char::is_ascii_alphabetic)
+            .take(3)
+            .collect::<String>()
+            .to_uppercase(),
+        name,
+    }
+}
+
+/// Parse a row of an armor table, e.g. `| Half plate | 750 gp | 15 + Dex modifier (max 2) | — |
+/// Disadvantage | 40 lb. |`.
+fn parse_armor_row<'a>(
+    row: &[(&str, &str)],
+    section_type: Option<ItemType>,
+    source: Source<'a>,
+) -> Result<Item<'a>> {
+    let (name, weight, value) = parse_common_cells(row)?;
+    let armor_class_cell = table_cell(row, &["armor class", "ac"]).unwrap_or_default();
+    let (armor_class, armor_type) = parse_armor_class_formula(armor_class_cell)?;
+    let item_type = section_type.unwrap_or_else(|| {
+        if name.eq_ignore_ascii_case("shield") {
+            ItemType::Shield
+        } else {
+            armor_type
+        }
+    });
+    let strength = table_cell(row, &["strength"])
+        .map(|strength| -> Result<u8> {
+            Ok(strength
+                .trim_start_matches("Str")
+                .trim_start_matches("STR")
+                .trim()
+                .parse()
+                .map_err(|_| ParseError::new(strength, "Armor strength"))?)
+        })
+        .transpose()?;
+    Ok(Item {
+        armor: Some(ArmorStats {
+            armor_class,
+            strength,
+            stealth_disadvantage: table_cell(row, &["stealth"])
+                .is_some_and(|stealth| stealth.to_lowercase().contains("disadvantage")),
+        }),
+        ..mundane_item(name, source, item_type, weight, value)
+    })
+}
+
+/// Parse an armor class formula, e.g. `12 + Dex modifier (max 2)` or `+2`.
+///
+/// Returns: The base armor class and the armor type implied by the formula.
+fn parse_armor_class_formula(formula: &str) -> Result<(u8, ItemType)> {
+    let formula_re = Regex::new(
+        r"(?i)^(?P<shield>\+)?(?P<ac>\d+)(?P<dex> \+ Dex(?:terity)? modifier(?P<max> \(max 2\))?)?$",
+    )
+    .unwrap();
+    let captures = formula_re
+        .captures(formula.trim())
+        .ok_or_else(|| ParseError::new_with_problem(formula, "Armor class", "Invalid format"))?;
+    let armor_type = if captures.name("shield").is_some() {
+        ItemType::Shield
+    } else if captures.name("max").is_some() {
+        ItemType::MediumArmor
+    } else if captures.name("dex").is_some() {
+        ItemType::LightArmor
+    } else {
+        ItemType::HeavyArmor
+    };
+    Ok((
+        captures["ac"]
+            .parse()
+            .map_err(ParseError::from_intparse_error(
+                formula.to_string(),
+                "Armor class".to_string(),
+            ))?,
+        armor_type,
+    ))
+}
+
+/// Parse an amount of coins, e.g. `500 gp`, `1,500 gp` or `2 gp 5 sp`. Text after the amount,
+/// e.g. `each`, is ignored.
+impl TryFrom<&str> for ItemValue {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self> {
+        let amounts_re =
+            Regex::new(r"(?i)^(?:\d[\d,]* ?(?:cp|sp|ep|gp|pp)\b(?:,? (?:and )?)?)+").unwrap();
+        let coins_re = Regex::new(r"(?i)(?P<value>\d[\d,]*) ?(?P<unit>cp|sp|ep|gp|pp)\b").unwrap();
+        let amounts = amounts_re
+            .find(value.trim())
+            .ok_or_else(|| ParseError::new(value, "Item value"))?;
+        coins_re
+            .captures_iter(amounts.as_str())
+            .map(|captures| -> Result<Self> {
+                Ok(Self {
+                    value: captures["value"].replace(',', "").parse().map_err(
+                        ParseError::from_intparse_error(
+                            value.to_string(),
+                            "Item value".to_string(),
+                        ),
+                    )?,
+                    unit: captures["unit"].try_into()?,
+                })
+            })
+            .sum()
+    }
+}
+
+impl TryFrom<&str> for Rarity {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self> {
+        match &value.trim().to_lowercase()[..] {
+            "none" | "mundane" => Ok(Self::None),
+            "common" => Ok(Self::Common),
+            "uncommon" => Ok(Self::Uncommon),
+            "rare" => Ok(Self::Rare),
+            "very rare" => Ok(Self::VeryRare),
+            "legendary" => Ok(Self::Legendary),
+            "artifact" => Ok(Self::Artifact),
+            "rarity varies" | "varies" => Ok(Self::Varies),
+            _ => Err(ParseError {
+                string: value.to_string(),
+                parsing_step: "Rarity".to_string(),
+                problem: None,
+            }
+            .into()),
+        }
+    }
+}
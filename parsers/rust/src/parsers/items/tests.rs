@@ -0,0 +1,279 @@
+use std::fs;
+
+use serde_json::{json, Value};
+
+use crate::{
+    models::{
+        common::{Classes, DamageType, Source},
+        items::{
+            Attunement, Currency, Item, ItemBonus, ItemProperty, ItemType, ItemValue, Rarity,
+            Recharge, RechargePeriod, WeaponDamage, WeaponProperty,
+        },
+    },
+    utils::{compare::json_compare, traits::To5etools},
+};
+
+use super::{
+    extract_items, parse_armor_class_formula, parse_attunement, parse_bonuses,
+    parse_equipment_tables, parse_items, parse_recharge, parse_type_line, parse_weapon_damage,
+    parse_weapon_properties, parse_weight,
+};
+
+const SOURCE: Source = Source {
+    source_book: "test-source",
+    page: 0,
+};
+
+#[test]
+fn extract_items_test() {
+    let document = fs::read_to_string("resources/test/items/unit_input.md").unwrap();
+    let items = extract_items(&document);
+    assert_eq!(items.len(), 4);
+    assert_eq!(
+        items[1],
+        vec![
+            "### Frost Brand Longsword",
+            "*Weapon (longsword), rare (requires attunement)*",
+            "You gain a +1 bonus to attack and damage rolls made with this magic weapon.",
+            "**Weight:** 3 lb.",
+        ]
+    );
+}
+
+#[test]
+fn type_line() {
+    assert_eq!(
+        parse_type_line("Armor (plate), very rare (requires attunement)", &[]),
+        Ok((
+            ItemType::HeavyArmor,
+            Some("plate".to_string()),
+            Rarity::VeryRare,
+            Some(Attunement::Required)
+        ))
+    );
+
+    assert_eq!(
+        parse_type_line("Weapon (Any Sword), Rare", &[]),
+        Ok((ItemType::MeleeWeapon, None, Rarity::Rare, None))
+    );
+
+    assert_eq!(
+        parse_type_line("Armor (any medium or heavy), uncommon", &[]),
+        Ok((ItemType::MediumArmor, None, Rarity::Uncommon, None))
+    );
+
+    assert_eq!(
+        parse_type_line("Weapon (longbow), legendary", &[]),
+        Ok((
+            ItemType::RangedWeapon,
+            Some("longbow".to_string()),
+            Rarity::Legendary,
+            None
+        ))
+    );
+
+    assert_eq!(
+        parse_type_line("Wondrous item, rarity varies", &[]),
+        Ok((ItemType::WondrousItem, None, Rarity::Varies, None))
+    );
+
+    assert!(parse_type_line("Vehicle, rare", &[]).is_err());
+    assert!(parse_type_line("Ring, mythical", &[]).is_err());
+}
+
+#[test]
+fn attunement() {
+    assert_eq!(
+        parse_attunement("requires attunement"),
+        Attunement::Required
+    );
+    assert_eq!(
+        parse_attunement("Requires Attunement by a Cleric or Paladin"),
+        Attunement::RequiredBy {
+            prerequisite: "by a Cleric or Paladin".to_string(),
+            classes: vec![Classes::Cleric, Classes::Paladin],
+        }
+    );
+    assert_eq!(
+        parse_attunement("requires attunement by a creature of good alignment"),
+        Attunement::RequiredBy {
+            prerequisite: "by a creature of good alignment".to_string(),
+            classes: vec![],
+        }
+    );
+}
+
+#[test]
+fn recharge() {
+    assert_eq!(
+        parse_recharge("The wand regains 1d6 + 1 expended charges daily at dawn."),
+        Some(Recharge {
+            period: RechargePeriod::Dawn,
+            amount: Some("1d6 + 1".to_string()),
+        })
+    );
+    assert_eq!(
+        parse_recharge("The ring regains all expended charges at dusk."),
+        Some(Recharge {
+            period: RechargePeriod::Dusk,
+            amount: None,
+        })
+    );
+    assert_eq!(parse_recharge("The ring has no charges."), None);
+}
+
+#[test]
+fn bonuses() {
+    assert_eq!(
+        parse_bonuses(
+            "You gain a +2 bonus to AC and a +1 bonus to saving throws while wearing this armor."
+        ),
+        Ok(vec![ItemBonus::ArmorClass(2), ItemBonus::SavingThrow(1)])
+    );
+    assert_eq!(
+        parse_bonuses("You gain a +3 bonus to spell attack rolls."),
+        Ok(vec![ItemBonus::SpellAttack(3)])
+    );
+}
+
+#[test]
+fn weight_and_value() {
+    assert_eq!(parse_weight("3 lb."), Ok(3.0));
+    assert_eq!(parse_weight("1/2 lb."), Ok(0.5));
+    assert!(parse_weight("heavy").is_err());
+    assert_eq!(
+        ItemValue::try_from("1,500 gp"),
+        Ok(ItemValue {
+            value: 1500,
+            unit: Currency::Gold,
+        })
+    );
+    let value = ItemValue::try_from("2 gp 5 sp").unwrap();
+    assert_eq!((value.value, value.unit), (25, Currency::Silver));
+    assert_eq!(
+        ItemValue::try_from("1 pp, 3 gp and 4 cp each").map(ItemValue::in_copper),
+        Ok(1304)
+    );
+    assert_eq!(
+        ItemValue::try_from("5,000,000 pp").map(ItemValue::in_copper),
+        Ok(5_000_000_000)
+    );
+    assert!(ItemValue::try_from("priceless").is_err());
+}
+
+#[test]
+fn items() {
+    let document = fs::read_to_string("resources/test/items/unit_input.md").unwrap();
+    let expected: Value =
+        serde_json::from_str(&fs::read_to_string("resources/test/items/unit_output.json").unwrap())
+            .unwrap();
+    let items = parse_items(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(items[3].is_base_item());
+    json_compare(items.to_5etools_base(), expected).unwrap();
+}
+
+#[test]
+fn weapon_properties() {
+    assert_eq!(
+        parse_weapon_properties("Ammunition (range 80/320), loading, two-handed"),
+        (
+            vec![
+                WeaponProperty::Ammunition,
+                WeaponProperty::Loading,
+                WeaponProperty::TwoHanded
+            ],
+            None,
+            Some((80, 320))
+        )
+    );
+    assert_eq!(
+        parse_weapon_properties("Finesse, versatile (1d10), range 30/120"),
+        (
+            vec![WeaponProperty::Finesse, WeaponProperty::Versatile],
+            Some("1d10".to_string()),
+            Some((30, 120))
+        )
+    );
+    assert_eq!(
+        parse_weapon_properties("concealable"),
+        (
+            vec![WeaponProperty::Custom(ItemProperty {
+                name: "Concealable".to_string(),
+                abbreviation: "CON".to_string(),
+            })],
+            None,
+            None
+        )
+    );
+    assert_eq!(parse_weapon_properties(""), (vec![], None, None));
+    assert_eq!(
+        parse_weapon_damage("1d8 slashing").unwrap(),
+        WeaponDamage {
+            dice: "1d8".to_string(),
+            damage_type: DamageType::Slashing,
+        }
+    );
+    assert!(parse_weapon_damage("1d8").is_err());
+}
+
+#[test]
+fn armor_class_formula() {
+    assert_eq!(
+        parse_armor_class_formula("11 + Dex modifier").unwrap(),
+        (11, ItemType::LightArmor)
+    );
+    assert_eq!(
+        parse_armor_class_formula("14 + Dex modifier (max 2)").unwrap(),
+        (14, ItemType::MediumArmor)
+    );
+    assert_eq!(
+        parse_armor_class_formula("17").unwrap(),
+        (17, ItemType::HeavyArmor)
+    );
+    assert_eq!(
+        parse_armor_class_formula("+2").unwrap(),
+        (2, ItemType::Shield)
+    );
+    assert!(parse_armor_class_formula("very high").is_err());
+}
+
+#[test]
+fn equipment_tables() {
+    let document = fs::read_to_string("resources/test/items/equipment_input.md").unwrap();
+    let items = parse_equipment_tables(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(items.iter().all(Item::is_base_item));
+    let expected: Value = serde_json::from_str(
+        &fs::read_to_string("resources/test/items/equipment_output.json").unwrap(),
+    )
+    .unwrap();
+    json_compare(items.to_5etools_base(), expected).unwrap();
+}
+
+#[test]
+fn items_based_on_document_base_items() {
+    let document = format!(
+        "{}\n{}",
+        fs::read_to_string("resources/test/items/equipment_input.md").unwrap(),
+        "### Whispering Cane\n*Weapon (sword cane), rare*\n\nIt whispers.\n\n\
+         ### Blade of Testing\n*Weapon (shortsword), uncommon*\n\nIt tests."
+    );
+    let items = parse_items(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(items[0].item_type, ItemType::MeleeWeapon);
+    assert_eq!(
+        items[0].to_5etools_base()["baseItem"],
+        json!("sword cane|test-source")
+    );
+    assert_eq!(
+        items[1].to_5etools_base()["baseItem"],
+        json!("shortsword|phb")
+    );
+}
@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{
+    models::{
+        common::{NamedEntry, Source, StatusCondition},
+        creatures::{AbilityScores, DamageModifier, DamageModifierType, Size},
+        objects::{Object, ObjectType},
+    },
+    parsers::creatures::{
+        extract_stat_blocks, non_empty, parse_condition_immunities, parse_damage_modifier,
+        parse_named_entry, parse_third_group,
+    },
+    utils::{
+        error::{ParseError, Result},
+        markdown::parse_entries,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The parts of an object or vehicle stat block.
+pub(crate) struct StatBlockParts {
+    pub(crate) name: String,
+    /// The line below the name without emphasis and in lowercase, e.g. `large object`.
+    pub(crate) subtitle: String,
+    /// Property lines by their label in lowercase, e.g. `armor class` and `15`.
+    pub(crate) properties: HashMap<String, String>,
+    pub(crate) ability_scores: Option<AbilityScores>,
+    /// Lines before the first `###` heading that are neither properties nor the ability table.
+    pub(crate) description: Vec<String>,
+    /// Headings of the `###` sections with their lines.
+    pub(crate) sections: Vec<(String, Vec<String>)>,
+}
+
+impl StatBlockParts {
+    /// Lines of the section with the given heading in lowercase, e.g. `actions`.
+    pub(crate) fn section(&self, heading: &str) -> Option<&[String]> {
+        self.sections
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(heading))
+            .map(|(_, lines)| &lines[..])
+    }
+
+    /// Parse a property with the given label in lowercase, e.g. `damage immunities`.
+    pub(crate) fn parse_property<T>(
+        &self,
+        label: &str,
+        parser: fn(&str) -> Result<T>,
+    ) -> Result<Option<T>> {
+        self.properties
+            .get(label)
+            .map(|value| parser(value))
+            .transpose()
+    }
+
+    /// Parse a property that every stat block of its kind has, e.g. `armor class`.
+    pub(crate) fn parse_required_property<T>(
+        &self,
+        label: &str,
+        parsing_step: &str,
+        parser: fn(&str) -> Result<T>,
+    ) -> Result<T> {
+        self.parse_property(label, parser)?.ok_or_else(|| {
+            ParseError::new_with_problem(&self.name, parsing_step, &format!("No {label} found"))
+                .into()
+        })
+    }
+
+    /// Parse the damage resistances, immunities and vulnerabilities.
+    pub(crate) fn parse_damage_modifiers(&self) -> Result<[Option<Vec<DamageModifier>>; 3]> {
+        Ok([
+            self.parse_property("damage resistances", |line| {
+                parse_damage_modifier(DamageModifierType::Resistance, line)
+            })?,
+            self.parse_property("damage immunities", |line| {
+                parse_damage_modifier(DamageModifierType::Immunity, line)
+            })?,
+            self.parse_property("damage vulnerabilities", |line| {
+                parse_damage_modifier(DamageModifierType::Vulnerability, line)
+            })?,
+        ])
+    }
+
+    pub(crate) fn parse_condition_immunities(&self) -> Result<Option<Vec<StatusCondition>>> {
+        self.parse_property("condition immunities", parse_condition_immunities)
+    }
+}
+
+/// Parse a property line of a stat block, e.g. `- **Armor Class** 15` or `**Hit Points** 50`.
+///
+/// Returns: The label in lowercase and the value.
+pub(crate) fn parse_property_line(line: &str) -> Option<(String, String)> {
+    let property_re =
+        Regex::new(r"^(?:[-•] )?\*\*(?P<label>[^*]+?)[.:]?\*\*\s*(?P<value>.+)$").unwrap();
+    property_re.captures(line).map(|captures| {
+        (
+            captures["label"].trim().to_lowercase(),
+            captures["value"].trim().to_string(),
+        )
+    })
+}
+
+/// The line below the name of a stat block without emphasis and in lowercase, e.g.
+/// `large object` for `*Large object*`.
+pub(crate) fn stat_block_subtitle(stat_block: &[String]) -> Option<String> {
+    stat_block
+        .iter()
+        .filter(|line| !line.is_empty())
+        .nth(1)
+        .map(|line| line.replace(['*', '_'], "").trim().to_lowercase())
+}
+
+/// Split an object or vehicle stat block into its parts. Unlike creature stat blocks, the
+/// properties are not in a fixed order of `___` separated groups.
+///
+/// * `stat_block` - Lines of the stat block, with the leading `>` removed
+pub(crate) fn split_stat_block(stat_block: &[String]) -> Result<StatBlockParts> {
+    let mut lines = stat_block
+        .iter()
+        .filter(|line| !line.is_empty() && line.as_str() != "___");
+    let name = lines
+        .next()
+        .and_then(|line| line.strip_prefix("## "))
+        .ok_or_else(|| {
+            ParseError::new_with_problem(
+                &stat_block.join("\n"),
+                "Name",
+                "Name line does not start with `## `",
+            )
+        })?
+        .trim()
+        .to_string();
+    let subtitle = stat_block_subtitle(stat_block).unwrap_or_default();
+    let lines = lines.skip(1).collect_vec();
+
+    let (head, sections) = lines
+        .iter()
+        .position(|line| line.starts_with("### "))
+        .map_or((&lines[..], &[][..]), |start| lines.split_at(start));
+    let (table, head): (Vec<&String>, Vec<&String>) =
+        head.iter().partition(|line| line.starts_with('|'));
+    let (properties, description) = split_properties(head.into_iter().cloned());
+
+    let mut split_sections = vec![];
+    for line in sections {
+        match line.strip_prefix("### ") {
+            Some(heading) => split_sections.push((heading.trim().to_string(), vec![])),
+            None => {
+                if let Some((_, section)) = split_sections.last_mut() {
+                    section.push((*line).clone());
+                }
+            }
+        }
+    }
+
+    Ok(StatBlockParts {
+        name,
+        subtitle,
+        properties,
+        ability_scores: (!table.is_empty())
+            .then(|| parse_third_group(table.into_iter().cloned().collect()))
+            .transpose()?,
+        description,
+        sections: split_sections,
+    })
+}
+
+/// Split lines into the properties, by their label in lowercase, and the other lines.
+pub(crate) fn split_properties(
+    lines: impl IntoIterator<Item = String>,
+) -> (HashMap<String, String>, Vec<String>) {
+    let mut properties = HashMap::new();
+    let mut rest = vec![];
+    for line in lines {
+        match parse_property_line(&line) {
+            Some((label, value)) => {
+                properties.insert(label, value);
+            }
+            None => rest.push(line),
+        }
+    }
+    (properties, rest)
+}
+
+/// Parse `***Name.*** Text` paragraphs, e.g. the actions of a siege weapon.
+pub(crate) fn parse_named_entries(lines: &[String]) -> Result<Vec<NamedEntry>> {
+    format!("\n{}", lines.join("\n"))
+        .split("\n***")
+        .filter(|entry| !entry.trim().is_empty())
+        .map(parse_named_entry)
+        .collect()
+}
+
+/// Parse all object stat blocks, e.g. siege weapons, in a document.
+///
+/// Object stat blocks are written like creature stat blocks, with `object` in place of the
+/// creature type, e.g. `*Large object*`.
+#[must_use]
+pub fn parse_objects<'a>(document: &str, source: &Source<'a>) -> Vec<Result<Object<'a>>> {
+    extract_stat_blocks(document.to_string())
+        .into_iter()
+        .filter(|stat_block| is_object(stat_block))
+        .map(|stat_block| parse_object(&stat_block, source.clone()))
+        .collect()
+}
+
+pub(crate) fn is_object(stat_block: &[String]) -> bool {
+    let object_re = Regex::new(r"\bobject$").unwrap();
+    stat_block_subtitle(stat_block).is_some_and(|subtitle| object_re.is_match(&subtitle))
+}
+
+/// Parse the sizes in the line below the name of a stat block, e.g. `large or huge object`.
+pub(crate) fn parse_stat_block_sizes(subtitle: &str) -> Result<Vec<Size>> {
+    let size_re = Regex::new(r"^(?P<sizes>[a-z]+(?:(?:, | or )[a-z]+)*) ").unwrap();
+    let captures = size_re
+        .captures(subtitle)
+        .ok_or_else(|| ParseError::new_with_problem(subtitle, "Size", "No size found"))?;
+    captures["sizes"]
+        .split(", ")
+        .flat_map(|sizes| sizes.split(" or "))
+        .map(Size::try_from)
+        .collect()
+}
+
+/// Parse a single object stat block.
+///
+/// * `stat_block` - Lines of the stat block, with the leading `>` removed
+/// * `source` - Source book of the object
+///
+/// Returns: The parsed object.
+fn parse_object<'a>(stat_block: &[String], source: Source<'a>) -> Result<Object<'a>> {
+    let parts = split_stat_block(stat_block)?;
+    let [damage_resistance, damage_immunity, damage_vulnerability] =
+        parts.parse_damage_modifiers()?;
+    let actions = parts
+        .section("actions")
+        .map(parse_named_entries)
+        .transpose()?
+        .and_then(non_empty);
+    Ok(Object {
+        size: parse_stat_block_sizes(&parts.subtitle)?,
+        object_type: if actions.is_some() {
+            ObjectType::SiegeWeapon
+        } else {
+            ObjectType::Generic
+        },
+        armor_class: parts
+            .parse_required_property("armor class", "Object", |line| line.try_into())?,
+        hit_points: parts
+            .parse_required_property("hit points", "Object", |line| line.try_into())?,
+        speed: parts.parse_property("speed", |line| line.try_into())?,
+        damage_resistance,
+        damage_immunity,
+        damage_vulnerability,
+        condition_immunities: parts.parse_condition_immunities()?,
+        entries: parse_entries(&parts.description.join("\n")),
+        actions,
+        name: parts.name,
+        source,
+        ability_scores: parts.ability_scores,
+    })
+}
@@ -0,0 +1,60 @@
+use std::fs;
+
+use serde_json::Value;
+
+use crate::{
+    models::common::Source,
+    utils::{compare::json_compare, traits::To5etools},
+};
+
+use super::{parse_objects, parse_property_line, stat_block_subtitle};
+
+const SOURCE: Source = Source {
+    source_book: "test-source",
+    page: 0,
+};
+
+#[test]
+fn property_lines() {
+    assert_eq!(
+        parse_property_line("- **Armor Class** 15"),
+        Some(("armor class".to_string(), "15".to_string()))
+    );
+    assert_eq!(
+        parse_property_line("**Hit Points:** 50 (damage threshold 20)"),
+        Some((
+            "hit points".to_string(),
+            "50 (damage threshold 20)".to_string()
+        ))
+    );
+    assert_eq!(
+        parse_property_line("***Harpoon.*** Ranged Weapon Attack"),
+        None
+    );
+}
+
+#[test]
+fn subtitles() {
+    assert_eq!(
+        stat_block_subtitle(&[
+            "## Tide Ballista".to_string(),
+            String::new(),
+            "*Large object*".to_string(),
+        ]),
+        Some("large object".to_string())
+    );
+}
+
+#[test]
+fn objects() {
+    let document = fs::read_to_string("resources/test/objects/unit_input.md").unwrap();
+    let objects = parse_objects(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let expected: Value = serde_json::from_str(
+        &fs::read_to_string("resources/test/objects/unit_output.json").unwrap(),
+    )
+    .unwrap();
+    json_compare(objects.to_5etools_base(), expected).unwrap();
+}
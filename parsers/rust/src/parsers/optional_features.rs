@@ -0,0 +1,244 @@
+use std::iter;
+
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{
+    models::{
+        common::{Classes, Source},
+        optional_features::{OptionalFeature, OptionalFeaturePrerequisites, OptionalFeatureType},
+    },
+    parsers::{classes::find_class, feats::is_feat_line, races::split_traits},
+    utils::{
+        error::{ParseError, Result},
+        markdown::{
+            document_lines, heading_block, heading_level, heading_name, parse_block_entries,
+            remove_emphasis, split_top_level,
+        },
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A list of optional features of one type, starting with its heading.
+pub(crate) type OptionalFeatureList = (OptionalFeatureType, Vec<String>);
+
+/// Parse all optional features in a GM Binder or Homebrewery document.
+///
+/// Optional features are the subheadings or the `***Name.***` paragraphs below a heading that
+/// names their type, e.g. `Eldritch Invocations`, `Maneuvers` or `Paladin Fighting Styles`.
+#[must_use]
+pub fn parse_optional_features<'a>(
+    document: &str,
+    source: &Source<'a>,
+) -> Vec<Result<OptionalFeature<'a>>> {
+    extract_optional_feature_lists(document)
+        .into_iter()
+        .flat_map(|(feature_type, list)| {
+            split_optional_features(&list)
+                .into_iter()
+                .map(|(name, lines)| {
+                    parse_optional_feature(name, &lines, feature_type, source.clone())
+                })
+                .collect_vec()
+        })
+        .collect()
+}
+
+/// Parse the type of the optional features below a heading, e.g. `Eldritch Invocations`.
+///
+/// * `heading` - Name of the heading
+/// * `ancestors` - Names of the headings the heading is nested in, from the closest to the
+///   farthest, e.g. the class of a `Fighting Style` feature
+fn parse_feature_type(heading: &str, ancestors: &[String]) -> Option<OptionalFeatureType> {
+    let heading = heading.to_lowercase();
+    if heading.contains("invocation") {
+        Some(OptionalFeatureType::EldritchInvocation)
+    } else if heading.contains("maneuver") {
+        Some(OptionalFeatureType::Maneuver)
+    } else if heading.contains("metamagic") {
+        Some(OptionalFeatureType::Metamagic)
+    } else if heading.contains("infusion") {
+        Some(OptionalFeatureType::ArtificerInfusion)
+    } else if heading.contains("fighting style") {
+        Some(OptionalFeatureType::FightingStyle(
+            iter::once(&heading)
+                .chain(ancestors)
+                .find_map(|name| find_class(name))
+                .unwrap_or(Classes::Fighter),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Split a document into lists of optional features, each starting with its heading and ending
+/// before the next heading of the same or a higher level.
+pub(crate) fn extract_optional_feature_lists(document: &str) -> Vec<OptionalFeatureList> {
+    let lines = document_lines(document);
+    let headings = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| heading_level(line).map(|level| (index, level)))
+        .collect_vec();
+    let mut lists = vec![];
+    let mut end = 0;
+    for (position, &(start, level)) in headings.iter().enumerate() {
+        // Optional features named after their type, e.g. the `Metamagic Adept` invocation.
+        if start < end {
+            continue;
+        }
+        let mut ancestor_level = level;
+        let ancestors = headings[..position]
+            .iter()
+            .rev()
+            .filter(|(_, ancestor)| {
+                *ancestor < ancestor_level && {
+                    ancestor_level = *ancestor;
+                    true
+                }
+            })
+            .map(|(index, _)| heading_name(lines[*index]))
+            .collect_vec();
+        let Some(feature_type) = parse_feature_type(&heading_name(lines[start]), &ancestors) else {
+            continue;
+        };
+        let list = heading_block(&lines, start);
+        if is_optional_feature_list(&list) {
+            end = start + list.len();
+            lists.push((feature_type, list));
+        }
+    }
+    lists
+}
+
+/// Whether the lines below a heading that names a type of optional features list options, as
+/// subheadings or `***Name.***` paragraphs. A heading followed by a feat line, e.g. the
+/// `Metamagic Adept` feat, is a feat instead.
+fn is_optional_feature_list(list: &[String]) -> bool {
+    let options = &list[1..];
+    !options.first().is_some_and(|line| is_feat_line(line))
+        && (options.iter().any(|line| heading_level(line).is_some())
+            || !split_traits(options).1.is_empty())
+}
+
+/// Split a list of optional features into their names and lines. The features are the
+/// subheadings of the list or, without subheadings, its `***Name.***` paragraphs.
+fn split_optional_features(list: &[String]) -> Vec<(String, Vec<String>)> {
+    let lines = list.iter().skip(1).map(String::as_str).collect_vec();
+    lines
+        .iter()
+        .filter_map(|line| heading_level(line))
+        .min()
+        .map_or_else(
+            || {
+                split_traits(&list[1..])
+                    .1
+                    .into_iter()
+                    .map(|feature| {
+                        (
+                            feature.name,
+                            feature.text.lines().map(str::to_string).collect(),
+                        )
+                    })
+                    .collect()
+            },
+            |feature_level| {
+                lines
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, line)| heading_level(line) == Some(feature_level))
+                    .map(|(index, line)| {
+                        (
+                            heading_name(line),
+                            heading_block(&lines, index).into_iter().skip(1).collect(),
+                        )
+                    })
+                    .collect()
+            },
+        )
+}
+
+/// Parse a single optional feature.
+///
+/// * `name` - Name of the optional feature
+/// * `lines` - Lines of the optional feature, which may start with its prerequisites, e.g.
+///   `*Prerequisite: 5th level, Pact of the Blade feature*`
+/// * `feature_type` - Type of the list of the optional feature
+/// * `source` - Source book of the optional feature
+///
+/// Returns: The parsed optional feature.
+fn parse_optional_feature<'a>(
+    name: String,
+    lines: &[String],
+    feature_type: OptionalFeatureType,
+    source: Source<'a>,
+) -> Result<OptionalFeature<'a>> {
+    let prerequisites_re = Regex::new(r"(?i)^prerequisites?:\s*(?P<prerequisites>.+)$").unwrap();
+    let [first, rest @ ..] = lines else {
+        return Err(
+            ParseError::new_with_problem(&name, "Optional feature", "Missing description").into(),
+        );
+    };
+    let first = remove_emphasis(first);
+    let (prerequisites, entries) = prerequisites_re.captures(&first).map_or_else(
+        || Ok((OptionalFeaturePrerequisites::default(), lines)),
+        |captures| {
+            parse_prerequisites(captures["prerequisites"].trim_end_matches('.'))
+                .map(|prerequisites| (prerequisites, rest))
+        },
+    )?;
+    Ok(OptionalFeature {
+        name,
+        source,
+        feature_type,
+        prerequisites,
+        entries: parse_block_entries(entries),
+    })
+}
+
+/// Parse the prerequisites of an optional feature, e.g. `5th level, Pact of the Blade feature`
+/// or `eldritch blast cantrip`.
+fn parse_prerequisites(prerequisites: &str) -> Result<OptionalFeaturePrerequisites> {
+    let level_re =
+        Regex::new(r"(?i)^(?:(?P<ordinal>\d+)(?:st|nd|rd|th)[- ]level|level (?P<level>\d+)\+?)\b")
+            .unwrap();
+    let pact_re = Regex::new(r"(?i)^pact of the (?P<pact>\w+)(?: feature)?$").unwrap();
+    let spell_re = Regex::new(r"(?i)^(?:the )?(?P<spell>.+?) (?P<kind>cantrip|spell)$").unwrap();
+
+    let mut result = OptionalFeaturePrerequisites::default();
+    for prerequisite in split_top_level(prerequisites)
+        .into_iter()
+        .map(|prerequisite| prerequisite.trim_start_matches("and ").trim())
+        .filter(|prerequisite| !prerequisite.is_empty())
+    {
+        if let Some(captures) = level_re.captures(prerequisite) {
+            let level = captures
+                .name("ordinal")
+                .or_else(|| captures.name("level"))
+                .map_or("", |level| level.as_str());
+            result.level = Some(level.parse().map_err(|_| {
+                ParseError::new_with_problem(
+                    prerequisites,
+                    "Optional feature prerequisites",
+                    "Invalid level",
+                )
+            })?);
+        } else if let Some(captures) = pact_re.captures(prerequisite) {
+            result.pact = Some(captures["pact"].to_string());
+        } else if let Some(captures) = spell_re.captures(prerequisite) {
+            let spell = captures["spell"].to_lowercase();
+            result
+                .spells
+                .push(if captures["kind"].eq_ignore_ascii_case("cantrip") {
+                    format!("{spell}#c")
+                } else {
+                    spell
+                });
+        } else {
+            result.other.push(prerequisite.to_string());
+        }
+    }
+    Ok(result)
+}
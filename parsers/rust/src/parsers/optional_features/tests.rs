@@ -0,0 +1,70 @@
+use std::fs;
+
+use serde_json::Value;
+
+use crate::{
+    models::{
+        common::{Classes, Source},
+        optional_features::{OptionalFeaturePrerequisites, OptionalFeatureType},
+    },
+    utils::{compare::json_compare, traits::To5etools},
+};
+
+use super::{parse_feature_type, parse_optional_features, parse_prerequisites};
+
+const SOURCE: Source = Source {
+    source_book: "test-source",
+    page: 0,
+};
+
+#[test]
+fn feature_types() {
+    assert_eq!(
+        parse_feature_type("Metamagic Options", &[]),
+        Some(OptionalFeatureType::Metamagic)
+    );
+    assert_eq!(
+        parse_feature_type("Fighting Style", &["Ranger".to_string()]),
+        Some(OptionalFeatureType::FightingStyle(Classes::Ranger))
+    );
+    assert_eq!(
+        parse_feature_type("Fighting Styles", &[]),
+        Some(OptionalFeatureType::FightingStyle(Classes::Fighter))
+    );
+    assert_eq!(parse_feature_type("Spells", &[]), None);
+}
+
+#[test]
+fn prerequisites() {
+    assert_eq!(
+        parse_prerequisites("12th level, Pact of the Tome feature, hex spell"),
+        Ok(OptionalFeaturePrerequisites {
+            level: Some(12),
+            pact: Some("Tome".to_string()),
+            spells: vec!["hex".to_string()],
+            other: vec![],
+        })
+    );
+    assert_eq!(
+        parse_prerequisites("Level 5+ Warlock, a magic item"),
+        Ok(OptionalFeaturePrerequisites {
+            level: Some(5),
+            other: vec!["a magic item".to_string()],
+            ..OptionalFeaturePrerequisites::default()
+        })
+    );
+}
+
+#[test]
+fn optional_features() {
+    let document = fs::read_to_string("resources/test/optional_features/unit_input.md").unwrap();
+    let features = parse_optional_features(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let expected: Value = serde_json::from_str(
+        &fs::read_to_string("resources/test/optional_features/unit_output.json").unwrap(),
+    )
+    .unwrap();
+    json_compare(features.to_5etools_base(), expected).unwrap();
+}
@@ -0,0 +1,517 @@
+use std::iter;
+
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{
+    models::{
+        backgrounds::LanguageProficiencies,
+        common::{AbilityScore, Description, Source},
+        creatures::{Size, Speed, SpeedValue},
+        races::{AbilityChoice, Age, Race, RaceAbilities, RaceTraits, Subrace},
+    },
+    parsers::{
+        backgrounds::parse_language_proficiencies,
+        feats::{parse_abilities, ABILITIES},
+    },
+    utils::{
+        error::{OutOfBoundsError, ParseError, Result},
+        markdown::{
+            document_lines, heading_block, heading_level, heading_name, parse_block_entries,
+            parse_count, parse_entries,
+        },
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Traits that are stored in the fields of a race instead of its entries.
+const OMITTED_TRAITS: [&str; 5] = [
+    "ability score increase",
+    "ability score increases",
+    "creature type",
+    "speed",
+    "subrace",
+];
+
+/// Traits at the start of a 2024 species, e.g. `***Size:*** Medium`, which are single lines.
+const HEADER_TRAITS: [&str; 3] = ["creature type", "size", "speed"];
+
+/// Test whether a trait, given by its name and text in lowercase, has a trait tag.
+type TraitTagTest = fn(&str, &str) -> bool;
+
+/// 5etools trait tags with their tests.
+const TRAIT_TAGS: [(&str, TraitTagTest); 11] = [
+    ("Amphibious", |name, text| {
+        name.contains("amphibious") || text.contains("breathe air and water")
+    }),
+    ("Armor Proficiency", |_, text| {
+        text.contains("proficiency with") && text.contains(" armor")
+    }),
+    ("Improved Resting", |name, _| name.contains("trance")),
+    ("Natural Armor", |name, text| {
+        name.contains("natural armor") || text.contains("natural armor")
+    }),
+    ("Natural Weapon", |_, text| {
+        text.contains("unarmed strike")
+            && [
+                "natural weapon",
+                "claws",
+                "fangs",
+                "bite",
+                "horns",
+                "hooves",
+                "talons",
+            ]
+            .iter()
+            .any(|weapon| text.contains(weapon))
+    }),
+    ("Powerful Build", |name, _| name.contains("powerful build")),
+    ("Skill Proficiency", |_, text| {
+        text.contains("proficiency in the") && text.contains("skill")
+    }),
+    ("Spellcasting", |_, text| {
+        text.contains("cantrip") || text.contains("you can cast")
+    }),
+    ("Sunlight Sensitivity", |name, _| {
+        name.contains("sunlight sensitivity")
+    }),
+    ("Tool Proficiency", |_, text| {
+        text.contains("proficiency with")
+            && ["tools", "artisan", "kit", "supplies"]
+                .iter()
+                .any(|tool| text.contains(tool))
+    }),
+    ("Weapon Proficiency", |_, text| {
+        text.contains("proficiency with the")
+            && ["axe", "sword", "bow", "hammer", "spear", "weapons"]
+                .iter()
+                .any(|weapon| text.contains(weapon))
+    }),
+];
+
+/// A subheading of a race with the lines below it, or the lines before the first subheading.
+type Subheading<'a> = (Option<&'a String>, Vec<String>);
+
+/// A trait of a race, e.g. `***Darkvision.*** Accustomed to life underground, …`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Trait {
+    pub(crate) name: String,
+    pub(crate) text: String,
+}
+
+impl Trait {
+    fn lowercase_name(&self) -> String {
+        self.name.to_lowercase()
+    }
+}
+
+/// Parse all races or species in a GM Binder or Homebrewery document.
+///
+/// A race is a heading whose text has a `Speed` trait, e.g. `***Speed.*** Your base walking
+/// speed is 25 feet.`, or the parent heading if that heading is named `… Traits`. Subheadings
+/// with traits of their own are subraces.
+#[must_use]
+pub fn parse_races<'a>(document: &str, source: &Source<'a>) -> Vec<Result<Race<'a>>> {
+    extract_races(document)
+        .into_iter()
+        .map(|race| parse_race(&race, source.clone()))
+        .collect()
+}
+
+/// Parse a trait line, e.g. `***Darkvision.*** You can see …` or `***Size:*** Medium`.
+fn parse_trait_line(line: &str) -> Option<Trait> {
+    let trait_re = Regex::new(
+        r"^(?:[-•] )?(?:\*\*\*|___|\*\*|__)(?P<name>[^*_:.]+?)(?:[.:](?:\*\*\*|___|\*\*|__)|(?:\*\*\*|___|\*\*|__)[.:])\s*(?P<text>.*)$",
+    )
+    .unwrap();
+    trait_re.captures(line).map(|captures| Trait {
+        name: captures["name"].trim().to_string(),
+        text: captures["text"].trim().to_string(),
+    })
+}
+
+fn is_trait_line(line: &str) -> bool {
+    parse_trait_line(line).is_some()
+}
+
+/// Split lines into the lines before or between traits and the traits with the lines that
+/// continue them.
+pub(crate) fn split_traits(lines: &[String]) -> (Vec<String>, Vec<Trait>) {
+    let mut prose = vec![];
+    let mut traits: Vec<Trait> = vec![];
+    for line in lines {
+        if let Some(race_trait) = parse_trait_line(line) {
+            traits.push(race_trait);
+        } else if let Some(race_trait) = traits
+            .last_mut()
+            .filter(|race_trait| !HEADER_TRAITS.contains(&&race_trait.lowercase_name()[..]))
+        {
+            race_trait.text = format!("{}\n{line}", race_trait.text);
+        } else {
+            prose.push(line.clone());
+        }
+    }
+    (prose, traits)
+}
+
+/// Split a document into races, each starting with its heading and ending before the next
+/// heading of the same or a higher level.
+pub(crate) fn extract_races(document: &str) -> Vec<Vec<String>> {
+    let lines = document_lines(document);
+    let headings = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| heading_level(line).map(|level| (index, level)))
+        .collect_vec();
+    let race_headings = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| {
+            parse_trait_line(line).is_some_and(|race_trait| race_trait.lowercase_name() == "speed")
+        })
+        .filter_map(|(index, _)| {
+            let (position, &(heading, level)) = headings
+                .iter()
+                .enumerate()
+                .rev()
+                .find(|(_, (heading, _))| *heading < index)?;
+            if !heading_name(lines[heading])
+                .to_lowercase()
+                .ends_with("traits")
+            {
+                return Some((heading, level));
+            }
+            Some(
+                headings[..position]
+                    .iter()
+                    .rev()
+                    .find(|(_, parent_level)| *parent_level < level)
+                    .copied()
+                    .unwrap_or((heading, level)),
+            )
+        })
+        .unique()
+        .collect_vec();
+
+    let mut races = vec![];
+    let mut end = 0;
+    for (start, _) in race_headings {
+        // Speed traits of subraces are part of the race before them.
+        if start < end {
+            continue;
+        }
+        let race = heading_block(&lines, start);
+        end = start + race.len();
+        races.push(race);
+    }
+    races
+}
+
+/// Parse a single race.
+///
+/// * `race` - Lines of the race, starting with its heading
+/// * `source` - Source book of the race
+///
+/// Returns: The parsed race with its subraces.
+fn parse_race<'a>(race: &[String], source: Source<'a>) -> Result<Race<'a>> {
+    let [heading, lines @ ..] = race else {
+        return Err(OutOfBoundsError {
+            array: race.to_vec(),
+            index: 0,
+            parsing_step: "Race".to_string(),
+            problem: Some("Expected a heading".to_string()),
+        }
+        .into());
+    };
+    let name = heading_name(heading);
+    // Lines before the first subheading and each subheading with the lines below it.
+    let mut groups: Vec<Subheading> = vec![(None, vec![])];
+    for line in lines {
+        if heading_level(line).is_some() {
+            groups.push((Some(line), vec![]));
+        } else if let Some((_, group_lines)) = groups.last_mut() {
+            group_lines.push(line.clone());
+        }
+    }
+    let mut race_lines = vec![];
+    let mut sections = vec![];
+    let mut subraces = vec![];
+    for (subheading, group_lines) in groups {
+        let Some(subheading) = subheading else {
+            race_lines.extend(group_lines);
+            continue;
+        };
+        let subheading_name = heading_name(subheading);
+        if subheading_name.to_lowercase().ends_with("traits") {
+            race_lines.extend(group_lines);
+        } else if group_lines.iter().any(|line| is_trait_line(line)) {
+            subraces.push(parse_subrace(&subheading_name, &group_lines, &name)?);
+        } else {
+            sections.push(subheading.clone());
+            sections.extend(group_lines);
+        }
+    }
+
+    let (prose, traits) = split_traits(&race_lines);
+    let find_trait = |names: &[&str]| {
+        traits
+            .iter()
+            .find(|race_trait| names.contains(&&race_trait.lowercase_name()[..]))
+    };
+    let size = find_trait(&["size"])
+        .map(|size| parse_sizes(&size.text))
+        .transpose()?
+        .filter(|size| !size.is_empty())
+        .ok_or_else(|| ParseError::new_with_problem(&name, "Race", "Missing size"))?;
+    // Other speeds may be part of other traits, e.g. `Amphibious`.
+    let speed = find_trait(&["speed"])
+        .and_then(|speed| {
+            parse_speed(
+                &iter::once(&speed.text)
+                    .chain(traits.iter().map(|race_trait| &race_trait.text))
+                    .join("\n"),
+            )
+        })
+        .ok_or_else(|| ParseError::new_with_problem(&name, "Race", "Missing walking speed"))?;
+    let age = find_trait(&["age"]).and_then(|age| parse_age(&age.text));
+    let mut traits = parse_race_traits(&prose, &traits)?;
+    traits.entries.extend(parse_block_entries(&sections));
+    Ok(Race {
+        name,
+        source,
+        size,
+        speed,
+        age,
+        traits,
+        subraces,
+    })
+}
+
+/// Parse a subrace, e.g. a `Hill Dwarf` heading with its traits.
+fn parse_subrace(name: &str, lines: &[String], race_name: &str) -> Result<Subrace> {
+    let (prose, traits) = split_traits(lines);
+    let speed = traits
+        .iter()
+        .find_map(|race_trait| parse_speed(&race_trait.text));
+    let short_name = name
+        .strip_suffix(race_name)
+        .or_else(|| name.strip_prefix(race_name))
+        .map(|short_name| short_name.trim_matches(|c: char| c == ',' || c.is_whitespace()))
+        .filter(|short_name| !short_name.is_empty())
+        .unwrap_or(name);
+    Ok(Subrace {
+        name: short_name.to_string(),
+        speed,
+        traits: parse_race_traits(&prose, &traits)?,
+    })
+}
+
+/// Parse the traits that races and subraces have in common.
+fn parse_race_traits(prose: &[String], traits: &[Trait]) -> Result<RaceTraits> {
+    let mut ability = None;
+    let mut languages = LanguageProficiencies::default();
+    for race_trait in traits {
+        match &race_trait.lowercase_name()[..] {
+            "ability score increase" | "ability score increases" => {
+                ability = parse_race_abilities(&race_trait.text)?;
+            }
+            "languages" => languages = parse_race_languages(&race_trait.text),
+            _ => {}
+        }
+    }
+    let mut entries = parse_block_entries(prose);
+    entries.extend(
+        traits
+            .iter()
+            .filter(|race_trait| !OMITTED_TRAITS.contains(&&race_trait.lowercase_name()[..]))
+            // Sizes of 2024 species are only in the size field.
+            .filter(|race_trait| {
+                race_trait.lowercase_name() != "size" || race_trait.text.contains('.')
+            })
+            .map(|race_trait| Description::Section {
+                name: race_trait.name.clone(),
+                entries: parse_entries(&race_trait.text),
+            }),
+    );
+    Ok(RaceTraits {
+        ability,
+        darkvision: traits
+            .iter()
+            .find_map(|race_trait| parse_darkvision(&race_trait.text)),
+        languages,
+        trait_tags: parse_trait_tags(traits),
+        entries,
+    })
+}
+
+/// Parse the sizes of a race, e.g. `Your size is Medium.` or `Medium (about 4–7 feet tall) or
+/// Small (about 2–4 feet tall)`.
+fn parse_sizes(text: &str) -> Result<Vec<Size>> {
+    let size_re = Regex::new(r"\b(?:tiny|small|medium|large)\b").unwrap();
+    let lowercase = text.to_lowercase();
+    let sizes = lowercase
+        .split_once("your size is")
+        .map_or(&lowercase[..], |(_, sizes)| sizes);
+    size_re
+        .find_iter(sizes)
+        .map(|size| size.as_str())
+        .unique()
+        .map(Size::try_from)
+        .collect()
+}
+
+/// Parse the speed of a race or a change of it, e.g. `Your base walking speed is 30 feet, and
+/// you have a climbing speed equal to your walking speed.` or `35 feet`.
+fn parse_speed(text: &str) -> Option<Speed> {
+    let walk_re = Regex::new(
+        r"(?i)(?:walking speed (?:is|of|increases to|becomes)\s*|^)(?P<speed>\d+) (?:feet|ft\.?)",
+    )
+    .unwrap();
+    let other_re = Regex::new(
+        r"(?i)(?P<mode>burrowing|climbing|flying|swimming) speed (?:of (?P<speed>\d+) (?:feet|ft\.?)|equal to your walking speed)",
+    )
+    .unwrap();
+    let walk: u16 = walk_re.captures(text)?["speed"].parse().ok()?;
+    let mut speed = Speed {
+        walk: SpeedValue::from(walk),
+        burrow: None,
+        climb: None,
+        crawl: None,
+        fly: None,
+        swim: None,
+        can_hover: false,
+        alternate: None,
+    };
+    for captures in other_re.captures_iter(text) {
+        let value = SpeedValue::from(
+            captures
+                .name("speed")
+                .and_then(|speed| speed.as_str().parse().ok())
+                .unwrap_or(walk),
+        );
+        match &captures["mode"].to_lowercase()[..] {
+            "burrowing" => speed.burrow = Some(value),
+            "climbing" => speed.climb = Some(value),
+            "flying" => speed.fly = Some(value),
+            _ => speed.swim = Some(value),
+        }
+    }
+    Some(speed)
+}
+
+/// Parse the range of darkvision, e.g. `You can see in dim light within 60 feet of you` or `You
+/// have Darkvision with a range of 60 feet`.
+fn parse_darkvision(text: &str) -> Option<u16> {
+    let darkvision_re = Regex::new(
+        r"(?i)(?:darkvision[^.\d]*?|see in dim light within )(?P<range>\d+) (?:feet|ft\.?)",
+    )
+    .unwrap();
+    darkvision_re.captures(text)?["range"].parse().ok()
+}
+
+/// Parse the age trait of a race, e.g. `Dwarves mature at the same rate as humans, but they're
+/// considered young until they reach the age of 50. On average, they live about 350 years.`
+fn parse_age(text: &str) -> Option<Age> {
+    let mature_re = Regex::new(
+        r"(?i)(?:reach(?:es)? adulthood|mature|considered (?:young )?adults?)[^.]*?(?P<age>\d[\d,]*)",
+    )
+    .unwrap();
+    let max_re = Regex::new(
+        r"(?i)\blive (?:to be |for |until )?(?:about |around |up to |less than |more than |over |well over |nearly |roughly |an average of )*(?P<age>\d[\d,]*)",
+    )
+    .unwrap();
+    let parse = |re: &Regex, text: &str| {
+        re.captures(text)
+            .and_then(|captures| captures["age"].replace(',', "").parse().ok())
+    };
+    // The age of adulthood is before the lifespan, e.g. `reach adulthood in their late teens and
+    // can live to be 750 years old`.
+    let before_max = max_re.find(text).map_or(text, |max| &text[..max.start()]);
+    let age = Age {
+        mature: parse(&mature_re, before_max),
+        max: parse(&max_re, text),
+    };
+    (age.mature.is_some() || age.max.is_some()).then_some(age)
+}
+
+/// Parse the ability score increases of a race, e.g. `Your Constitution score increases by 2.`
+/// or `Your Charisma score increases by 2, and two other ability scores of your choice increase
+/// by 1.`
+fn parse_race_abilities(text: &str) -> Result<Option<RaceAbilities>> {
+    let fixed_re = Regex::new(&format!(
+        r"(?i)\byour (?P<abilities>(?:{ABILITIES})(?:,? (?:and )?(?:{ABILITIES}))*) scores? (?:each )?increases? by (?P<amount>\d+)"
+    ))
+    .unwrap();
+    let all_re = Regex::new(r"(?i)\byour ability scores each increase by (?P<amount>\d+)").unwrap();
+    let choose_re = Regex::new(
+        r"(?i)\b(?P<count>\w+) (?:other |different )?ability scores? of your choice (?:each )?increases? by (?P<amount>\d+)",
+    )
+    .unwrap();
+    let parse_amount = |amount: &str| -> Result<u8> {
+        amount
+            .parse()
+            .map_err(|_| ParseError::new(text, "Race ability score increase").into())
+    };
+    let all_abilities = [
+        AbilityScore::Strength,
+        AbilityScore::Dexterity,
+        AbilityScore::Constitution,
+        AbilityScore::Intelligence,
+        AbilityScore::Wisdom,
+        AbilityScore::Charisma,
+    ];
+
+    let mut abilities = RaceAbilities::default();
+    if let Some(captures) = all_re.captures(text) {
+        let amount = parse_amount(&captures["amount"])?;
+        abilities.fixed = all_abilities
+            .iter()
+            .map(|ability| (ability.clone(), amount))
+            .collect();
+    }
+    for captures in fixed_re.captures_iter(text) {
+        let amount = parse_amount(&captures["amount"])?;
+        abilities.fixed.extend(
+            parse_abilities(&captures["abilities"])?
+                .into_iter()
+                .map(|ability| (ability, amount)),
+        );
+    }
+    if let Some(captures) = choose_re.captures(text) {
+        abilities.choose = Some(AbilityChoice {
+            from: all_abilities
+                .into_iter()
+                .filter(|ability| abilities.fixed.iter().all(|(fixed, _)| fixed != ability))
+                .collect(),
+            count: parse_count(&captures["count"], "Race ability score increase")?,
+            amount: parse_amount(&captures["amount"])?,
+        });
+    }
+    Ok((abilities != RaceAbilities::default()).then_some(abilities))
+}
+
+/// Parse the languages trait of a race, e.g. `You can speak, read, and write Common and
+/// Dwarvish.`
+fn parse_race_languages(text: &str) -> LanguageProficiencies {
+    let languages_re =
+        Regex::new(r"(?i)(?:speak, read,? and write|you know) (?P<languages>[^.]+)").unwrap();
+    languages_re
+        .captures(text)
+        .map(|captures| parse_language_proficiencies(&captures["languages"]))
+        .unwrap_or_default()
+}
+
+/// Derive the 5etools trait tags of a race from its traits.
+fn parse_trait_tags(traits: &[Trait]) -> Vec<String> {
+    let traits = traits
+        .iter()
+        .map(|race_trait| (race_trait.lowercase_name(), race_trait.text.to_lowercase()))
+        .collect_vec();
+    TRAIT_TAGS
+        .iter()
+        .filter(|(_, has_tag)| traits.iter().any(|(name, text)| has_tag(name, text)))
+        .map(|(tag, _)| (*tag).to_string())
+        .collect()
+}
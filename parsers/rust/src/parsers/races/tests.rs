@@ -0,0 +1,108 @@
+use std::fs;
+
+use serde_json::{json, Value};
+
+use crate::{
+    models::{
+        common::{AbilityScore, Source},
+        creatures::Size,
+        races::{AbilityChoice, Age, Race, RaceAbilities},
+    },
+    utils::{compare::json_compare, traits::To5etools},
+};
+
+use super::{
+    extract_races, parse_age, parse_race_abilities, parse_races, parse_sizes, parse_speed,
+};
+
+const SOURCE: Source = Source {
+    source_book: "test-source",
+    page: 0,
+};
+
+#[test]
+fn extract_races_test() {
+    let document = fs::read_to_string("resources/test/races/unit_input.md").unwrap();
+    let races = extract_races(&document);
+    assert_eq!(races.len(), 2);
+    assert_eq!(races[0][0], "## Stonekin");
+    assert_eq!(races[1][0], "## Mirefolk");
+}
+
+#[test]
+fn race_abilities() {
+    assert_eq!(
+        parse_race_abilities("Your Constitution score increases by 2."),
+        Ok(Some(RaceAbilities {
+            fixed: vec![(AbilityScore::Constitution, 2)],
+            choose: None,
+        }))
+    );
+    assert_eq!(
+        parse_race_abilities(
+            "Your Charisma score increases by 2, and two other ability scores of your choice increase by 1."
+        ),
+        Ok(Some(RaceAbilities {
+            fixed: vec![(AbilityScore::Charisma, 2)],
+            choose: Some(AbilityChoice {
+                from: vec![
+                    AbilityScore::Strength,
+                    AbilityScore::Dexterity,
+                    AbilityScore::Constitution,
+                    AbilityScore::Intelligence,
+                    AbilityScore::Wisdom,
+                ],
+                count: 2,
+                amount: 1,
+            }),
+        }))
+    );
+    assert_eq!(
+        parse_race_abilities("Your ability scores each increase by 1.")
+            .unwrap()
+            .map(|abilities| abilities.fixed.len()),
+        Some(6)
+    );
+    assert_eq!(parse_race_abilities("You gain a feat."), Ok(None));
+}
+
+#[test]
+fn size_speed_and_age() {
+    assert_eq!(
+        parse_sizes("Your size is Small or Medium."),
+        Ok(vec![Size::Small, Size::Medium])
+    );
+    assert_eq!(
+        parse_speed("Your base walking speed is 30 feet, and you have a flying speed of 50 feet.")
+            .map(|speed| speed.to_5etools_base()),
+        Some(json!({"walk": 30, "fly": 50}))
+    );
+    assert_eq!(parse_speed("You can swim."), None);
+    assert_eq!(
+        parse_age("Elves reach adulthood in their late teens and can live to be 750 years old."),
+        Some(Age {
+            mature: None,
+            max: Some(750),
+        })
+    );
+}
+
+#[test]
+fn races() {
+    let document = fs::read_to_string("resources/test/races/unit_input.md").unwrap();
+    let races = parse_races(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let expected: Value =
+        serde_json::from_str(&fs::read_to_string("resources/test/races/unit_output.json").unwrap())
+            .unwrap();
+    json_compare(
+        json!({
+            "race": races.to_5etools_base(),
+            "subrace": races.iter().flat_map(Race::to_5etools_subraces).collect::<Vec<_>>(),
+        }),
+        expected,
+    )
+    .unwrap();
+}
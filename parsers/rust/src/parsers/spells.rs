@@ -190,7 +190,7 @@ fn parse_range(range_str: &str) -> Result<Range, Error> {
 
 fn parse_components(components_str: String) -> Result<Components, Error> {
     let stripped_components_str = strip_str(&components_str.as_str());
-    let components = _parse_components_helper(stripped_components_str, &components_str)?;
+    let components = _parse_components_helper(&stripped_components_str, &components_str)?;
     if components.verbal || components.somatic || components.material.is_some() {
         Ok(components)
     } else {
@@ -204,14 +204,14 @@ fn parse_components(components_str: String) -> Result<Components, Error> {
 }
 
 fn _parse_components_helper(
-    stripped_str: String,
+    stripped_str: &str,
     original_str: &String,
 ) -> Result<Components, Error> {
     let mut stripped_words = stripped_str.split(' ');
     match stripped_words.next() {
         Some("v") => {
             let other_components =
-                _parse_components_helper(stripped_words.join(" "), original_str)?;
+                _parse_components_helper(&stripped_words.join(" "), original_str)?;
             Ok(Components {
                 verbal: true,
                 somatic: other_components.somatic,
@@ -220,7 +220,7 @@ fn _parse_components_helper(
         }
         Some("s") => {
             let other_components =
-                _parse_components_helper(stripped_words.join(" "), original_str)?;
+                _parse_components_helper(&stripped_words.join(" "), original_str)?;
             Ok(Components {
                 verbal: false,
                 somatic: true,
@@ -238,38 +238,11 @@ fn _parse_components_helper(
             let consumed = words_vec
                 .iter()
                 .any(|word| word.to_lowercase().starts_with("consume"));
-            let value = if words_vec.iter().contains(&"worth".to_owned()) {
-                let mut words = stripped_str.split(' ');
-                let value = words
-                    .find(|word| word.parse::<u32>().is_ok())
-                    .ok_or(ParseError {
-                        string: stripped_str.clone(),
-                        parsing_step: "Components (material): value".to_owned(),
-                        problem: Some("No word found that parses as u32.".to_owned()),
-                    })?
-                    .parse::<u32>()
-                    .unwrap();
-                let unit = words
-                    .next()
-                    .ok_or({
-                        let array: Vec<String> = stripped_str.split(' ').map_into().collect_vec();
-                        OutOfBoundsError {
-                            index: array
-                                .clone()
-                                .into_iter()
-                                .find_position(|word| word.parse::<u32>().is_ok())
-                                .map(|(i, _)| i as u32 + 1)
-                                .expect("A parsable u32 was found above."),
-                            array,
-                            parsing_step: "Components (material): currency".to_owned(),
-                            problem: None,
-                        }
-                    })?
-                    .try_into()?;
-                Some(ItemValue { value, unit })
-            } else {
-                None
-            };
+            let value = component
+                .to_lowercase()
+                .split_once("worth ")
+                .map(|(_, worth)| ItemValue::try_from(worth.trim_start_matches("at least ")))
+                .transpose()?;
             // Parse cost and consumption
             Ok(Components {
                 verbal: false,
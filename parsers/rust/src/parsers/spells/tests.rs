@@ -158,6 +158,21 @@ fn components_parse_test() {
             })
         })
     );
+    assert_eq!(
+        parse_components("m a diamond worth at least 1,000 gp".to_owned()),
+        Ok(Components {
+            verbal: false,
+            somatic: false,
+            material: Some(MaterialComponent {
+                component: "a diamond worth at least 1,000 gp".to_owned(),
+                value: Some(ItemValue {
+                    value: 1000,
+                    unit: Currency::Gold
+                }),
+                consumed: false
+            })
+        })
+    );
 }
 
 #[test]
@@ -0,0 +1,249 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use regex::{Captures, Regex};
+
+use crate::{
+    models::{
+        common::{Description, Source},
+        tables::{Table, TableRoll, TableRow},
+    },
+    parsers::{
+        backgrounds::extract_backgrounds,
+        classes::{extract_classes, extract_subclasses},
+        races::extract_races,
+    },
+    utils::{
+        error::{ParseError, Result},
+        markdown::{
+            document_lines, heading_name, is_heading, parse_markdown_tables, strip_emphasis,
+        },
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Parse all tables to roll on in a GM Binder or Homebrewery document, i.e. markdown tables whose
+/// first column is a die, e.g. `d100 | Result`.
+///
+/// The caption of a table is the emphasized line right above it or its closest heading. References
+/// to other tables of the document, e.g. `Roll on the Treasure table`, are tagged. Tables inside
+/// backgrounds, races, classes and subclasses are part of those and are skipped.
+#[must_use]
+pub fn parse_tables<'a>(document: &str, source: &Source<'a>) -> Vec<Result<Table<'a>>> {
+    let tables = extract_tables(document)
+        .into_iter()
+        .filter(|(_, col_labels, _)| col_labels.first().is_some_and(|label| is_dice(label)))
+        .map(|(caption, col_labels, rows)| parse_table(caption, &col_labels, &rows, source.clone()))
+        .collect_vec();
+    let names = tables
+        .iter()
+        .flatten()
+        .map(|table| table.name.clone())
+        .collect_vec();
+    tables
+        .into_iter()
+        .map(|table| {
+            table.map(|mut table| {
+                for cell in table.rows.iter_mut().flat_map(|row| &mut row.cells) {
+                    *cell = link_tables(cell, &names, source);
+                }
+                table
+            })
+        })
+        .collect()
+}
+
+fn is_dice(label: &str) -> bool {
+    let dice_re = Regex::new(r"(?i)^\d*d\d+$").unwrap();
+    dice_re.is_match(label)
+}
+
+/// A markdown table with its caption, column labels and rows.
+type MarkdownTable = (Option<String>, Vec<String>, Vec<Vec<String>>);
+
+/// Lines of the backgrounds, races, classes and subclasses of a document, which embed their own
+/// tables.
+fn embedded_lines(document: &str) -> HashSet<String> {
+    extract_backgrounds(document)
+        .into_iter()
+        .chain(extract_races(document))
+        .chain(extract_classes(document))
+        .chain(
+            extract_subclasses(document)
+                .into_iter()
+                .map(|subclass| subclass.lines),
+        )
+        .flatten()
+        .collect()
+}
+
+/// Find all markdown tables in a document with their captions, except the tables embedded in
+/// other entities.
+fn extract_tables(document: &str) -> Vec<MarkdownTable> {
+    let lines = document_lines(document);
+    let embedded_lines = embedded_lines(document);
+    lines
+        .iter()
+        .enumerate()
+        .group_by(|(_, line)| line.starts_with('|'))
+        .into_iter()
+        .filter(|(is_table, _)| *is_table)
+        .map(|(_, group)| group.collect_vec())
+        .filter(|group| {
+            group
+                .first()
+                .is_none_or(|(_, line)| !embedded_lines.contains(**line))
+        })
+        .flat_map(|group| {
+            let caption = group
+                .first()
+                .and_then(|(index, _)| find_caption(&lines[..*index]));
+            let rows = group.into_iter().map(|(_, line)| *line).collect_vec();
+            // Only the first of consecutive tables follows the caption.
+            parse_markdown_tables(&rows)
+                .into_iter()
+                .enumerate()
+                .filter_map(move |(position, table)| match table {
+                    Description::Table {
+                        col_labels, rows, ..
+                    } => Some((caption.clone().filter(|_| position == 0), col_labels, rows)),
+                    _ => None,
+                })
+        })
+        .collect()
+}
+
+/// Find the caption of a table in the lines above it, which is the emphasized line right above
+/// it or else the closest heading after the previous table.
+fn find_caption(lines: &[&str]) -> Option<String> {
+    lines
+        .last()
+        .filter(|line| !is_heading(line))
+        .and_then(|line| parse_caption(line))
+        .or_else(|| {
+            lines
+                .iter()
+                .rev()
+                .take_while(|line| !line.starts_with('|'))
+                .find(|line| is_heading(line))
+                .and_then(|line| parse_caption(line))
+        })
+}
+
+/// Parse the caption of a table from a line above it, e.g. `##### Wild Magic Surge` or
+/// `**Random Encounters**`.
+fn parse_caption(line: &str) -> Option<String> {
+    if is_heading(line) {
+        return Some(heading_name(line));
+    }
+    strip_emphasis(line)
+        .map(|caption| caption.trim().trim_end_matches(['.', ':']).to_string())
+        .filter(|caption| !caption.is_empty())
+}
+
+/// Whether the roll of a table row is written with leading zeros, e.g. `01–05` or `96–00`.
+fn is_padded(roll: &str) -> bool {
+    let padded_re = Regex::new(r"(?:^|\D)0\d").unwrap();
+    padded_re.is_match(roll.trim())
+}
+
+/// Parse the roll of a table row, e.g. `6`, `01–05` or `96–00`.
+///
+/// * `roll` - The roll cell of the row
+/// * `dice` - The dice of the table, e.g. `d100`, where `00` is 100 on a d100 and `0` is 10 on a
+///   d10
+/// * `pad` - Whether the rolls of the table are written with leading zeros
+///
+/// Returns: The parsed roll.
+fn parse_roll(roll: &str, dice: &str, pad: bool) -> Result<TableRoll> {
+    let roll_re = Regex::new(r"^(?P<min>\d+)(?:\s*[-–—]\s*(?P<max>\d+))?\+?$").unwrap();
+    let captures = roll_re
+        .captures(roll.trim())
+        .ok_or_else(|| ParseError::new(roll, "Table roll"))?;
+    let parse_number = |number: &str| -> Result<u16> {
+        match (number, dice.to_lowercase().trim_start_matches('1')) {
+            ("00", "d100") => Ok(100),
+            ("0", "d10") => Ok(10),
+            _ => number
+                .parse()
+                .map_err(|_| ParseError::new(roll, "Table roll").into()),
+        }
+    };
+    let min = parse_number(&captures["min"])?;
+    Ok(TableRoll {
+        min,
+        max: captures
+            .name("max")
+            .map(|max| parse_number(max.as_str()))
+            .transpose()?
+            .unwrap_or(min),
+        pad,
+    })
+}
+
+/// Tag the dice in a cell, e.g. `2d6 + 1 goblins` becomes `{@dice 2d6 + 1} goblins`.
+fn tag_dice(cell: &str) -> String {
+    let dice_re = Regex::new(r"\b(?P<dice>\d*d\d+(?: ?[+-] ?\d+)?)\b").unwrap();
+    dice_re.replace_all(cell, "{@dice $dice}").to_string()
+}
+
+/// Tag references to other tables in a cell, e.g. `Roll on the Treasure table` becomes `Roll on
+/// the {@table Treasure|HB} table`.
+fn link_tables(cell: &str, names: &[String], source: &Source) -> String {
+    let reference_re = Regex::new(r"(?i)\bon the (?P<name>.+?) table\b").unwrap();
+    reference_re
+        .replace_all(cell, |captures: &Captures| {
+            names
+                .iter()
+                .find(|name| name.eq_ignore_ascii_case(&captures["name"]))
+                .map_or_else(
+                    || captures[0].to_string(),
+                    |name| format!("on the {{@table {name}|{}}} table", source.source_book),
+                )
+        })
+        .to_string()
+}
+
+/// Parse a single table to roll on.
+///
+/// * `caption` - Caption of the table, if any
+/// * `col_labels` - Labels of the columns, the first one being the die, e.g. `d100`
+/// * `rows` - Cells of the rows, the first one being the roll, e.g. `01–05`
+/// * `source` - Source book of the table
+///
+/// Returns: The parsed table.
+fn parse_table<'a>(
+    caption: Option<String>,
+    col_labels: &[String],
+    rows: &[Vec<String>],
+    source: Source<'a>,
+) -> Result<Table<'a>> {
+    let [dice, col_labels @ ..] = col_labels else {
+        return Err(ParseError::new_with_problem("", "Table", "Missing columns").into());
+    };
+    let name = caption
+        .or_else(|| col_labels.first().cloned())
+        .unwrap_or_else(|| dice.clone());
+    let pad = rows
+        .iter()
+        .filter_map(|row| row.first())
+        .any(|roll| is_padded(roll));
+    Ok(Table {
+        rows: rows
+            .iter()
+            .filter_map(|row| row.split_first())
+            .map(|(roll, cells)| {
+                Ok(TableRow {
+                    roll: parse_roll(roll, dice, pad)?,
+                    cells: cells.iter().map(|cell| tag_dice(cell)).collect(),
+                })
+            })
+            .collect::<Result<_>>()?,
+        name,
+        source,
+        dice: dice.to_lowercase(),
+        col_labels: col_labels.to_vec(),
+    })
+}
@@ -0,0 +1,83 @@
+use std::fs;
+
+use serde_json::Value;
+
+use crate::{
+    models::{common::Source, tables::TableRoll},
+    utils::{compare::json_compare, traits::To5etools},
+};
+
+use super::{is_padded, parse_caption, parse_roll, parse_tables, tag_dice};
+
+const SOURCE: Source = Source {
+    source_book: "test-source",
+    page: 0,
+};
+
+#[test]
+fn rolls() {
+    let roll = |min, max, pad| TableRoll { min, max, pad };
+    assert_eq!(parse_roll("6", "d6", false), Ok(roll(6, 6, false)));
+    assert_eq!(parse_roll("01–05", "d100", true), Ok(roll(1, 5, true)));
+    assert_eq!(parse_roll("96–00", "d100", true), Ok(roll(96, 100, true)));
+    assert_eq!(
+        parse_roll(" 11 - 20 ", "d20", false),
+        Ok(roll(11, 20, false))
+    );
+    assert_eq!(parse_roll("9–0", "d10", false), Ok(roll(9, 10, false)));
+    assert_eq!(parse_roll("0", "1d10", false), Ok(roll(10, 10, false)));
+    assert_eq!(parse_roll("00", "d20", true), Ok(roll(0, 0, true)));
+    assert!(parse_roll("Padded", "d6", false).is_err());
+    assert!(is_padded("01–05"));
+    assert!(is_padded("96–00"));
+    assert!(!is_padded("10"));
+    assert!(!is_padded("9–0"));
+}
+
+#[test]
+fn captions() {
+    assert_eq!(
+        parse_caption("##### Wild Surge"),
+        Some("Wild Surge".to_string())
+    );
+    assert_eq!(
+        parse_caption("***Random Encounters:***"),
+        Some("Random Encounters".to_string())
+    );
+    assert_eq!(parse_caption("Roll once per hour."), None);
+}
+
+#[test]
+fn dice() {
+    assert_eq!(
+        tag_dice("2d4 + 1 goblins and d6 rats"),
+        "{@dice 2d4 + 1} goblins and {@dice d6} rats"
+    );
+}
+
+#[test]
+fn tables() {
+    let document = fs::read_to_string("resources/test/tables/unit_input.md").unwrap();
+    let tables = parse_tables(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(tables[1].rows.iter().all(|row| row.roll.pad));
+    let expected: Value = serde_json::from_str(
+        &fs::read_to_string("resources/test/tables/unit_output.json").unwrap(),
+    )
+    .unwrap();
+    json_compare(tables.to_5etools_base(), expected).unwrap();
+}
+
+#[test]
+fn embedded_tables() {
+    // Background characteristics and race tables are part of the background or race.
+    for path in [
+        "resources/test/backgrounds/unit_input.md",
+        "resources/test/races/unit_input.md",
+    ] {
+        let document = fs::read_to_string(path).unwrap();
+        assert!(parse_tables(&document, &SOURCE).is_empty(), "{path}");
+    }
+}
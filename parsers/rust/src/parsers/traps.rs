@@ -0,0 +1,145 @@
+use regex::Regex;
+
+use crate::{
+    models::{
+        common::Source,
+        traps::{TrapHazard, TrapHazardType},
+    },
+    parsers::{creatures::non_empty, objects::stat_block_subtitle},
+    utils::{
+        error::{ParseError, Result},
+        markdown::{
+            document_lines, heading_block, heading_name, is_heading, parse_block_entries,
+            strip_emphasis,
+        },
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Parse all traps and hazards in a GM Binder or Homebrewery document.
+///
+/// Traps and hazards are headings followed by an emphasized line with their type, e.g.
+/// `*Mechanical trap*`, `*Simple trap (level 1–4, moderate threat)*` or `*Wilderness hazard*`.
+#[must_use]
+pub fn parse_traps_hazards<'a>(document: &str, source: &Source<'a>) -> Vec<Result<TrapHazard<'a>>> {
+    let lines = document_lines(document);
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(index, line)| {
+            is_heading(line)
+                && lines
+                    .get(index + 1)
+                    .and_then(|line| strip_emphasis(line))
+                    .is_some_and(|type_line| parse_trap_hazard_type(type_line).is_some())
+        })
+        .map(|(index, _)| parse_trap_hazard(&heading_block(&lines, index), source.clone()))
+        .collect()
+}
+
+/// Whether a `>` block is a trap or hazard, e.g. with the line `*Magic trap*` below its name.
+pub(crate) fn is_trap_hazard(stat_block: &[String]) -> bool {
+    stat_block_subtitle(stat_block)
+        .is_some_and(|subtitle| parse_trap_hazard_type(&subtitle).is_some())
+}
+
+/// Parse the type of a trap or hazard, e.g. `Magic trap` or `Simple trap (level 5–10,
+/// dangerous threat)`.
+fn parse_trap_hazard_type(type_line: &str) -> Option<TrapHazardType> {
+    let simple_re = Regex::new(
+        r"(?i)^simple trap \(levels? (?P<level>\d+)\s*[-–—]\s*\d+, (?P<threat>\w+) threat\)$",
+    )
+    .unwrap();
+    let type_re = Regex::new(
+        r"(?i)^(?:(?P<mechanical>mechanical trap)|(?P<magic>magic(?:al)? trap)|(?P<weather>weather)(?: hazard)?|(?:(?P<kind>environmental|wilderness|dungeon) )?hazard)$",
+    )
+    .unwrap();
+    let type_line = type_line.trim().trim_end_matches('.');
+    if let Some(captures) = simple_re.captures(type_line) {
+        let level: u8 = captures["level"].parse().ok()?;
+        return Some(TrapHazardType::SimpleTrap {
+            tier: match level {
+                0..=4 => 1,
+                5..=10 => 2,
+                11..=16 => 3,
+                _ => 4,
+            },
+            threat: captures["threat"].to_lowercase(),
+        });
+    }
+    let captures = type_re.captures(type_line)?;
+    Some(if captures.name("mechanical").is_some() {
+        TrapHazardType::MechanicalTrap
+    } else if captures.name("magic").is_some() {
+        TrapHazardType::MagicTrap
+    } else if captures.name("weather").is_some() {
+        TrapHazardType::Weather
+    } else {
+        match captures
+            .name("kind")
+            .map(|kind| kind.as_str().to_lowercase())
+            .as_deref()
+        {
+            Some("environmental") => TrapHazardType::EnvironmentalHazard,
+            Some("wilderness") => TrapHazardType::WildernessHazard,
+            _ => TrapHazardType::Hazard,
+        }
+    })
+}
+
+/// Parse a single trap or hazard.
+///
+/// * `block` - Lines of the trap or hazard, starting with its heading and type line. Simple traps
+///   have `**Trigger.**`, `**Effect.**` and `**Countermeasures.**` paragraphs.
+/// * `source` - Source book of the trap or hazard
+///
+/// Returns: The parsed trap or hazard.
+fn parse_trap_hazard<'a>(block: &[String], source: Source<'a>) -> Result<TrapHazard<'a>> {
+    let section_re =
+        Regex::new(r"(?i)^[*_]+(?P<section>trigger|effect|countermeasures?)[.:]?[*_]+[.:]?\s*")
+            .unwrap();
+    let [heading, type_line, lines @ ..] = block else {
+        return Err(ParseError::new_with_problem(
+            &block.join("\n"),
+            "Trap or hazard",
+            "Missing type line",
+        )
+        .into());
+    };
+    let trap_hazard_type = strip_emphasis(type_line)
+        .and_then(parse_trap_hazard_type)
+        .ok_or_else(|| ParseError::new(type_line, "Trap or hazard type"))?;
+
+    // Lines of the description, the trigger, the effect and the countermeasures.
+    let mut sections: [Vec<String>; 4] = Default::default();
+    let mut current = 0;
+    for line in lines {
+        let line = section_re.captures(line).map_or_else(
+            || line.clone(),
+            |captures| {
+                current = match captures["section"].to_lowercase().as_str() {
+                    "trigger" => 1,
+                    "effect" => 2,
+                    _ => 3,
+                };
+                line[captures[0].len()..].to_string()
+            },
+        );
+        if !line.is_empty() {
+            sections[current].push(line);
+        }
+    }
+    let [entries, trigger, effect, countermeasures] =
+        sections.map(|section| parse_block_entries(&section));
+    Ok(TrapHazard {
+        name: heading_name(heading),
+        source,
+        trap_hazard_type,
+        entries,
+        trigger: non_empty(trigger),
+        effect: non_empty(effect),
+        countermeasures: non_empty(countermeasures),
+    })
+}
@@ -0,0 +1,52 @@
+use std::fs;
+
+use serde_json::Value;
+
+use crate::{
+    models::{common::Source, traps::TrapHazardType},
+    utils::{compare::json_compare, traits::To5etools},
+};
+
+use super::{parse_trap_hazard_type, parse_traps_hazards};
+
+const SOURCE: Source = Source {
+    source_book: "test-source",
+    page: 0,
+};
+
+#[test]
+fn trap_hazard_types() {
+    assert_eq!(
+        parse_trap_hazard_type("Magical trap"),
+        Some(TrapHazardType::MagicTrap)
+    );
+    assert_eq!(
+        parse_trap_hazard_type("Simple trap (levels 11–16, deadly threat)"),
+        Some(TrapHazardType::SimpleTrap {
+            tier: 3,
+            threat: "deadly".to_string(),
+        })
+    );
+    assert_eq!(
+        parse_trap_hazard_type("Weather hazard"),
+        Some(TrapHazardType::Weather)
+    );
+    assert_eq!(
+        parse_trap_hazard_type("Dungeon hazard"),
+        Some(TrapHazardType::Hazard)
+    );
+    assert_eq!(parse_trap_hazard_type("Wondrous item, rare"), None);
+}
+
+#[test]
+fn traps_hazards() {
+    let document = fs::read_to_string("resources/test/traps/unit_input.md").unwrap();
+    let traps_hazards = parse_traps_hazards(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let expected: Value =
+        serde_json::from_str(&fs::read_to_string("resources/test/traps/unit_output.json").unwrap())
+            .unwrap();
+    json_compare(traps_hazards.to_5etools_base(), expected).unwrap();
+}
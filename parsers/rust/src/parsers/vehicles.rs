@@ -0,0 +1,280 @@
+use regex::Regex;
+
+use crate::{
+    models::{
+        common::{Description, Source},
+        creatures::{ArmorClass, HitPoints},
+        vehicles::{ComponentType, Vehicle, VehicleComponent, VehicleType},
+    },
+    parsers::{
+        creatures::{extract_stat_blocks, non_empty},
+        objects::{
+            parse_named_entries, parse_property_line, parse_stat_block_sizes, split_properties,
+            split_stat_block, stat_block_subtitle, StatBlockParts,
+        },
+    },
+    utils::{
+        error::{ParseError, Result},
+        markdown::parse_entries,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Parse all vehicle stat blocks, e.g. ships and infernal war machines, in a document.
+///
+/// Vehicle stat blocks are written like creature stat blocks, with `vehicle` in place of the
+/// creature type, e.g. `*Gargantuan vehicle (130 ft. by 20 ft.)*`. Ships have a `### Hull`
+/// section and a section per component, e.g. `### Control: Helm`.
+#[must_use]
+pub fn parse_vehicles<'a>(document: &str, source: &Source<'a>) -> Vec<Result<Vehicle<'a>>> {
+    extract_stat_blocks(document.to_string())
+        .into_iter()
+        .filter(|stat_block| is_vehicle(stat_block))
+        .map(|stat_block| parse_vehicle(&stat_block, source.clone()))
+        .collect()
+}
+
+pub(crate) fn is_vehicle(stat_block: &[String]) -> bool {
+    let vehicle_re = Regex::new(r"\bvehicle\b").unwrap();
+    stat_block_subtitle(stat_block).is_some_and(|subtitle| vehicle_re.is_match(&subtitle))
+}
+
+/// Parse hit points with thresholds, e.g. `30 (damage threshold 10, mishap threshold 10)`, or of
+/// each of several components, e.g. `50 each`.
+///
+/// Returns: The hit points, the damage threshold and the mishap threshold.
+fn parse_hit_points(value: &str) -> Result<(HitPoints, Option<u16>, Option<u16>)> {
+    let value = value.trim_end_matches(" each");
+    let hit_points_re =
+        Regex::new(r"^(?P<hit_points>.+?)\s*\((?P<thresholds>[^)]*threshold[^)]*)\)$").unwrap();
+    let threshold_re = Regex::new(r"(?P<kind>damage|mishap) threshold (?P<value>\d+)").unwrap();
+    let Some(captures) = hit_points_re.captures(value) else {
+        return Ok((value.try_into()?, None, None));
+    };
+    let mut thresholds = [None, None];
+    for threshold in threshold_re.captures_iter(&captures["thresholds"]) {
+        let index = usize::from(&threshold["kind"] == "mishap");
+        thresholds[index] = Some(threshold["value"].parse().map_err(|_| {
+            ParseError::new_with_problem(value, "Hit points", "Could not parse threshold as u16")
+        })?);
+    }
+    let [damage_threshold, mishap_threshold] = thresholds;
+    Ok((
+        captures["hit_points"].try_into()?,
+        damage_threshold,
+        mishap_threshold,
+    ))
+}
+
+/// Parse the creature capacity of a vehicle, e.g. `80 crew, 40 passengers` or `1 driver, 2
+/// passengers`.
+///
+/// Returns: The number of crew members and the number of other creatures.
+fn parse_creature_capacity(value: &str) -> Result<(Option<u16>, Option<u16>)> {
+    let capacity_re = Regex::new(r"^(?P<count>[\d,]+) (?P<kind>\w+)").unwrap();
+    let mut crew = None;
+    let mut passengers = None;
+    for part in value.split([',', ';']).map(str::trim) {
+        let captures = capacity_re.captures(part).ok_or_else(|| {
+            ParseError::new_with_problem(value, "Creature capacity", "Expected e.g. `80 crew`")
+        })?;
+        let count: u16 = captures["count"].replace(',', "").parse().map_err(|_| {
+            ParseError::new_with_problem(value, "Creature capacity", "Could not parse as u16")
+        })?;
+        let capacity = if matches!(&captures["kind"], "crew" | "driver" | "drivers") {
+            &mut crew
+        } else {
+            &mut passengers
+        };
+        *capacity = Some(capacity.unwrap_or(0) + count);
+    }
+    Ok((crew, passengers))
+}
+
+/// Parse a weight in pounds, e.g. `150 tons` or `1,200 lb.`.
+fn parse_weight(value: &str) -> Result<u32> {
+    let weight_re = Regex::new(r"^(?P<amount>[\d,]+) (?P<unit>tons?|lb\.?|pounds?)").unwrap();
+    let captures = weight_re
+        .captures(value)
+        .ok_or_else(|| ParseError::new_with_problem(value, "Weight", "Expected e.g. `2 tons`"))?;
+    let amount: u32 = captures["amount"]
+        .replace(',', "")
+        .parse()
+        .map_err(|_| ParseError::new_with_problem(value, "Weight", "Could not parse as u32"))?;
+    Ok(if captures["unit"].starts_with("ton") {
+        amount * 2000
+    } else {
+        amount
+    })
+}
+
+/// Parse the travel pace of a ship, e.g. `4 miles per hour (96 miles per day)`.
+fn parse_pace(value: &str) -> Result<u8> {
+    let pace_re = Regex::new(r"^(?P<pace>\d+) miles? per hour").unwrap();
+    pace_re
+        .captures(value)
+        .and_then(|captures| captures["pace"].parse().ok())
+        .ok_or_else(|| ParseError::new(value, "Travel pace").into())
+}
+
+/// Parse the component of a ship in a section, e.g. `### Weapons: Ballistae (4)`.
+///
+/// * `heading` - Heading of the section
+/// * `lines` - Lines of the section, starting with its armor class and hit points
+///
+/// Returns: The parsed component or `None` if the section is not a component.
+fn parse_component(heading: &str, lines: &[String]) -> Option<Result<VehicleComponent>> {
+    let component_re = Regex::new(
+        r"(?i)^(?P<type>control|movement|weapons?)\s*:\s*(?P<name>.+?)(?:\s*\((?P<count>\d+)\))?$",
+    )
+    .unwrap();
+    let captures = component_re.captures(heading)?;
+    let component_type = match captures["type"].to_lowercase().as_str() {
+        "control" => ComponentType::Control,
+        "movement" => ComponentType::Movement,
+        _ => ComponentType::Weapon,
+    };
+    Some(parse_component_lines(heading, lines).map(
+        |(armor_class, hit_points, damage_threshold, entries)| {
+            VehicleComponent {
+                name: captures["name"].to_string(),
+                component_type,
+                count: captures
+                    .name("count")
+                    .and_then(|count| count.as_str().parse().ok()),
+                armor_class,
+                hit_points,
+                damage_threshold,
+                entries,
+            }
+        },
+    ))
+}
+
+/// The armor class, hit points, damage threshold and entries of a ship component.
+type ComponentStats = (ArmorClass, HitPoints, Option<u16>, Vec<Description>);
+
+/// Parse the lines of a ship component, which start with its armor class and hit points.
+fn parse_component_lines(heading: &str, lines: &[String]) -> Result<ComponentStats> {
+    let mut armor_class = None;
+    let mut hit_points = None;
+    let mut entries = vec![];
+    for line in lines {
+        match parse_property_line(line) {
+            Some((label, value)) if label == "armor class" => {
+                armor_class = Some(ArmorClass::try_from(value.as_str())?);
+            }
+            Some((label, value)) if label == "hit points" => {
+                hit_points = Some(parse_hit_points(&value)?);
+            }
+            // Other properties of the component, e.g. its speed, are kept as text.
+            Some(_) => entries.push(line.replace("**", "").trim_start_matches("- ").to_string()),
+            None => entries.push(line.clone()),
+        }
+    }
+    let missing = |label: &str| {
+        ParseError::new_with_problem(heading, "Vehicle component", &format!("No {label} found"))
+    };
+    let (hit_points, damage_threshold, _) = hit_points.ok_or_else(|| missing("hit points"))?;
+    Ok((
+        armor_class.ok_or_else(|| missing("armor class"))?,
+        hit_points,
+        damage_threshold,
+        parse_entries(&entries.join("\n")),
+    ))
+}
+
+/// Parse the size and the dimensions or weight in the line below the name of a vehicle, e.g.
+/// `gargantuan vehicle (130 ft. by 20 ft.)` or `large vehicle (1,200 lb.)`.
+fn parse_subtitle(parts: &StatBlockParts) -> Result<(Vec<String>, Option<u32>)> {
+    let details_re = Regex::new(r"\((?P<details>[^)]+)\)$").unwrap();
+    let Some(captures) = details_re.captures(&parts.subtitle) else {
+        return Ok((vec![], None));
+    };
+    let details = &captures["details"];
+    if details.contains(" by ") {
+        Ok((
+            details
+                .split(" by ")
+                .map(|length| length.trim().to_string())
+                .collect(),
+            None,
+        ))
+    } else {
+        Ok((vec![], Some(parse_weight(details)?)))
+    }
+}
+
+/// Parse a single vehicle stat block.
+///
+/// * `stat_block` - Lines of the stat block, with the leading `>` removed
+/// * `source` - Source book of the vehicle
+///
+/// Returns: The parsed vehicle.
+fn parse_vehicle<'a>(stat_block: &[String], source: Source<'a>) -> Result<Vehicle<'a>> {
+    let mut parts = split_stat_block(stat_block)?;
+    // The armor class and hit points of a ship are those of its hull.
+    let vehicle_type = match parts.section("hull").map(<[String]>::to_vec) {
+        Some(hull) => {
+            parts.properties.extend(split_properties(hull).0);
+            VehicleType::Ship
+        }
+        None => VehicleType::InfernalWarMachine,
+    };
+    let size = parse_stat_block_sizes(&parts.subtitle)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| ParseError::new(&parts.subtitle, "Vehicle size"))?;
+    let (dimensions, weight) = parse_subtitle(&parts)?;
+    let (crew, passengers) = parts
+        .parse_property("creature capacity", parse_creature_capacity)?
+        .unwrap_or_default();
+    let (hit_points, damage_threshold, mishap_threshold) =
+        parts.parse_required_property("hit points", "Vehicle", parse_hit_points)?;
+    let [damage_resistance, damage_immunity, _] = parts.parse_damage_modifiers()?;
+    let named_entries = |heading: &str| {
+        parts
+            .section(heading)
+            .map(parse_named_entries)
+            .transpose()
+            .map(|entries| entries.and_then(non_empty))
+    };
+    Ok(Vehicle {
+        vehicle_type,
+        size,
+        dimensions: non_empty(dimensions),
+        weight,
+        crew,
+        passengers,
+        cargo: parts.parse_property("cargo capacity", parse_weight)?,
+        pace: parts.parse_property("travel pace", parse_pace)?,
+        armor_class: parts
+            .parse_required_property("armor class", "Vehicle", |line| line.try_into())?,
+        hit_points,
+        damage_threshold,
+        mishap_threshold,
+        speed: parts.parse_property("speed", |line| line.try_into())?,
+        ability_scores: parts.ability_scores.clone().ok_or_else(|| {
+            ParseError::new_with_problem(&parts.name, "Vehicle", "No ability scores found")
+        })?,
+        damage_resistance,
+        damage_immunity,
+        condition_immunities: parts.parse_condition_immunities()?,
+        traits: non_empty(parse_named_entries(&parts.description)?),
+        actions: parts
+            .section("actions")
+            .map(|lines| parse_entries(&lines.join("\n")))
+            .unwrap_or_default(),
+        action_stations: named_entries("action stations")?,
+        reactions: named_entries("reactions")?,
+        components: parts
+            .sections
+            .iter()
+            .filter_map(|(heading, lines)| parse_component(heading, lines))
+            .collect::<Result<_>>()?,
+        name: parts.name.clone(),
+        source,
+    })
+}
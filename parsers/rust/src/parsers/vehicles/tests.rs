@@ -0,0 +1,62 @@
+use std::fs;
+
+use serde_json::Value;
+
+use crate::{
+    models::{common::Source, creatures::HitPoints},
+    utils::{compare::json_compare, traits::To5etools},
+};
+
+use super::{parse_creature_capacity, parse_hit_points, parse_pace, parse_vehicles, parse_weight};
+
+const SOURCE: Source = Source {
+    source_book: "test-source",
+    page: 0,
+};
+
+#[test]
+fn hit_points() {
+    assert_eq!(
+        parse_hit_points("30 (damage threshold 10, mishap threshold 12)"),
+        Ok((HitPoints::Fixed(30), Some(10), Some(12)))
+    );
+    assert_eq!(
+        parse_hit_points("500 (damage threshold 20)"),
+        Ok((HitPoints::Fixed(500), Some(20), None))
+    );
+    assert_eq!(
+        parse_hit_points("50 each"),
+        Ok((HitPoints::Fixed(50), None, None))
+    );
+}
+
+#[test]
+fn capacities() {
+    assert_eq!(
+        parse_creature_capacity("80 crew, 40 passengers"),
+        Ok((Some(80), Some(40)))
+    );
+    assert_eq!(
+        parse_creature_capacity("1 driver, 2 passengers"),
+        Ok((Some(1), Some(2)))
+    );
+    assert_eq!(parse_creature_capacity("6 creatures"), Ok((None, Some(6))));
+    assert_eq!(parse_weight("150 tons"), Ok(300_000));
+    assert_eq!(parse_weight("1,200 lb."), Ok(1200));
+    assert_eq!(parse_pace("4 miles per hour (96 miles per day)"), Ok(4));
+    assert!(parse_pace("fast").is_err());
+}
+
+#[test]
+fn vehicles() {
+    let document = fs::read_to_string("resources/test/vehicles/unit_input.md").unwrap();
+    let vehicles = parse_vehicles(&document, &SOURCE)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let expected: Value = serde_json::from_str(
+        &fs::read_to_string("resources/test/vehicles/unit_output.json").unwrap(),
+    )
+    .unwrap();
+    json_compare(vehicles.to_5etools_base(), expected).unwrap();
+}
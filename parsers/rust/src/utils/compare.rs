@@ -1,3 +1,6 @@
+// `JSONDiffError` holds the two differing values, which are large with `preserve_order` maps.
+#![allow(clippy::result_large_err)]
+
 use std::format;
 
 use itertools::{EitherOrBoth, Itertools};
@@ -6,6 +9,9 @@ use Value::{Array, Bool, Null, Number, Object};
 
 use super::error::JSONDiffError;
 
+#[cfg(test)]
+mod tests;
+
 fn compare_arrays(
     arr1: Vec<Value>,
     arr2: Vec<Value>,
@@ -38,44 +44,34 @@ fn compare_arrays(
         .unwrap_or(Ok(()))
 }
 
+/// Compare maps key by key in sorted order, so the order of the keys does not matter. A key
+/// missing from one of the maps is reported with `null` on that side.
 fn compare_maps(
-    map1: Map<String, Value>,
-    map2: Map<String, Value>,
+    mut map1: Map<String, Value>,
+    mut map2: Map<String, Value>,
     json_path: String,
 ) -> Result<(), JSONDiffError> {
-    let mut zipped = map1.into_iter().zip_longest(map2.into_iter());
-    zipped
-        .find_map(|zip_val| {
-            use EitherOrBoth::{Both, Left, Right};
-            let comparison = match zip_val {
-                Both((k1, v1), (k2, v2)) => {
-                    if k1 == k2 {
-                        json_compare_helper(v1, v2, format!("{json_path}.{k1}"))
-                    } else {
-                        Err(JSONDiffError {
-                            json1: v1,
-                            json2: Null,
-                            json_path: format!("{json_path}.{k1}"),
-                        })
-                    }
-                }
-                Left((k1, v1)) => Err(JSONDiffError {
-                    json1: v1,
-                    json2: Null,
-                    json_path: format!("{json_path}.{k1}"),
-                }),
-                Right((k2, v2)) => Err(JSONDiffError {
-                    json1: Null,
-                    json2: v2,
-                    json_path: format!("{json_path}.{k2}"),
+    let keys = map1
+        .keys()
+        .chain(map2.keys())
+        .cloned()
+        .sorted()
+        .dedup()
+        .collect_vec();
+    keys.into_iter()
+        .find_map(|key| {
+            let new_json_path = format!("{json_path}.{key}");
+            let comparison = match (map1.remove(&key), map2.remove(&key)) {
+                (Some(val1), Some(val2)) => json_compare_helper(val1, val2, new_json_path),
+                (val1, val2) => Err(JSONDiffError {
+                    json1: val1.unwrap_or(Null),
+                    json2: val2.unwrap_or(Null),
+                    json_path: new_json_path,
                 }),
             };
-            match comparison {
-                Ok(_) => None,
-                error => Some(error),
-            }
+            comparison.err()
         })
-        .unwrap_or(Ok(()))
+        .map_or(Ok(()), Err)
 }
 
 fn json_compare_helper(json1: Value, json2: Value, json_path: String) -> Result<(), JSONDiffError> {
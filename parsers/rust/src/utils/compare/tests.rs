@@ -0,0 +1,26 @@
+use serde_json::{json, Value};
+
+use super::json_compare;
+
+#[test]
+fn maps() {
+    assert!(json_compare(
+        json!({"min": 96, "max": 100, "pad": true}),
+        json!({"pad": true, "max": 100, "min": 96})
+    )
+    .is_ok());
+    let error = json_compare(
+        json!({"roll": {"max": 100, "min": 96}}),
+        json!({"roll": {"max": 100, "min": 96, "pad": true}}),
+    )
+    .unwrap_err();
+    assert_eq!(
+        (error.json_path.as_str(), error.json1, error.json2),
+        ("<root>.roll.pad", Value::Null, json!(true))
+    );
+    let error = json_compare(json!({"a": 1, "c": 3}), json!({"b": 2, "c": 3})).unwrap_err();
+    assert_eq!(
+        (error.json_path.as_str(), error.json1, error.json2),
+        ("<root>.a", json!(1), Value::Null)
+    );
+}
@@ -0,0 +1,261 @@
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{
+    models::common::Description,
+    utils::error::{ParseError, Result},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Get the trimmed, non-empty lines of a document that are text rather than layout, e.g.
+/// `\column`, `<div>` or `:::`.
+pub(crate) fn document_lines(document: &str) -> Vec<&str> {
+    let layout_re = Regex::new(r"^(?:\\|<|:::|\{\{|\}\})").unwrap();
+    document
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !layout_re.is_match(line))
+        .collect()
+}
+
+/// Whether a line is a markdown heading, e.g. `### Acolyte`.
+pub(crate) fn is_heading(line: &str) -> bool {
+    line.starts_with('#')
+}
+
+/// Level of a markdown heading, e.g. 3 for `### Acolyte`.
+pub(crate) fn heading_level(line: &str) -> Option<usize> {
+    is_heading(line).then(|| line.chars().take_while(|c| *c == '#').count())
+}
+
+/// Name of a markdown heading, e.g. `Acolyte` for `### Acolyte`.
+pub(crate) fn heading_name(heading: &str) -> String {
+    heading.trim_start_matches('#').trim().to_string()
+}
+
+/// Lines of a heading and everything below it until the next heading of the same or a higher
+/// level.
+pub(crate) fn heading_block(lines: &[&str], start: usize) -> Vec<String> {
+    let level = heading_level(lines[start]).unwrap_or_default();
+    lines[start..]
+        .iter()
+        .enumerate()
+        .take_while(|(offset, line)| {
+            *offset == 0 || heading_level(line).is_none_or(|other| other > level)
+        })
+        .map(|(_, line)| (*line).to_string())
+        .collect()
+}
+
+/// Split a document into blocks, e.g. items or feats, each starting with a heading that is
+/// followed by a line for which `is_block_start` holds. A block ends at the next heading.
+pub(crate) fn extract_blocks(document: &str, is_block_start: fn(&str) -> bool) -> Vec<Vec<String>> {
+    let lines = document_lines(document);
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(index, line)| {
+            is_heading(line)
+                && lines
+                    .get(index + 1)
+                    .is_some_and(|line| is_block_start(line))
+        })
+        .map(|(index, _)| {
+            lines[index..]
+                .iter()
+                .enumerate()
+                .take_while(|(offset, line)| *offset == 0 || !is_heading(line))
+                .map(|(_, line)| (*line).to_string())
+                .collect()
+        })
+        .collect()
+}
+
+/// Remove the emphasis around a line, e.g. `*Ring, rare*` or `_Ring, rare_`.
+pub(crate) fn strip_emphasis(line: &str) -> Option<&str> {
+    let line = line.trim();
+    ["***", "**", "*", "_"].into_iter().find_map(|emphasis| {
+        line.strip_prefix(emphasis)
+            .and_then(|line| line.strip_suffix(emphasis))
+    })
+}
+
+/// Remove the emphasis of a line, also when only a label is emphasized, e.g.
+/// `**Prerequisite:** 4th level`.
+pub(crate) fn remove_emphasis(line: &str) -> String {
+    line.replace('*', "").trim_matches('_').trim().to_string()
+}
+
+/// Parse a section of free text, e.g. the description of an item or the lair actions of a
+/// creature.
+///
+/// * `section` - Lines of text, where consecutive lines starting with `- ` or `* ` form a list.
+pub(crate) fn parse_entries(section: &str) -> Vec<Description> {
+    let list_item_re = Regex::new(r"^[-*•] ").unwrap();
+    section
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .group_by(|line| list_item_re.is_match(line))
+        .into_iter()
+        .flat_map(|(is_list_item, lines)| {
+            if is_list_item {
+                vec![Description::List(
+                    lines
+                        .map(|line| list_item_re.replace(line, "").to_string())
+                        .map(Description::Entry)
+                        .collect(),
+                )]
+            } else {
+                lines
+                    .map(ToString::to_string)
+                    .map(Description::Entry)
+                    .collect()
+            }
+        })
+        .collect()
+}
+
+/// Parse the entries of a block of lines, where subheadings start sections and markdown tables
+/// become tables.
+pub(crate) fn parse_block_entries(lines: &[String]) -> Vec<Description> {
+    let mut entries = vec![];
+    let mut section: Option<(String, Vec<Description>)> = None;
+    for (kind, group) in &lines.iter().group_by(|line| {
+        if is_heading(line) {
+            0
+        } else if line.starts_with('|') {
+            1
+        } else {
+            2
+        }
+    }) {
+        let group = group.map(String::as_str).collect_vec();
+        let target = section
+            .as_mut()
+            .map_or(&mut entries, |(_, section_entries)| section_entries);
+        match kind {
+            0 => {
+                for heading in group {
+                    if let Some((name, section_entries)) = section.take() {
+                        entries.push(Description::Section {
+                            name,
+                            entries: section_entries,
+                        });
+                    }
+                    section = Some((heading_name(heading), vec![]));
+                }
+            }
+            1 => target.extend(parse_markdown_tables(&group)),
+            _ => target.extend(parse_entries(&group.join("\n"))),
+        }
+    }
+    if let Some((name, section_entries)) = section {
+        entries.push(Description::Section {
+            name,
+            entries: section_entries,
+        });
+    }
+    entries
+}
+
+/// Split a markdown table row into its trimmed cells, e.g. `| Club | 1 sp |`.
+pub(crate) fn split_table_row(line: &str) -> Vec<String> {
+    let line = line.trim().trim_start_matches('|');
+    let line = line.strip_suffix('|').unwrap_or(line);
+    line.split('|')
+        .map(|cell| cell.trim().to_string())
+        .collect()
+}
+
+/// Whether the cells are a separator row below a table header, e.g. `|:--:|:--|`. Every cell
+/// needs at least one dash, so an empty spacer row like `|  |  |` is not a separator.
+pub(crate) fn is_table_separator(cells: &[String]) -> bool {
+    cells
+        .iter()
+        .all(|cell| cell.contains('-') && cell.chars().all(|c| "-: ".contains(c)))
+}
+
+/// Parse consecutive markdown table rows into tables. A row followed by a separator row, e.g.
+/// `|:--:|:--|`, is a header row and starts a new table, as tables separated by empty lines
+/// follow each other once empty lines are removed.
+pub(crate) fn parse_markdown_tables(rows: &[&str]) -> Vec<Description> {
+    let rows = rows.iter().map(|row| split_table_row(row)).collect_vec();
+    let header_indices = rows
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, cells)| is_table_separator(cells))
+        .map(|(index, _)| index - 1)
+        .collect_vec();
+    header_indices
+        .iter()
+        .enumerate()
+        .map(|(table, &header)| {
+            let end = header_indices
+                .get(table + 1)
+                .copied()
+                .unwrap_or(rows.len())
+                .max(header + 2);
+            Description::Table {
+                caption: None,
+                col_labels: rows[header].clone(),
+                rows: rows[header + 2..end].to_vec(),
+            }
+        })
+        .collect()
+}
+
+/// Split at the commas that are not enclosed in parentheses.
+pub(crate) fn split_top_level(value: &str) -> Vec<&str> {
+    let mut depth = 0_usize;
+    let mut start = 0;
+    let mut parts = vec![];
+    for (index, character) in value.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(value[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(value[start..].trim());
+    parts
+}
+
+/// Split a list, e.g. `Insight, Religion` or `Insight and Religion`.
+pub(crate) fn split_list(list: &str) -> Vec<&str> {
+    split_top_level(list)
+        .into_iter()
+        .flat_map(|part| part.split(" and "))
+        .map(|part| {
+            part.trim_start_matches("and ")
+                .trim_start_matches("or ")
+                .trim()
+        })
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// Parse a count written as a word or a number, e.g. `two` or `2`.
+pub(crate) fn parse_count(count: &str, parsing_step: &str) -> Result<u8> {
+    Ok(match &count.trim().to_lowercase()[..] {
+        "a" | "an" | "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        number => number.parse().map_err(ParseError::from_intparse_error(
+            count.to_string(),
+            parsing_step.to_string(),
+        ))?,
+    })
+}
@@ -0,0 +1,80 @@
+use crate::models::common::Description;
+
+use super::{parse_block_entries, parse_entries, parse_markdown_tables};
+
+#[test]
+fn entries() {
+    use Description::{Entry, List};
+    assert_eq!(
+        parse_entries(
+            "The region around the lair is warped:\n* Tremors shake the area.\n* Water sources turn foul.\nIf the beast dies, these effects end."
+        ),
+        vec![
+            Entry("The region around the lair is warped:".to_string()),
+            List(vec![
+                Entry("Tremors shake the area.".to_string()),
+                Entry("Water sources turn foul.".to_string()),
+            ]),
+            Entry("If the beast dies, these effects end.".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn block_entries() {
+    let lines = [
+        "Intro 1d4",
+        "#### Feature",
+        "- Item",
+        "| d4 | Bond |",
+        "|:--:|:--|",
+        "| 1 | My ship |",
+        "| d4 | Flaw |",
+        "|:--:|:--|",
+        "| 1 | Greed |",
+    ]
+    .map(str::to_string);
+    assert_eq!(
+        parse_block_entries(&lines),
+        vec![
+            Description::Entry("Intro 1d4".to_string()),
+            Description::Section {
+                name: "Feature".to_string(),
+                entries: vec![
+                    Description::List(vec![Description::Entry("Item".to_string())]),
+                    Description::Table {
+                        caption: None,
+                        col_labels: vec!["d4".to_string(), "Bond".to_string()],
+                        rows: vec![vec!["1".to_string(), "My ship".to_string()]],
+                    },
+                    Description::Table {
+                        caption: None,
+                        col_labels: vec!["d4".to_string(), "Flaw".to_string()],
+                        rows: vec![vec!["1".to_string(), "Greed".to_string()]],
+                    },
+                ],
+            },
+        ]
+    );
+}
+
+#[test]
+fn markdown_tables() {
+    let table = |col_labels: &[&str], rows: &[&[&str]]| Description::Table {
+        caption: None,
+        col_labels: col_labels.iter().map(ToString::to_string).collect(),
+        rows: rows
+            .iter()
+            .map(|row| row.iter().map(ToString::to_string).collect())
+            .collect(),
+    };
+    assert_eq!(
+        parse_markdown_tables(&["| d4 | Trinket |", "|:--:|:--|", "| 1 | A key |", "|  |  |"]),
+        vec![table(&["d4", "Trinket"], &[&["1", "A key"], &["", ""]])]
+    );
+    assert_eq!(
+        parse_markdown_tables(&["| Armor | Cost |", "|--|--|", "|--|--|"]),
+        vec![table(&["Armor", "Cost"], &[]), table(&["--", "--"], &[]),]
+    );
+    assert!(parse_markdown_tables(&["|  |  |", "|  |  |"]).is_empty());
+}